@@ -1,25 +1,46 @@
-use serde::Deserialize;
+use std::collections::HashMap;
 
-#[derive(Hash, PartialEq, Eq, Clone, Deserialize)]
-pub enum MoveDirection {
-    ForwardIdle,
-    LeftIdle,
-    BackwardIdle,
-    RightIdle,
-    ForwardMove,
-    LeftMove,
-    BackwardMove,
-    RightMove,
+use crate::movement::entities::MoveDirection;
+
+/// First frame, last frame and frame duration of the sheet row.
+pub type SheetRow = (u32, u32, f32);
+
+/// Sheet with rows of bottom, left, top, right idles followed by the same moves.
+/// It has no rows for diagonals, so they use vertical ones. Diagonal and straight directions
+/// share the same sheet row, so animation isn't restarted when moving between them.
+pub fn four_sides_sheet(
+    frames: u32,
+    idle_seconds: f32,
+    move_seconds: f32,
+) -> HashMap<MoveDirection, SheetRow> {
+    let row = |idx: u32, seconds: f32| (idx * frames, (idx + 1) * frames - 1, seconds);
+    let mut moves = HashMap::with_capacity(16);
+    moves.insert(MoveDirection::BottomIdle, row(0, idle_seconds));
+    moves.insert(MoveDirection::LeftBottomIdle, row(0, idle_seconds));
+    moves.insert(MoveDirection::RightBottomIdle, row(0, idle_seconds));
+    moves.insert(MoveDirection::LeftIdle, row(1, idle_seconds));
+    moves.insert(MoveDirection::TopIdle, row(2, idle_seconds));
+    moves.insert(MoveDirection::LeftTopIdle, row(2, idle_seconds));
+    moves.insert(MoveDirection::RightTopIdle, row(2, idle_seconds));
+    moves.insert(MoveDirection::RightIdle, row(3, idle_seconds));
+    moves.insert(MoveDirection::Bottom, row(4, move_seconds));
+    moves.insert(MoveDirection::LeftBottom, row(4, move_seconds));
+    moves.insert(MoveDirection::RightBottom, row(4, move_seconds));
+    moves.insert(MoveDirection::Left, row(5, move_seconds));
+    moves.insert(MoveDirection::Top, row(6, move_seconds));
+    moves.insert(MoveDirection::LeftTop, row(6, move_seconds));
+    moves.insert(MoveDirection::RightTop, row(6, move_seconds));
+    moves.insert(MoveDirection::Right, row(7, move_seconds));
+    moves
 }
 
-impl MoveDirection {
-    pub fn is_idle(&self) -> bool {
-        matches!(
-            self,
-            MoveDirection::ForwardIdle
-                | MoveDirection::BackwardIdle
-                | MoveDirection::LeftIdle
-                | MoveDirection::RightIdle
-        )
-    }
+#[test]
+fn four_sides_sheet_test() {
+    let sheet = four_sides_sheet(6, 0.4, 0.15);
+
+    assert_eq!(sheet.len(), 16);
+    assert_eq!(sheet[&MoveDirection::BottomIdle], (0, 5, 0.4));
+    assert_eq!(sheet[&MoveDirection::RightIdle], (18, 23, 0.4));
+    assert_eq!(sheet[&MoveDirection::LeftTop], (36, 41, 0.15));
+    assert_eq!(sheet[&MoveDirection::RightBottomIdle], (0, 5, 0.4));
 }
@@ -0,0 +1,91 @@
+//! Prints dialog graph for review, e.g. to render it with Graphviz:
+//! cargo run --features dev --bin dialog_export -- 5 dot en | dot -Tsvg -o dialog_5.svg
+// Game modules are compiled into this binary only to reach the dialogs
+#![allow(dead_code, unused_imports)]
+#![allow(clippy::too_many_arguments, clippy::type_complexity)]
+
+use std::process::exit;
+
+use crate::dialog::export::{export_dialog, GraphFormat};
+use crate::dialog::DialogsStorage;
+use crate::localization::Localization;
+use crate::settings::Language;
+
+#[path = "../animation/mod.rs"]
+mod animation;
+#[path = "../core/mod.rs"]
+mod core;
+#[path = "../cutscene/mod.rs"]
+mod cutscene;
+#[path = "../dialog/mod.rs"]
+mod dialog;
+#[path = "../fight/mod.rs"]
+mod fight;
+#[path = "../gui/mod.rs"]
+mod gui;
+#[path = "../input/mod.rs"]
+mod input;
+#[path = "../interaction/mod.rs"]
+mod interaction;
+#[path = "../level/mod.rs"]
+mod level;
+#[path = "../localization/mod.rs"]
+mod localization;
+#[path = "../movement/mod.rs"]
+mod movement;
+#[path = "../npc/mod.rs"]
+mod npc;
+#[path = "../party/mod.rs"]
+mod party;
+#[path = "../player/mod.rs"]
+mod player;
+#[path = "../rpg/mod.rs"]
+mod rpg;
+#[path = "../settings/mod.rs"]
+mod settings;
+#[path = "../sound/mod.rs"]
+mod sound;
+#[path = "../trade/mod.rs"]
+mod trade;
+#[path = "../world_state/mod.rs"]
+mod world_state;
+
+const USAGE: &str = "Usage: dialog_export <dialog id> [dot|mermaid] [ru|en]";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let storage = DialogsStorage;
+
+    let Some(id) = args.first().and_then(|arg| arg.parse::<usize>().ok()) else {
+        let mut ids: Vec<usize> = storage.get_all().keys().copied().collect();
+        ids.sort();
+        eprintln!("{USAGE}\nDialogs: {ids:?}");
+        exit(1);
+    };
+    let format = match args.get(1) {
+        None => GraphFormat::Dot,
+        Some(name) => GraphFormat::from_name(name).unwrap_or_else(|| {
+            eprintln!("Unknown format {name}\n{USAGE}");
+            exit(1);
+        }),
+    };
+    let language = args.get(2).map(|code| {
+        *Language::ALL
+            .iter()
+            .find(|language| language.code() == code)
+            .unwrap_or_else(|| {
+                eprintln!("Unknown language {code}\n{USAGE}");
+                exit(1);
+            })
+    });
+    let Some(dialog) = storage.get_by_id(&id) else {
+        eprintln!("No dialog with id {id}");
+        exit(1);
+    };
+    // Without language texts are exported as they are written in code
+    let dialog = match language {
+        None => dialog,
+        Some(language) => dialog.localized(&Localization::load(language)),
+    };
+    print!("{}", export_dialog(&dialog, format));
+}
@@ -13,12 +13,9 @@ pub fn recalculate_z(
     let default = LevelYMax::create(192.0);
     let y_max = level_y_max.get_single().unwrap_or(&default);
     for (mut transform, body, y_offset) in bodies.iter_mut() {
-        match body {
-            RigidBody::Dynamic => {
-                let new_z = calculate_z(transform.translation.y - y_offset.value, y_max.value);
-                transform.translation.z = new_z;
-            }
-            _ => {}
+        if body == &RigidBody::Dynamic {
+            let new_z = calculate_z(transform.translation.y - y_offset.value, y_max.value);
+            transform.translation.z = new_z;
         }
     }
 }
@@ -0,0 +1,39 @@
+use std::fs;
+use std::path::PathBuf;
+
+use bevy::log::warn;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+const CONFIG_DIRECTORY: &str = "config";
+
+fn config_path(name: &str) -> PathBuf {
+    PathBuf::from(CONFIG_DIRECTORY).join(format!("{name}.json"))
+}
+
+/// Reads config by its name, None if there is no such config or it's broken.
+pub fn load_config<T: DeserializeOwned>(name: &str) -> Option<T> {
+    let path = config_path(name);
+    let json = fs::read_to_string(&path).ok()?;
+    match serde_json::from_str(&json) {
+        Ok(config) => Some(config),
+        Err(error) => {
+            warn!("Could not parse config {path:?}: {error}");
+            None
+        }
+    }
+}
+
+pub fn save_config<T: Serialize>(name: &str, config: &T) {
+    let path = config_path(name);
+    let json = match serde_json::to_string_pretty(config) {
+        Ok(json) => json,
+        Err(error) => {
+            warn!("Could not serialize config {path:?}: {error}");
+            return;
+        }
+    };
+    if let Err(error) = fs::create_dir_all(CONFIG_DIRECTORY).and_then(|_| fs::write(&path, json)) {
+        warn!("Could not save config {path:?}: {error}");
+    }
+}
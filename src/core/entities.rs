@@ -10,7 +10,7 @@ pub struct BodyYOffset {
 
 impl BodyYOffset {
     pub fn create(value: f32) -> BodyYOffset {
-        return BodyYOffset { value };
+        BodyYOffset { value }
     }
 }
 
@@ -21,7 +21,7 @@ pub struct LevelYMax {
 
 impl LevelYMax {
     pub fn create(value: f32) -> LevelYMax {
-        return LevelYMax { value };
+        LevelYMax { value }
     }
 }
 
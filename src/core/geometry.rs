@@ -18,42 +18,42 @@ impl BBox {
             0,
             min(self.top as i32, other.top as i32) - max(self.bottom as i32, other.bottom as i32),
         );
-        return x_overlap * y_overlap;
+        x_overlap * y_overlap
     }
 
     pub fn half_w(&self) -> f32 {
-        return (self.right - self.left) / 2.0;
+        (self.right - self.left) / 2.0
     }
 
     pub fn half_h(&self) -> f32 {
-        return (self.top - self.bottom) / 2.0;
+        (self.top - self.bottom) / 2.0
     }
 
     pub fn from_square(center_x: f32, center_y: f32, half_size: f32) -> Self {
-        return BBox {
+        BBox {
             left: center_x - half_size,
             top: center_y + half_size,
             right: center_x + half_size,
             bottom: center_y - half_size,
-        };
+        }
     }
 
     pub fn from_rect(center_x: f32, center_y: f32, half_w: f32, half_h: f32) -> Self {
-        return BBox {
+        BBox {
             left: center_x - half_w,
             top: center_y + half_h,
             right: center_x + half_w,
             bottom: center_y - half_h,
-        };
+        }
     }
 
     pub fn round_center(&self) -> Point2D {
         let half_w = self.half_w();
         let half_h = self.half_h();
-        return Point2D {
+        Point2D {
             x: (self.left + half_w) as i32,
             y: (self.bottom + half_h) as i32,
-        };
+        }
     }
 }
 
@@ -65,14 +65,14 @@ pub struct Point2D {
 
 impl Point2D {
     pub fn new(x: i32, y: i32) -> Point2D {
-        return Point2D { x, y };
+        Point2D { x, y }
     }
 
     // d = sqrt((xb - xa)^2 + (yb - ya)^2)
     pub fn distance_to(&self, other: &Point2D) -> f32 {
         let sq_x = (other.x - self.x) * (other.x - self.x);
         let sq_y = (other.y - self.y) * (other.y - self.y);
-        return ((sq_x + sq_y) as f32).sqrt();
+        ((sq_x + sq_y) as f32).sqrt()
     }
 }
 
@@ -85,57 +85,31 @@ pub struct Line2D {
 }
 
 impl Line2D {
+    #[allow(dead_code)]
     pub fn new(a: i32, b: i32, c: i32) -> Self {
-        return Line2D { a, b, c };
+        Line2D { a, b, c }
     }
 
     // x -  xa   y -  ya
     // ------- = ------- -> Ax + By + C = 0
     // xb - xa   yb - ya
     pub fn from(point1: &Point2D, point2: &Point2D) -> Self {
-        return Line2D {
+        Line2D {
             a: point2.y - point1.y,
             b: point1.x - point2.x,
             c: point1.y * point2.x - point1.x * point2.y,
-        };
-    }
-}
-
-pub fn nearest_to_line(points: &Vec<Point2D>, line: &Line2D) -> Point2D {
-    let mut nearest = points.first().expect("points must be not empty");
-    let mut distance = f32::MAX;
-    for point in points {
-        let new_distance = distance_to_line(point, line);
-        if new_distance < distance {
-            nearest = point;
-            distance = new_distance;
         }
     }
-
-    return nearest.clone();
-}
-
-pub fn nearest_to_point(points: &Vec<Point2D>, target: &Point2D) -> Point2D {
-    let mut nearest = points.first().expect("points must be not empty");
-    let mut distance = f32::MAX;
-    for point in points {
-        let new_distance = point.distance_to(target);
-        if new_distance < distance {
-            nearest = point;
-            distance = new_distance;
-        }
-    }
-
-    return nearest.clone();
 }
 
 //      |Ax0 + By0 + C|
 //  d = ---------------
 //      sqrt(A^2 + B^2)
+#[allow(dead_code)]
 pub fn distance_to_line(point: &Point2D, line: &Line2D) -> f32 {
     let numerator = (line.a * point.x + line.b * point.y + line.c).abs() as f32;
     let denominator = ((line.a * line.a + line.b * line.b) as f32).sqrt();
-    return numerator / denominator;
+    numerator / denominator
 }
 
 /// find lines intersection with solving equations systems using Cramer's rule
@@ -163,10 +137,10 @@ pub fn round_lines_intersection(line1: &Line2D, line2: &Line2D) -> Option<Point2
     }
     let dx = -line1.c * line2.b + line2.c * line1.b;
     let dy = -line1.a * line2.c + line2.a * line1.c;
-    return Some(Point2D {
+    Some(Point2D {
         x: dx / d,
         y: dy / d,
-    });
+    })
 }
 
 pub fn round_segments_intersection(
@@ -178,7 +152,7 @@ pub fn round_segments_intersection(
     let line1 = Line2D::from(segment1_x, segment1_y);
     let line2 = Line2D::from(segment2_x, segment2_y);
 
-    return round_lines_intersection(&line1, &line2).and_then(|point| {
+    round_lines_intersection(&line1, &line2).and_then(|point| {
         let intersect_first = point_in_rectangle(&point, segment1_x, segment1_y);
         let intersect_second = point_in_rectangle(&point, segment2_x, segment2_y);
         if intersect_first && intersect_second {
@@ -186,7 +160,7 @@ pub fn round_segments_intersection(
         } else {
             None
         }
-    });
+    })
 }
 
 fn point_in_rectangle(point: &Point2D, top_left: &Point2D, bottom_right: &Point2D) -> bool {
@@ -195,7 +169,7 @@ fn point_in_rectangle(point: &Point2D, top_left: &Point2D, bottom_right: &Point2
     let min_y = min(top_left.y, bottom_right.y);
     let max_y = max(top_left.y, bottom_right.y);
 
-    return min_x <= point.x && point.x <= max_x && min_y <= point.y && point.y <= max_y;
+    min_x <= point.x && point.x <= max_x && min_y <= point.y && point.y <= max_y
 }
 
 //   *******
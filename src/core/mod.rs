@@ -1,4 +1,5 @@
 pub mod collisions;
+pub mod config;
 pub mod entities;
 pub mod geometry;
 pub mod state_machines;
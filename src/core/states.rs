@@ -12,4 +12,6 @@ pub enum GameState {
     GameOver,
     DevSetting,
     Character,
+    Trading,
+    Journal,
 }
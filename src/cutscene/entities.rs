@@ -0,0 +1,244 @@
+use bevy::math::Vec2;
+use bevy::prelude::Component;
+
+use crate::dialog::{DialogCondition, DialogVariables, Replica};
+use crate::localization::Localization;
+use crate::world_state::QuestEvent;
+
+/// One moment of a cutscene, the next step starts when the previous one is finished.
+#[derive(Clone, Debug)]
+pub enum CutsceneStep {
+    /// Actor walks to the point, speed is in pixels per second.
+    MoveNpc {
+        actor: String,
+        to: Vec2,
+        speed: f32,
+    },
+    /// Frames from first to last of the actor sheet are shown once.
+    Animation {
+        actor: String,
+        first: usize,
+        last: usize,
+        frame_seconds: f32,
+    },
+    /// Replica is shown until player confirms it, key is unique in the cutscene.
+    Replica {
+        key: String,
+        replica: Replica,
+    },
+    PanCamera {
+        to: Vec2,
+        seconds: f32,
+    },
+    Wait(f32),
+    /// Screen is darkened to alpha, 1.0 is fully black.
+    Fade {
+        alpha: f32,
+        seconds: f32,
+    },
+    Sound(String),
+    /// Quest states are changed only by quest engines, so the step sends them event.
+    SetQuestState(QuestEvent),
+}
+
+impl CutsceneStep {
+    // Result of these steps stays after the cutscene, so they are applied even when it's skipped
+    fn is_lasting(&self) -> bool {
+        matches!(
+            self,
+            CutsceneStep::MoveNpc { .. } | CutsceneStep::SetQuestState(_)
+        )
+    }
+}
+
+/// Step is played only when condition is met at the moment it is reached.
+#[derive(Clone, Debug)]
+pub struct TimelineStep {
+    pub step: CutsceneStep,
+    pub condition: Option<DialogCondition>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Cutscene {
+    pub id: usize,
+    pub steps: Vec<TimelineStep>,
+}
+
+impl Cutscene {
+    pub fn new(id: usize) -> Self {
+        Cutscene { id, steps: vec![] }
+    }
+
+    pub fn step(mut self, step: CutsceneStep) -> Self {
+        self.steps.push(TimelineStep {
+            step,
+            condition: None,
+        });
+        self
+    }
+
+    pub fn step_if(mut self, condition: DialogCondition, step: CutsceneStep) -> Self {
+        self.steps.push(TimelineStep {
+            step,
+            condition: Some(condition),
+        });
+        self
+    }
+
+    /// Replicas are written in Russian in cutscenes data, other languages are in string tables.
+    pub fn localized(mut self, localization: &Localization) -> Cutscene {
+        let cutscene_id = self.id;
+        for timeline_step in self.steps.iter_mut() {
+            if let CutsceneStep::Replica { key, replica } = &mut timeline_step.step {
+                replica.text = localization.get_or(&replica_key(cutscene_id, key), &replica.text);
+            }
+        }
+        self
+    }
+
+    /// Keys and original texts of all replicas.
+    #[cfg(test)]
+    pub fn texts(&self) -> Vec<(String, String)> {
+        self.steps
+            .iter()
+            .filter_map(|timeline_step| match &timeline_step.step {
+                CutsceneStep::Replica { key, replica } => {
+                    Some((replica_key(self.id, key), replica.text.clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+fn replica_key(cutscene_id: usize, key: &str) -> String {
+    format!("cutscene.{cutscene_id}.{key}")
+}
+
+/// Position in the played cutscene.
+#[derive(Debug)]
+pub struct Timeline {
+    cutscene: Cutscene,
+    position: usize,
+    started: bool,
+    pub elapsed: f32,
+}
+
+impl Timeline {
+    pub fn new(cutscene: Cutscene) -> Self {
+        Timeline {
+            cutscene,
+            position: 0,
+            started: false,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Steps with unmet conditions are passed by, None means cutscene is over.
+    pub fn current(&mut self, variables: &DialogVariables) -> Option<CutsceneStep> {
+        while let Some(timeline_step) = self.cutscene.steps.get(self.position) {
+            let is_met = timeline_step
+                .condition
+                .as_ref()
+                .is_none_or(|condition| condition.is_met(variables));
+            if is_met {
+                return Some(timeline_step.step.clone());
+            }
+            self.position += 1;
+        }
+        None
+    }
+
+    /// Returns true only for the first call on the step, so it can be prepared.
+    pub fn start_step(&mut self) -> bool {
+        !std::mem::replace(&mut self.started, true)
+    }
+
+    pub fn next(&mut self) {
+        self.position += 1;
+        self.started = false;
+        self.elapsed = 0.0;
+    }
+
+    /// Finishes the cutscene and returns steps which must be applied anyway.
+    pub fn skip(&mut self, variables: &DialogVariables) -> Vec<CutsceneStep> {
+        let mut result = vec![];
+        while let Some(step) = self.current(variables) {
+            if step.is_lasting() {
+                result.push(step);
+            }
+            self.next();
+        }
+        result
+    }
+}
+
+/// Entity which cutscenes can move and animate by name.
+#[derive(Component)]
+pub struct CutsceneActor(pub String);
+
+#[test]
+fn cutscene_timeline_test() {
+    use crate::dialog::DialogValue;
+
+    let variables = {
+        let mut variables = DialogVariables::default();
+        variables.set("guilty", DialogValue::Flag(true));
+        variables
+    };
+    let guilty = DialogCondition::Equals("guilty".to_string(), DialogValue::Flag(true));
+    let verdict = |end_id| {
+        CutsceneStep::SetQuestState(QuestEvent::CutsceneEnded {
+            cutscene_id: 1,
+            end_id,
+        })
+    };
+    let cutscene = Cutscene::new(1)
+        .step(CutsceneStep::Wait(1.0))
+        .step(CutsceneStep::Replica {
+            key: "all_rise".to_string(),
+            replica: Replica::from_text("Встать, суд идёт!".to_string()),
+        })
+        .step_if(DialogCondition::Not(Box::new(guilty.clone())), verdict(1))
+        .step_if(guilty, verdict(2))
+        .step(CutsceneStep::MoveNpc {
+            actor: "judges".to_string(),
+            to: Vec2::ZERO,
+            speed: 10.0,
+        });
+    assert_eq!(
+        cutscene.texts(),
+        vec![(
+            "cutscene.1.all_rise".to_string(),
+            "Встать, суд идёт!".to_string()
+        )]
+    );
+
+    let mut timeline = Timeline::new(cutscene.clone());
+    assert!(matches!(
+        timeline.current(&variables),
+        Some(CutsceneStep::Wait(_))
+    ));
+    assert!(timeline.start_step());
+    assert!(!timeline.start_step());
+    timeline.next();
+    assert!(timeline.start_step());
+    timeline.next();
+    assert!(matches!(
+        timeline.current(&variables),
+        Some(CutsceneStep::SetQuestState(QuestEvent::CutsceneEnded {
+            end_id: 2,
+            ..
+        }))
+    ));
+
+    let mut timeline = Timeline::new(cutscene);
+    let lasting = timeline.skip(&variables);
+    assert_eq!(lasting.len(), 2);
+    assert!(matches!(
+        lasting[0],
+        CutsceneStep::SetQuestState(QuestEvent::CutsceneEnded { end_id: 2, .. })
+    ));
+    assert!(matches!(lasting[1], CutsceneStep::MoveNpc { .. }));
+    assert!(timeline.current(&variables).is_none());
+}
@@ -0,0 +1,7 @@
+pub use entities::*;
+pub use scene::*;
+pub use storages::*;
+
+mod entities;
+mod scene;
+mod storages;
@@ -0,0 +1,380 @@
+use bevy::app::{App, Plugin};
+use bevy::asset::AssetServer;
+use bevy::color::palettes::css::{ANTIQUE_WHITE, DIM_GREY, GOLD};
+use bevy::color::{Alpha, Color};
+use bevy::hierarchy::DespawnRecursiveExt;
+use bevy::input::ButtonInput;
+use bevy::log::warn;
+use bevy::math::Vec2;
+use bevy::prelude::in_state;
+use bevy::prelude::AlignItems;
+use bevy::prelude::BackgroundColor;
+use bevy::prelude::Commands;
+use bevy::prelude::Component;
+use bevy::prelude::Entity;
+use bevy::prelude::EventWriter;
+use bevy::prelude::IntoSystemConfigs;
+use bevy::prelude::JustifyContent;
+use bevy::prelude::MouseButton;
+use bevy::prelude::NextState;
+use bevy::prelude::OnEnter;
+use bevy::prelude::OnExit;
+use bevy::prelude::PositionType;
+use bevy::prelude::Query;
+use bevy::prelude::Res;
+use bevy::prelude::ResMut;
+use bevy::prelude::Resource;
+use bevy::prelude::TextureAtlas;
+use bevy::prelude::Time;
+use bevy::prelude::Transform;
+use bevy::prelude::UiRect;
+use bevy::prelude::Update;
+use bevy::prelude::Val;
+use bevy::prelude::With;
+use bevy::prelude::Without;
+use sickle_ui::prelude::SetAlignItemsExt;
+use sickle_ui::prelude::SetBackgroundColorExt;
+use sickle_ui::prelude::SetBorderColorExt;
+use sickle_ui::prelude::SetBorderExt;
+use sickle_ui::prelude::SetHeightExt;
+use sickle_ui::prelude::SetJustifyContentExt;
+use sickle_ui::prelude::SetMarginExt;
+use sickle_ui::prelude::SetPositionTypeExt;
+use sickle_ui::prelude::SetRightExt;
+use sickle_ui::prelude::SetTopExt;
+use sickle_ui::prelude::SetWidthExt;
+use sickle_ui::prelude::UiColumnExt;
+use sickle_ui::prelude::UiRoot;
+use sickle_ui::ui_builder::UiBuilderExt;
+
+use crate::core::entities::MainCamera;
+use crate::core::states::GameState;
+use crate::cutscene::{CutsceneActor, CutsceneStep, CutscenesStorage, Timeline};
+use crate::dialog::{DialogVariables, Replica};
+use crate::gui::{TextConfig, TextExt};
+use crate::input::{key_title, InputAction, InputBindings};
+use crate::level::transitions::LevelTransition;
+use crate::localization::Localization;
+use crate::sound::{AudioBus, AudioCommandsExt};
+use crate::world_state::QuestEvent;
+
+pub struct CutsceneScene;
+
+/// Cutscene started by `start` is played as soon as player is back to exploration,
+/// e.g. after the dialog which has triggered it.
+#[derive(Resource, Default)]
+pub struct CutscenePlayer {
+    pending: Option<usize>,
+    timeline: Option<Timeline>,
+    // Camera position before the cutscene, it is restored when the cutscene ends
+    camera_start: Vec2,
+    // Camera position and fade when the current step has started
+    camera_from: Vec2,
+    alpha_from: f32,
+    alpha: f32,
+}
+
+impl CutscenePlayer {
+    pub fn start(&mut self, cutscene_id: usize) {
+        self.pending = Some(cutscene_id);
+    }
+}
+
+/// Full screen fade, replicas are shown over it.
+#[derive(Component)]
+struct CutsceneScreen;
+
+#[derive(Component)]
+struct CutsceneReplica;
+
+impl Plugin for CutsceneScene {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(CutscenesStorage)
+            .init_resource::<CutscenePlayer>()
+            .add_systems(
+                Update,
+                cutscene_starts.run_if(in_state(GameState::Exploration)),
+            )
+            .add_systems(OnEnter(GameState::CatScene), spawn_main)
+            .add_systems(OnExit(GameState::CatScene), unspawn)
+            .add_systems(
+                Update,
+                (cutscene_skips, cutscene_plays)
+                    .chain()
+                    .run_if(in_state(GameState::CatScene)),
+            );
+    }
+}
+
+fn cutscene_starts(
+    storage: Res<CutscenesStorage>,
+    localization: Res<Localization>,
+    transition: Res<LevelTransition>,
+    mut player: ResMut<CutscenePlayer>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+) {
+    if transition.is_active() {
+        return;
+    }
+    let Some(cutscene_id) = player.pending.take() else {
+        return;
+    };
+    match storage.get_by_id(&cutscene_id) {
+        None => warn!("No cutscene with id {cutscene_id}"),
+        Some(cutscene) => {
+            player.timeline = Some(Timeline::new(cutscene.localized(&localization)));
+            next_game_state.set(GameState::CatScene);
+        }
+    }
+}
+
+fn spawn_main(
+    mut commands: Commands,
+    localization: Res<Localization>,
+    bindings: Res<InputBindings>,
+    mut player: ResMut<CutscenePlayer>,
+    camera_query: Query<&Transform, With<MainCamera>>,
+) {
+    player.alpha = 0.0;
+    if let Ok(transform) = camera_query.get_single() {
+        player.camera_start = transform.translation.truncate();
+    }
+    let skip_key = key_title(bindings.get(InputAction::Back).key);
+    let hint = localization.format("cutscene.skip", &[("key", skip_key)]);
+
+    commands
+        .ui_builder(UiRoot)
+        .column(|parent| {
+            parent
+                .configure_text(hint, TextConfig::small(Color::from(ANTIQUE_WHITE)))
+                .style()
+                .position_type(PositionType::Absolute)
+                .top(Val::Px(10.0))
+                .right(Val::Px(20.0));
+        })
+        .insert(CutsceneScreen)
+        .style()
+        .position_type(PositionType::Absolute)
+        .width(Val::Percent(100.0))
+        .height(Val::Percent(100.0))
+        .justify_content(JustifyContent::End)
+        .background_color(Color::BLACK.with_alpha(0.0));
+}
+
+fn spawn_replica(
+    commands: &mut Commands,
+    screen: Entity,
+    replica: &Replica,
+    localization: &Localization,
+    variables: &DialogVariables,
+) {
+    let name = match replica.speaker {
+        None => "".to_string(),
+        Some(speaker) => localization.get(speaker.name_key()),
+    };
+    let margin = UiRect {
+        left: Val::Px(20.0),
+        right: Val::Px(20.0),
+        top: Val::Px(10.0),
+        bottom: Val::Px(10.0),
+    };
+    commands
+        .ui_builder(screen)
+        .column(|parent| {
+            parent
+                .configure_text(name, TextConfig::small(Color::from(GOLD)))
+                .style()
+                .margin(margin);
+            parent
+                .configure_text(
+                    variables.interpolate(&replica.text),
+                    TextConfig::small(Color::from(ANTIQUE_WHITE)),
+                )
+                .style()
+                .margin(margin);
+        })
+        .insert(CutsceneReplica)
+        .style()
+        .align_items(AlignItems::Start)
+        .border(UiRect::all(Val::Px(5.0)))
+        .border_color(Color::from(ANTIQUE_WHITE))
+        .background_color(Color::from(DIM_GREY))
+        .height(Val::Percent(15.0))
+        .width(Val::Percent(100.0));
+}
+
+// Skipped cutscene is over immediately, only steps with lasting result are applied,
+// camera pans are not among them as the camera is restored when the cutscene ends
+fn cutscene_skips(
+    actions: Res<ButtonInput<InputAction>>,
+    variables: Res<DialogVariables>,
+    mut player: ResMut<CutscenePlayer>,
+    mut quest_events: EventWriter<QuestEvent>,
+    mut actors: Query<(&CutsceneActor, &mut Transform)>,
+) {
+    if !(actions.pressed(InputAction::Back) && actions.just_pressed(InputAction::Back)) {
+        return;
+    }
+    let Some(timeline) = &mut player.timeline else {
+        return;
+    };
+    for step in timeline.skip(&variables) {
+        match step {
+            CutsceneStep::MoveNpc { actor, to, .. } => {
+                for (_, mut transform) in actors.iter_mut().filter(|(name, _)| name.0 == actor) {
+                    transform.translation.x = to.x;
+                    transform.translation.y = to.y;
+                }
+            }
+            CutsceneStep::SetQuestState(event) => {
+                quest_events.send(event);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn cutscene_plays(
+    mut commands: Commands,
+    time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    localization: Res<Localization>,
+    variables: Res<DialogVariables>,
+    actions: Res<ButtonInput<InputAction>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut player: ResMut<CutscenePlayer>,
+    mut quest_events: EventWriter<QuestEvent>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+    mut actors: Query<(&CutsceneActor, &mut Transform, Option<&mut TextureAtlas>)>,
+    mut camera_query: Query<&mut Transform, (With<MainCamera>, Without<CutsceneActor>)>,
+    mut screen_query: Query<(Entity, &mut BackgroundColor), With<CutsceneScreen>>,
+    replica_query: Query<Entity, With<CutsceneReplica>>,
+) {
+    let CutscenePlayer {
+        timeline,
+        camera_from,
+        alpha_from,
+        alpha,
+        ..
+    } = player.as_mut();
+    let Some(current) = timeline.as_mut() else {
+        next_game_state.set(GameState::Exploration);
+        return;
+    };
+    let Some(step) = current.current(&variables) else {
+        *timeline = None;
+        next_game_state.set(GameState::Exploration);
+        return;
+    };
+    let is_started = current.start_step();
+    current.elapsed += time.delta_seconds();
+    let elapsed = current.elapsed;
+
+    let is_finished = match step {
+        CutsceneStep::MoveNpc { actor, to, speed } => {
+            let distance = speed * time.delta_seconds();
+            let mut is_arrived = true;
+            for (_, mut transform, _) in actors.iter_mut().filter(|(name, ..)| name.0 == actor) {
+                let position = transform.translation.truncate();
+                let next = if position.distance(to) <= distance {
+                    to
+                } else {
+                    position + (to - position).normalize() * distance
+                };
+                transform.translation.x = next.x;
+                transform.translation.y = next.y;
+                is_arrived &= next == to;
+            }
+            is_arrived
+        }
+        CutsceneStep::Animation {
+            actor,
+            first,
+            last,
+            frame_seconds,
+        } => {
+            let frame = first + (elapsed / frame_seconds) as usize;
+            for (_, _, sprite) in actors.iter_mut().filter(|(name, ..)| name.0 == actor) {
+                if let Some(mut sprite) = sprite {
+                    sprite.index = frame.min(last);
+                }
+            }
+            frame > last
+        }
+        CutsceneStep::Replica { replica, .. } => {
+            if is_started {
+                if let Ok((screen, _)) = screen_query.get_single() {
+                    spawn_replica(&mut commands, screen, &replica, &localization, &variables);
+                }
+                if let Some(sound) = &replica.sound {
+                    commands.play_sound(AudioBus::Sfx, asset_server.load(sound.clone()));
+                }
+            }
+            let is_confirmed = actions.just_pressed(InputAction::Confirm)
+                || actions.just_pressed(InputAction::Interact)
+                || mouse.just_pressed(MouseButton::Left);
+            if !is_started && is_confirmed {
+                for entity in replica_query.iter() {
+                    commands.entity(entity).despawn_recursive();
+                }
+                true
+            } else {
+                false
+            }
+        }
+        CutsceneStep::PanCamera { to, seconds } => {
+            let progress = (elapsed / seconds).min(1.0);
+            for mut transform in camera_query.iter_mut() {
+                if is_started {
+                    *camera_from = transform.translation.truncate();
+                }
+                let position = camera_from.lerp(to, progress);
+                transform.translation.x = position.x;
+                transform.translation.y = position.y;
+            }
+            progress >= 1.0
+        }
+        CutsceneStep::Wait(seconds) => elapsed >= seconds,
+        CutsceneStep::Fade {
+            alpha: target,
+            seconds,
+        } => {
+            if is_started {
+                *alpha_from = *alpha;
+            }
+            let progress = (elapsed / seconds).min(1.0);
+            *alpha = *alpha_from + (target - *alpha_from) * progress;
+            for (_, mut background) in screen_query.iter_mut() {
+                background.0 = Color::BLACK.with_alpha(*alpha);
+            }
+            progress >= 1.0
+        }
+        CutsceneStep::Sound(path) => {
+            commands.play_sound(AudioBus::Sfx, asset_server.load(path));
+            true
+        }
+        CutsceneStep::SetQuestState(event) => {
+            quest_events.send(event);
+            true
+        }
+    };
+    if is_finished {
+        current.next();
+    }
+}
+
+fn unspawn(
+    mut commands: Commands,
+    mut player: ResMut<CutscenePlayer>,
+    mut camera_query: Query<&mut Transform, With<MainCamera>>,
+    screen_query: Query<Entity, With<CutsceneScreen>>,
+) {
+    player.timeline = None;
+    for mut transform in camera_query.iter_mut() {
+        transform.translation.x = player.camera_start.x;
+        transform.translation.y = player.camera_start.y;
+    }
+    for entity in screen_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,21 @@
+use bevy::prelude::Resource;
+use bevy::utils::HashMap;
+
+use crate::cutscene::entities::Cutscene;
+use crate::level::cutscenes;
+
+#[derive(Resource)]
+pub struct CutscenesStorage;
+
+impl CutscenesStorage {
+    pub fn get_by_id(&self, id: &usize) -> Option<Cutscene> {
+        self.get_all().remove(id)
+    }
+
+    pub fn get_all(&self) -> HashMap<usize, Cutscene> {
+        cutscenes()
+            .into_iter()
+            .map(|cutscene| (cutscene.id, cutscene))
+            .collect()
+    }
+}
@@ -1,7 +1,6 @@
 use bevy::app::App;
 use bevy::app::Plugin;
 use bevy::app::Update;
-use bevy::audio::{AudioBundle, PlaybackSettings};
 use bevy::color::palettes::css::DIM_GREY;
 use bevy::hierarchy::DespawnRecursiveExt;
 use bevy::input::ButtonInput;
@@ -14,7 +13,6 @@ use bevy::prelude::Component;
 use bevy::prelude::Entity;
 use bevy::prelude::Interaction;
 use bevy::prelude::IntoSystemConfigs;
-use bevy::prelude::KeyCode;
 use bevy::prelude::NextState;
 use bevy::prelude::OnEnter;
 use bevy::prelude::OnExit;
@@ -38,8 +36,10 @@ use crate::core::states::GameState;
 use crate::dialog::{DialogId, DialogsStorage};
 use crate::fight::{FightId, FightStorage};
 use crate::gui::{TextButton, TextButtonExt};
-use crate::level::states::Level;
-use crate::sound::ButtonSounds;
+use crate::input::InputAction;
+use crate::level::registry::LevelRegistry;
+use crate::level::transitions::LevelTransition;
+use crate::sound::{AudioBus, AudioCommandsExt, ButtonSounds};
 
 pub struct DevSettingsPlugin;
 
@@ -84,10 +84,11 @@ impl Plugin for DevSettingsPlugin {
 
 fn mouse_input_handle(
     mut commands: Commands,
-    mut fight_id_query: Query<(&mut FightId)>,
-    mut dialog_id_query: Query<(&mut DialogId)>,
+    mut fight_id_query: Query<&mut FightId>,
+    mut dialog_id_query: Query<&mut DialogId>,
     mut next_game_state: ResMut<NextState<GameState>>,
-    mut next_level_state: ResMut<NextState<Level>>,
+    level_registry: Res<LevelRegistry>,
+    mut level_transition: ResMut<LevelTransition>,
     current_screen_state: Res<State<ScreenState>>,
     mut next_state: ResMut<NextState<ScreenState>>,
     mut query: Query<
@@ -105,10 +106,6 @@ fn mouse_input_handle(
                 *background_color = button.config.hover;
             }
             Interaction::Pressed => {
-                let default = AudioBundle {
-                    source: audio_res.click.clone(),
-                    settings: PlaybackSettings::ONCE,
-                };
                 match current_screen_state.get() {
                     ScreenState::Main => {
                         if button.payload == FIGHT_SAMPLES_BUTTON_ID {
@@ -157,23 +154,14 @@ fn mouse_input_handle(
                         return;
                     }
                     ScreenState::LevelsList => {
-                        if button.payload == HOUSE_LEVEL_BUTTON_ID {
-                            next_level_state.set(Level::House);
-                        }
-                        if button.payload == COURTHOUSE_FRONT_LEVEL_ID {
-                            next_level_state.set(Level::CourtHouseFront);
-                        }
-                        if button.payload == COURTHOUSE_HALL_LEVEL_ID {
-                            next_level_state.set(Level::CourtHouseHall);
-                        }
-                        if button.payload == COURTHOUSE_COURT_LEVEL_ID {
-                            next_level_state.set(Level::Court);
+                        if let Some(info) = level_registry.get_all().get(button.payload.0) {
+                            level_transition.start(info.level, info.default_spawn.to_string());
                         }
                         next_game_state.set(GameState::Exploration);
                         return;
                     }
                 }
-                commands.spawn(default);
+                commands.play_sound(AudioBus::Ui, audio_res.click.clone());
             }
         }
     }
@@ -182,13 +170,13 @@ fn mouse_input_handle(
 fn keyboard_input_handle(
     current_state: Res<State<ScreenState>>,
     mut next_state: ResMut<NextState<ScreenState>>,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    actions: Res<ButtonInput<InputAction>>,
 ) {
     if *current_state.get() == ScreenState::Main {
         return;
     }
 
-    if keyboard.pressed(KeyCode::Escape) && keyboard.just_pressed(KeyCode::Escape) {
+    if actions.pressed(InputAction::Back) && actions.just_pressed(InputAction::Back) {
         next_state.set(ScreenState::Main);
     }
 }
@@ -279,14 +267,13 @@ fn despawn_dialogs_list(mut commands: Commands, query: Query<Entity, With<Dialog
     commands.entity(entity).despawn_recursive();
 }
 
-fn spawn_levels_list(mut commands: Commands) {
+fn spawn_levels_list(mut commands: Commands, level_registry: Res<LevelRegistry>) {
     commands
         .ui_builder(UiRoot)
         .column(|parent| {
-            parent.text_button("House", HOUSE_LEVEL_BUTTON_ID);
-            parent.text_button("Courthouse front", COURTHOUSE_FRONT_LEVEL_ID);
-            parent.text_button("Courthouse hall", COURTHOUSE_HALL_LEVEL_ID);
-            parent.text_button("Court", COURTHOUSE_COURT_LEVEL_ID);
+            for (idx, info) in level_registry.get_all().iter().enumerate() {
+                parent.text_button(info.title, SettingsId(idx));
+            }
         })
         .insert(LevelsList)
         .style()
@@ -310,7 +297,3 @@ const DIALOGS_SAMPLES_BUTTON_ID: SettingsId = SettingsId(2);
 const LEVEL_SAMPLES_BUTTON_ID: SettingsId = SettingsId(3);
 const CHARACTER_SAMPLE_BUTTON_ID: SettingsId = SettingsId(4);
 const INVENTORY_AND_ABILITIES_SAMPLE_BUTTON_ID: SettingsId = SettingsId(5);
-const HOUSE_LEVEL_BUTTON_ID: SettingsId = SettingsId(11);
-const COURTHOUSE_FRONT_LEVEL_ID: SettingsId = SettingsId(12);
-const COURTHOUSE_HALL_LEVEL_ID: SettingsId = SettingsId(13);
-const COURTHOUSE_COURT_LEVEL_ID: SettingsId = SettingsId(14);
@@ -0,0 +1,92 @@
+use rand::Rng;
+
+use crate::dialog::DialogEffect;
+use crate::rpg::Characteristic;
+
+/// Check of the player characteristic. When it fails, variant leads to the failure stick.
+/// Result is kept in dialog variables as check.<name> for later dialogs and is sent to quests.
+#[derive(Clone, Debug)]
+pub struct SkillCheck {
+    pub name: String,
+    pub characteristic: Characteristic,
+    pub difficulty: i32,
+    pub dice: bool,
+    pub failure_stick_id: usize,
+    pub failure_effect: Option<DialogEffect>,
+}
+
+impl SkillCheck {
+    /// Without dice check is passed only when characteristic is not less than difficulty.
+    pub fn of(
+        name: &str,
+        characteristic: Characteristic,
+        difficulty: i32,
+        failure_stick_id: usize,
+    ) -> Self {
+        SkillCheck {
+            name: name.to_string(),
+            characteristic,
+            difficulty,
+            dice: false,
+            failure_stick_id,
+            failure_effect: None,
+        }
+    }
+
+    pub fn with_dice(mut self) -> Self {
+        self.dice = true;
+        self
+    }
+
+    pub fn with_failure_effect(mut self, effect: DialogEffect) -> Self {
+        self.failure_effect = Some(effect);
+        self
+    }
+
+    pub fn variable(&self) -> String {
+        format!("check.{}", self.name)
+    }
+
+    /// Percent of success, each point above or below difficulty changes it.
+    pub fn chance(&self, value: i32) -> i32 {
+        if !self.dice {
+            return if value >= self.difficulty { 100 } else { 0 };
+        }
+        (BASE_CHANCE + (value - self.difficulty) * CHANCE_PER_POINT).clamp(MIN_CHANCE, MAX_CHANCE)
+    }
+
+    /// Roll is a number from 1 to 100.
+    pub fn passes(&self, value: i32, roll: i32) -> bool {
+        roll <= self.chance(value)
+    }
+
+    pub fn perform(&self, value: i32) -> bool {
+        let roll = rand::thread_rng().gen_range(1..=100);
+        self.passes(value, roll)
+    }
+}
+
+const BASE_CHANCE: i32 = 50;
+const CHANCE_PER_POINT: i32 = 15;
+// There is always a chance to fail or to be lucky
+const MIN_CHANCE: i32 = 5;
+const MAX_CHANCE: i32 = 95;
+
+#[test]
+fn skill_check_chance_test() {
+    let fixed = SkillCheck::of("fixed", Characteristic::Strength, 3, 1);
+    let dice = SkillCheck::of("dice", Characteristic::Charisma, 3, 1).with_dice();
+
+    assert_eq!(fixed.chance(2), 0);
+    assert_eq!(fixed.chance(3), 100);
+    assert!(fixed.passes(3, 100));
+    assert!(!fixed.passes(2, 1));
+
+    assert_eq!(dice.chance(3), 50);
+    assert_eq!(dice.chance(1), 20);
+    assert_eq!(dice.chance(-10), 5);
+    assert_eq!(dice.chance(10), 95);
+    assert!(dice.passes(3, 50));
+    assert!(!dice.passes(3, 51));
+    assert_eq!(dice.variable(), "check.dice");
+}
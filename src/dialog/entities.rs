@@ -1,7 +1,17 @@
 use crate::dialog::graph::DialogStick;
+use crate::localization::Localization;
 use bevy::prelude::Component;
 use std::collections::HashMap;
 
+/// Pairs end id constants with their names for Dialog::with_end_names.
+macro_rules! end_names {
+    ($($end:ident),* $(,)?) => {
+        vec![$(($end, stringify!($end))),*]
+    };
+}
+
+pub(crate) use end_names;
+
 #[derive(Component)]
 pub struct Dialog {
     pub id: DialogId,
@@ -10,6 +20,7 @@ pub struct Dialog {
     pub character_path: String,
     pub root_id: usize,
     sticks: HashMap<usize, DialogStick>,
+    end_names: HashMap<usize, &'static str>,
 }
 
 impl Dialog {
@@ -28,18 +39,81 @@ impl Dialog {
             character_path,
             root_id,
             sticks,
+            end_names: HashMap::new(),
         }
     }
 
+    /// Names of end id constants, exported graphs show them instead of numbers.
+    pub fn with_end_names(mut self, end_names: Vec<(usize, &'static str)>) -> Dialog {
+        self.end_names.extend(end_names);
+        self
+    }
+
+    #[cfg(any(test, feature = "dev"))]
+    pub fn end_name(&self, end_id: usize) -> Option<&'static str> {
+        self.end_names.get(&end_id).copied()
+    }
+
     pub fn get_root_stick(&self) -> &DialogStick {
         self.get_stick_at(self.root_id)
     }
+    /// All sticks ordered by id.
+    #[cfg(any(test, feature = "dev"))]
+    pub fn get_sticks(&self) -> Vec<&DialogStick> {
+        let mut sticks: Vec<&DialogStick> = self.sticks.values().collect();
+        sticks.sort_by_key(|stick| stick.id);
+        sticks
+    }
     pub fn get_stick_at(&self, stick_id: usize) -> &DialogStick {
         self.sticks
             .get(&stick_id)
-            .expect(&format!("No stick with id {stick_id}"))
+            .unwrap_or_else(|| panic!("No stick with id {stick_id}"))
+    }
+
+    /// Replaces texts written in code with strings of the current language, if they exist.
+    pub fn localized(mut self, localization: &Localization) -> Dialog {
+        let dialog_id = self.id.0;
+        for stick in self.sticks.values_mut() {
+            for (pos, replica) in stick.replicas.iter_mut().enumerate() {
+                let key = replica_key(dialog_id, stick.id, pos);
+                replica.text = localization.get_or(&key, &replica.text);
+            }
+            if let Some(branching) = &mut stick.branching {
+                for (pos, variant) in branching.variants.iter_mut().enumerate() {
+                    let key = variant_key(dialog_id, stick.id, pos);
+                    variant.label = localization.get_or(&key, &variant.label);
+                }
+            }
+        }
+        self
+    }
+
+    /// Keys and original texts of all replicas and variants.
+    #[cfg(test)]
+    pub fn texts(&self) -> Vec<(String, String)> {
+        let dialog_id = self.id.0;
+        let mut result = vec![];
+        for stick in self.sticks.values() {
+            for (pos, replica) in stick.replicas.iter().enumerate() {
+                result.push((replica_key(dialog_id, stick.id, pos), replica.text.clone()));
+            }
+            if let Some(branching) = &stick.branching {
+                for (pos, variant) in branching.variants.iter().enumerate() {
+                    result.push((variant_key(dialog_id, stick.id, pos), variant.label.clone()));
+                }
+            }
+        }
+        result
     }
 }
 
+fn replica_key(dialog_id: usize, stick_id: usize, pos: usize) -> String {
+    format!("dialog.{dialog_id}.{stick_id}.{pos}")
+}
+
+fn variant_key(dialog_id: usize, stick_id: usize, pos: usize) -> String {
+    format!("dialog.{dialog_id}.{stick_id}.variant.{pos}")
+}
+
 #[derive(Component)]
 pub struct DialogId(pub usize);
@@ -0,0 +1,316 @@
+use std::collections::BTreeSet;
+
+use crate::dialog::{Dialog, DialogEffect, DialogStick};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphFormat {
+    Dot,
+    Mermaid,
+}
+
+impl GraphFormat {
+    pub fn from_name(name: &str) -> Option<GraphFormat> {
+        match name {
+            "dot" => Some(GraphFormat::Dot),
+            "mermaid" => Some(GraphFormat::Mermaid),
+            _ => None,
+        }
+    }
+}
+
+/// Draws dialog as a graph for review: sticks are nodes, variants are edges.
+/// Dashed edges show where dialog goes after the stick without branching.
+pub fn export_dialog(dialog: &Dialog, format: GraphFormat) -> String {
+    let graph = DialogGraph::build(dialog);
+    match format {
+        GraphFormat::Dot => graph.to_dot(dialog.id.0),
+        GraphFormat::Mermaid => graph.to_mermaid(),
+    }
+}
+
+enum NodeShape {
+    Start,
+    Stick,
+    End,
+}
+
+struct Node {
+    id: String,
+    label: String,
+    shape: NodeShape,
+}
+
+#[derive(PartialEq)]
+struct Edge {
+    from: String,
+    to: String,
+    label: String,
+    dashed: bool,
+}
+
+struct DialogGraph {
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+}
+
+impl DialogGraph {
+    fn build(dialog: &Dialog) -> DialogGraph {
+        let mut graph = DialogGraph {
+            nodes: vec![Node {
+                id: "start".to_string(),
+                label: "START".to_string(),
+                shape: NodeShape::Start,
+            }],
+            edges: vec![],
+        };
+        let mut end_ids = BTreeSet::new();
+        graph.add_edge("start".to_string(), stick_node(dialog.root_id), "", false);
+
+        for stick in dialog.get_sticks() {
+            graph.nodes.push(Node {
+                id: stick_node(stick.id),
+                label: stick_summary(stick),
+                shape: NodeShape::Stick,
+            });
+            let Some(branching) = &stick.branching else {
+                continue;
+            };
+            for (pos, variant) in branching.variants.iter().enumerate() {
+                let mut label = format!("{}. {}", pos + 1, summary(&variant.label));
+                if let Some(condition) = &variant.condition {
+                    label += &format!(" if {condition:?}");
+                }
+                if let Some(check) = &variant.check {
+                    let dice = if check.dice { " dice" } else { "" };
+                    label += &format!(" [{:?} {}{dice}]", check.characteristic, check.difficulty);
+                }
+                for action in &variant.actions {
+                    label += &format!(" {action:?}");
+                }
+                let mut outcomes = vec![(variant.stick_id, &variant.effect, label.clone())];
+                if let Some(check) = &variant.check {
+                    let failure_label = format!("{}. failed", pos + 1);
+                    outcomes.push((check.failure_stick_id, &check.failure_effect, failure_label));
+                }
+                for (target_id, effect, label) in outcomes {
+                    let label = label + &effect_summary(effect);
+                    graph.add_edge(stick_node(stick.id), stick_node(target_id), &label, false);
+                    match effect {
+                        Some(DialogEffect::EndDialog(end_id)) => {
+                            end_ids.insert(*end_id);
+                            graph.add_edge(stick_node(target_id), end_node(end_id), "", true);
+                        }
+                        Some(DialogEffect::ReplaceDialog) => {}
+                        None => {
+                            // stick without branching returns to the variants it was chosen from
+                            if dialog.get_stick_at(target_id).branching.is_none() {
+                                graph.add_edge(
+                                    stick_node(target_id),
+                                    stick_node(stick.id),
+                                    "",
+                                    true,
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        for end_id in end_ids {
+            let label = match end_id {
+                None => "END".to_string(),
+                Some(id) => match dialog.end_name(id) {
+                    None => format!("END {id}"),
+                    Some(name) => name.to_string(),
+                },
+            };
+            graph.nodes.push(Node {
+                id: end_node(&end_id),
+                label,
+                shape: NodeShape::End,
+            });
+        }
+        graph
+    }
+
+    fn add_edge(&mut self, from: String, to: String, label: &str, dashed: bool) {
+        let edge = Edge {
+            from,
+            to,
+            label: label.to_string(),
+            dashed,
+        };
+        if !self.edges.contains(&edge) {
+            self.edges.push(edge);
+        }
+    }
+
+    fn to_dot(&self, dialog_id: usize) -> String {
+        let mut result = format!("digraph dialog_{dialog_id} {{\n    node [shape=box];\n");
+        for node in &self.nodes {
+            let shape = match node.shape {
+                NodeShape::Start => ", shape=circle",
+                NodeShape::Stick => "",
+                NodeShape::End => ", shape=doublecircle",
+            };
+            result += &format!(
+                "    {} [label=\"{}\"{shape}];\n",
+                node.id,
+                escape_dot(&node.label)
+            );
+        }
+        for edge in &self.edges {
+            let style = if edge.dashed { ", style=dashed" } else { "" };
+            result += &format!(
+                "    {} -> {} [label=\"{}\"{style}];\n",
+                edge.from,
+                edge.to,
+                escape_dot(&edge.label)
+            );
+        }
+        result += "}\n";
+        result
+    }
+
+    fn to_mermaid(&self) -> String {
+        let mut result = "flowchart TD\n".to_string();
+        for node in &self.nodes {
+            let label = escape_mermaid(&node.label);
+            let node = match node.shape {
+                NodeShape::Start => format!("{}((\"{label}\"))", node.id),
+                NodeShape::Stick => format!("{}[\"{label}\"]", node.id),
+                NodeShape::End => format!("{}(((\"{label}\")))", node.id),
+            };
+            result += &format!("    {node}\n");
+        }
+        for edge in &self.edges {
+            let arrow = if edge.dashed { "-.->" } else { "-->" };
+            if edge.label.is_empty() {
+                result += &format!("    {} {arrow} {}\n", edge.from, edge.to);
+            } else {
+                result += &format!(
+                    "    {} {arrow}|\"{}\"| {}\n",
+                    edge.from,
+                    escape_mermaid(&edge.label),
+                    edge.to
+                );
+            }
+        }
+        result
+    }
+}
+
+fn stick_node(id: usize) -> String {
+    format!("stick_{id}")
+}
+
+fn end_node(end_id: &Option<usize>) -> String {
+    match end_id {
+        None => "end".to_string(),
+        Some(id) => format!("end_{id}"),
+    }
+}
+
+fn stick_summary(stick: &DialogStick) -> String {
+    match stick.replicas.first() {
+        None => format!("{}", stick.id),
+        Some(replica) if stick.replicas.len() == 1 => {
+            format!("{}: {}", stick.id, summary(&replica.text))
+        }
+        Some(replica) => format!(
+            "{}: {} (+{} replicas)",
+            stick.id,
+            summary(&replica.text),
+            stick.replicas.len() - 1
+        ),
+    }
+}
+
+fn effect_summary(effect: &Option<DialogEffect>) -> String {
+    match effect {
+        None => "".to_string(),
+        Some(DialogEffect::ReplaceDialog) => " [replace]".to_string(),
+        Some(DialogEffect::EndDialog(None)) => " [end]".to_string(),
+        Some(DialogEffect::EndDialog(Some(id))) => format!(" [end {id}]"),
+    }
+}
+
+fn summary(text: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.chars().count() <= SUMMARY_LENGTH {
+        return text;
+    }
+    let short: String = text.chars().take(SUMMARY_LENGTH).collect();
+    format!("{short}...")
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_mermaid(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+const SUMMARY_LENGTH: usize = 40;
+
+#[test]
+fn export_dialog_test() {
+    use crate::dialog::{end_names, Branching, DialogId, Replica, Variant};
+    use std::collections::HashMap;
+
+    const END_DIALOG_BYE: usize = 3;
+
+    let mut main_stick = DialogStick::from(0);
+    main_stick.replicas.extend(vec![
+        Replica::from_text("Say \"hello\"".to_string()),
+        Replica::from_text("Or go".to_string()),
+    ]);
+    let mut hello = DialogStick::from(1);
+    hello
+        .replicas
+        .push(Replica::from_text("Hello!".to_string()));
+    let mut bye = DialogStick::from(2);
+    bye.replicas.push(Replica::from_text("Bye!".to_string()));
+    main_stick.branching = Some(Branching {
+        variants: vec![
+            Variant::create("Hello".to_string(), hello.id),
+            Variant::create_with_effect(
+                "Bye".to_string(),
+                bye.id,
+                DialogEffect::EndDialog(Some(END_DIALOG_BYE)),
+            ),
+        ],
+    });
+    let mut sticks = HashMap::new();
+    sticks.insert(main_stick.id, main_stick);
+    sticks.insert(hello.id, hello);
+    sticks.insert(bye.id, bye);
+    let dialog = Dialog::from(
+        DialogId(42),
+        "".to_string(),
+        "".to_string(),
+        "".to_string(),
+        0,
+        sticks,
+    )
+    .with_end_names(end_names![END_DIALOG_BYE]);
+
+    assert_eq!(GraphFormat::from_name("dot"), Some(GraphFormat::Dot));
+    assert_eq!(GraphFormat::from_name("svg"), None);
+
+    let dot = export_dialog(&dialog, GraphFormat::Dot);
+    assert!(dot.starts_with("digraph dialog_42 {"));
+    assert!(dot.contains("stick_0 [label=\"0: Say \\\"hello\\\" (+1 replicas)\"];"));
+    assert!(dot.contains("stick_0 -> stick_1 [label=\"1. Hello\"];"));
+    assert!(dot.contains("stick_1 -> stick_0 [label=\"\", style=dashed];"));
+    assert!(dot.contains("stick_0 -> stick_2 [label=\"2. Bye [end 3]\"];"));
+    assert!(dot.contains("end_3 [label=\"END_DIALOG_BYE\", shape=doublecircle];"));
+
+    let mermaid = export_dialog(&dialog, GraphFormat::Mermaid);
+    assert!(mermaid.starts_with("flowchart TD\n"));
+    assert!(mermaid.contains("stick_0[\"0: Say #quot;hello#quot; (+1 replicas)\"]"));
+    assert!(mermaid.contains("stick_0 -->|\"2. Bye [end 3]\"| stick_2"));
+    assert!(mermaid.contains("stick_2 -.-> end_3"));
+}
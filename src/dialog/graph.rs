@@ -1,22 +1,18 @@
+#[cfg(test)]
 use std::collections::{HashMap, HashSet};
 
-#[derive(Clone, Debug)]
+use serde::Deserialize;
+
+use crate::dialog::checks::SkillCheck;
+use crate::dialog::variables::{DialogCondition, DialogValue};
+
+#[derive(Clone, Debug, Default)]
 pub struct DialogStick {
     pub id: usize,
     pub replicas: Vec<Replica>,
     pub branching: Option<Branching>,
 }
 
-impl Default for DialogStick {
-    fn default() -> Self {
-        DialogStick {
-            id: 0,
-            replicas: vec![],
-            branching: None,
-        }
-    }
-}
-
 impl DialogStick {
     pub fn replicas_size(&self) -> usize {
         self.replicas.len()
@@ -26,10 +22,6 @@ impl DialogStick {
         self.get_replica_at(0)
     }
 
-    pub fn last_replica(&self) -> &Replica {
-        self.get_replica_at(self.replicas.len() - 1)
-    }
-
     pub fn get_replica_at(&self, idx: usize) -> &Replica {
         &self.replicas[idx]
     }
@@ -47,37 +39,87 @@ impl DialogStick {
     }
 }
 
+/// Replica without speaker is a narration, it is shown without name plate.
+/// Portrait and sound paths are relative to assets, dialog portrait is used when it's not set.
 #[derive(Clone, Debug)]
 pub struct Replica {
     pub text: String,
+    pub speaker: Option<Speaker>,
+    pub portrait: Option<String>,
+    pub sound: Option<String>,
 }
 
 impl Replica {
     pub fn from_text(text: String) -> Replica {
-        Replica { text }
+        Replica {
+            text,
+            speaker: None,
+            portrait: None,
+            sound: None,
+        }
+    }
+
+    pub fn with_speaker(mut self, speaker: Speaker) -> Self {
+        self.speaker = Some(speaker);
+        self
+    }
+
+    pub fn with_portrait(mut self, path: &str) -> Self {
+        self.portrait = Some(path.to_string());
+        self
+    }
+
+    /// Own portrait, then the speaker one.
+    pub fn portrait_path(&self) -> Option<&str> {
+        self.portrait
+            .as_deref()
+            .or(self.speaker.and_then(|speaker| speaker.portrait()))
     }
 }
 
-#[derive(Clone, Debug)]
-pub struct Branching {
-    pub id: usize,
-    pub variants: Vec<Variant>,
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum Speaker {
+    GopnikLeader,
+    GopnikGiant,
+    ChiefJudge,
+    Kid,
+    Fatty,
+    OrdinaryBoy,
 }
 
-impl Default for Branching {
-    fn default() -> Self {
-        Branching {
-            id: 0,
-            variants: vec![],
+impl Speaker {
+    pub fn name_key(&self) -> &'static str {
+        match self {
+            Speaker::GopnikLeader => "speaker.gopnik_leader",
+            Speaker::GopnikGiant => "speaker.gopnik_giant",
+            Speaker::ChiefJudge => "speaker.chief_judge",
+            Speaker::Kid => "speaker.kid",
+            Speaker::Fatty => "speaker.fatty",
+            Speaker::OrdinaryBoy => "speaker.ordinary_boy",
+        }
+    }
+
+    fn portrait(&self) -> Option<&'static str> {
+        match self {
+            Speaker::GopnikLeader | Speaker::GopnikGiant => Some("npc/dialog/gopniks.png"),
+            _ => None,
         }
     }
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct Branching {
+    pub variants: Vec<Variant>,
+}
+
 #[derive(Clone, Debug)]
 pub struct Variant {
     pub label: String,
     pub stick_id: usize,
     pub effect: Option<DialogEffect>,
+    pub actions: Vec<DialogAction>,
+    pub condition: Option<DialogCondition>,
+    pub check: Option<SkillCheck>,
 }
 
 impl Variant {
@@ -86,6 +128,9 @@ impl Variant {
             label,
             stick_id,
             effect: Some(effect),
+            actions: vec![],
+            condition: None,
+            check: None,
         }
     }
 
@@ -94,8 +139,28 @@ impl Variant {
             label,
             stick_id,
             effect: None,
+            actions: vec![],
+            condition: None,
+            check: None,
         }
     }
+
+    pub fn with_action(mut self, action: DialogAction) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// Stick and effect of the variant are used when check is passed, actions are applied anyway.
+    pub fn with_check(mut self, check: SkillCheck) -> Self {
+        self.check = Some(check);
+        self
+    }
+
+    /// Branching should keep at least one variant without condition, otherwise dialog can stuck.
+    pub fn with_condition(mut self, condition: DialogCondition) -> Self {
+        self.condition = Some(condition);
+        self
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -104,6 +169,16 @@ pub enum DialogEffect {
     EndDialog(Option<usize>),
 }
 
+// Applied when variant is selected, independently of DialogEffect
+#[derive(Clone, Debug, PartialEq)]
+pub enum DialogAction {
+    GiveMoney(i32),
+    SetVariable(String, DialogValue),
+    // Item name as in maps and shops data, e.g. "dumplings"
+    GiveConsumable(String),
+}
+
+#[cfg(test)]
 fn dialog_run_str(dialog: (usize, HashMap<usize, DialogStick>), answers: Vec<usize>) -> String {
     let (root_id, pool) = dialog;
     let mut result = "".to_string();
@@ -168,6 +243,7 @@ fn dialog_run_str(dialog: (usize, HashMap<usize, DialogStick>), answers: Vec<usi
 //    |  | | |
 //    ---* * *
 //         end
+#[cfg(test)]
 fn test_dialog_0() -> (usize, HashMap<usize, DialogStick>) {
     let mut main_stick = DialogStick::from(0);
 
@@ -192,7 +268,6 @@ fn test_dialog_0() -> (usize, HashMap<usize, DialogStick>) {
         .push(Replica::from_text("Yes, get out here!".to_string()));
 
     main_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create("What is your name?".to_string(), question_stick.id),
             Variant::create_with_effect(
@@ -231,6 +306,7 @@ fn test_dialog_0() -> (usize, HashMap<usize, DialogStick>) {
 //   |  ^ ^
 //   |  | |
 //   -- * *
+#[cfg(test)]
 pub fn test_dialog_1() -> (usize, HashMap<usize, DialogStick>) {
     let mut main_stick = DialogStick::from(0);
 
@@ -257,7 +333,6 @@ pub fn test_dialog_1() -> (usize, HashMap<usize, DialogStick>) {
         .push(Replica::from_text("I don't care!".to_string()));
 
     question_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "Formidable Face!".to_string(),
@@ -283,7 +358,6 @@ pub fn test_dialog_1() -> (usize, HashMap<usize, DialogStick>) {
         .push(Replica::from_text("Yes, get out here!".to_string()));
 
     main_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create("What is your name?".to_string(), question_stick.id),
             Variant::create_with_effect(
@@ -333,6 +407,7 @@ pub fn test_dialog_1() -> (usize, HashMap<usize, DialogStick>) {
 //  |      end  |
 //  |<----------|
 //
+#[cfg(test)]
 pub fn test_dialog_2() -> (usize, HashMap<usize, DialogStick>) {
     let mut main_stick = DialogStick::from(0);
     main_stick.replicas.extend(
@@ -374,7 +449,6 @@ pub fn test_dialog_2() -> (usize, HashMap<usize, DialogStick>) {
         "Что вы... Грозный, ввваааааа! Эу...".to_string(),
     ));
     why_question_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "[Промолчать]".to_string(),
@@ -412,7 +486,6 @@ pub fn test_dialog_2() -> (usize, HashMap<usize, DialogStick>) {
         .replicas
         .push(Replica::from_text("Да что вы...! Эуу....!".to_string()));
     main_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create(
                 "Ыыыу, ето ошибка! Мне не должно ничего приходить!".to_string(),
@@ -609,4 +682,15 @@ fn test_dialog_22() {
     assert_eq!(expected, dialog_run_str(test_dialog_2(), vec![1, 0, 4]))
 }
 
-pub const INVALID_NODE_ID: usize = 0;
+#[test]
+fn replica_portrait_path_test() {
+    let narration = Replica::from_text("".to_string());
+    let leader = Replica::from_text("".to_string()).with_speaker(Speaker::GopnikLeader);
+    let angry_kid = Replica::from_text("".to_string())
+        .with_speaker(Speaker::Kid)
+        .with_portrait("npc/red_chad.png");
+
+    assert_eq!(narration.portrait_path(), None);
+    assert_eq!(leader.portrait_path(), Some("npc/dialog/gopniks.png"));
+    assert_eq!(angry_kid.portrait_path(), Some("npc/red_chad.png"));
+}
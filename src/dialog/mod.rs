@@ -1,15 +1,25 @@
+pub use checks::*;
 pub use entities::*;
 pub use graph::Branching;
+pub use graph::DialogAction;
 pub use graph::DialogEffect;
 pub use graph::DialogStick;
 pub use graph::Replica;
+pub use graph::Speaker;
 pub use graph::Variant;
 pub use plugin::*;
 pub use scene::*;
 pub use storages::*;
+pub use variables::*;
 
+mod checks;
 mod entities;
+// Only the dialog_export binary uses it, the game itself does not
+#[cfg(any(test, feature = "dev"))]
+#[allow(dead_code)]
+pub mod export;
 mod graph;
 mod plugin;
 mod scene;
 mod storages;
+mod variables;
@@ -1,16 +1,20 @@
-use crate::dialog::{DialogsStorage, SelectedVariantsSource};
-use bevy::app::{App, Plugin, Startup};
+use crate::dialog::{
+    party_variables_updates, DialogVariables, DialogsStorage, SelectedVariantsSource,
+};
+use bevy::app::{App, Plugin, Startup, Update};
 use bevy::prelude::Commands;
 
 pub struct DialogPlugin;
 
 impl Plugin for DialogPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, init_storages);
+        app.add_systems(Startup, init_storages)
+            .add_systems(Update, party_variables_updates);
     }
 }
 
 fn init_storages(mut commands: Commands) {
     commands.insert_resource(DialogsStorage);
     commands.insert_resource(SelectedVariantsSource::default());
+    commands.insert_resource(DialogVariables::default());
 }
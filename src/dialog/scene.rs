@@ -1,29 +1,34 @@
 use bevy::app::{App, Plugin};
 use bevy::asset::AssetServer;
-use bevy::color::palettes::css::{ANTIQUE_WHITE, DIM_GREY, WHITE};
-use bevy::color::Color;
+use bevy::color::palettes::css::{ANTIQUE_WHITE, DIM_GREY, GOLD, GREY};
+use bevy::color::{Color, Srgba};
 use bevy::hierarchy::{Children, DespawnRecursiveExt};
+use bevy::input::ButtonInput;
 use bevy::log::warn;
 use bevy::prelude::BackgroundColor;
 use bevy::prelude::Changed;
 use bevy::prelude::Commands;
 use bevy::prelude::Component;
+use bevy::prelude::DetectChanges;
+use bevy::prelude::DetectChangesMut;
 use bevy::prelude::Entity;
+use bevy::prelude::EventWriter;
+use bevy::prelude::IntoSystemConfigs;
 use bevy::prelude::JustifyContent;
-use bevy::prelude::NodeBundle;
+use bevy::prelude::MouseButton;
 use bevy::prelude::OnEnter;
 use bevy::prelude::OnExit;
 use bevy::prelude::Query;
 use bevy::prelude::Res;
+use bevy::prelude::Time;
 use bevy::prelude::UiRect;
 use bevy::prelude::Update;
 use bevy::prelude::Val;
 use bevy::prelude::With;
 use bevy::prelude::{
-    default, AlignItems, ImageBundle, Interaction, NextState, PositionType, ResMut, State, UiImage,
+    default, AlignItems, ImageBundle, Interaction, NextState, PositionType, ResMut, UiImage,
 };
 use bevy::ui::{AlignSelf, FocusPolicy};
-use bevy::utils::HashMap;
 use sickle_ui::prelude::SetAlignItemsExt;
 use sickle_ui::prelude::SetAlignSelfExt;
 use sickle_ui::prelude::SetBackgroundColorExt;
@@ -40,18 +45,24 @@ use sickle_ui::prelude::UiRoot;
 use sickle_ui::prelude::UiRowExt;
 use sickle_ui::prelude::UiScrollViewExt;
 use sickle_ui::prelude::{
-    ScrollAxis, SetBorderColorExt, SetBorderExt, SetLeftExt, SetPositionTypeExt, SetTopExt,
+    ScrollAxis, SetBorderColorExt, SetBorderExt, SetLeftExt, SetPositionTypeExt, SetRightExt,
+    SetTopExt,
 };
 use sickle_ui::ui_builder::UiBuilderExt;
 use sickle_ui::ui_commands::UpdateTextExt;
 
 use crate::core::states::GameState;
 use crate::dialog::{
-    Branching, Dialog, DialogEffect, DialogId, DialogStick, DialogsStorage, SelectedVariantsSource,
+    Branching, Dialog, DialogAction, DialogEffect, DialogId, DialogValue, DialogVariables,
+    DialogsStorage, Replica, SelectedVariantsSource, SkillCheck,
 };
-use crate::fight::FightId;
 use crate::gui::{ButtonConfig, TextButton, TextButtonExt, TextConfig, TextExt};
-use crate::world_state::EscapeFromHouse;
+use crate::localization::Localization;
+use crate::party::PartyStateStorage;
+use crate::rpg::{CharacterStorage, Class, ConsumableItem};
+use crate::settings::Settings;
+use crate::sound::{music_duck, music_unduck, AudioBus, AudioCommandsExt};
+use crate::world_state::QuestEvent;
 
 pub struct DialogScene;
 
@@ -65,48 +76,139 @@ struct DialogOptions;
 struct Sticks(Vec<(usize, usize)>);
 
 #[derive(Component)]
-struct CurrentReplica(String);
+struct CurrentReplica(Replica);
+
+#[derive(Component)]
+struct SpeakerName;
+
+#[derive(Component)]
+struct Portrait;
+
+/// Reveals replica text char by char, speed is taken from settings.
+#[derive(Component, Default)]
+struct Typewriter {
+    text: String,
+    shown: usize,
+    elapsed: f32,
+}
+
+impl Typewriter {
+    fn start(&mut self, text: String) {
+        self.text = text;
+        self.shown = 0;
+        self.elapsed = 0.0;
+    }
+
+    fn finish(&mut self) {
+        self.shown = self.text.chars().count();
+    }
+
+    fn is_finished(&self) -> bool {
+        self.shown >= self.text.chars().count()
+    }
+
+    /// Returns true if more chars became visible.
+    fn advance(&mut self, seconds: f32, chars_per_second: Option<f32>) -> bool {
+        if self.is_finished() {
+            return false;
+        }
+        let Some(speed) = chars_per_second else {
+            self.finish();
+            return true;
+        };
+        self.elapsed += seconds;
+        let shown = ((self.elapsed * speed) as usize).min(self.text.chars().count());
+        let advanced = shown > self.shown;
+        self.shown = shown;
+        advanced
+    }
+
+    fn visible_text(&self) -> String {
+        self.text.chars().take(self.shown).collect()
+    }
+}
 
 #[derive(Component)]
 struct CurrentBranching(Option<Branching>);
 
+/// Replicas and chosen variants of the current dialog, the newest is the last.
+#[derive(Component, Default)]
+struct Backlog(Vec<BacklogEntry>);
+
+enum BacklogEntry {
+    Replica(Replica),
+    Choice(String),
+    CheckResult(bool),
+}
+
+impl BacklogEntry {
+    fn line(&self, localization: &Localization) -> String {
+        match self {
+            BacklogEntry::Replica(replica) => match replica.speaker {
+                None => replica.text.clone(),
+                Some(speaker) => {
+                    format!("{}: {}", localization.get(speaker.name_key()), replica.text)
+                }
+            },
+            BacklogEntry::Choice(label) => format!("> {label}"),
+            BacklogEntry::CheckResult(true) => localization.get("dialog.check_passed"),
+            BacklogEntry::CheckResult(false) => localization.get("dialog.check_failed"),
+        }
+    }
+}
+
 #[derive(Component)]
-struct LastPhrase(String);
+struct BacklogButton;
+
+#[derive(Component)]
+struct BacklogPanel;
+
+/// Content of the opened backlog panel, keeps count of shown entries.
+#[derive(Component)]
+struct BacklogRows(usize);
 
 #[derive(Component)]
 struct OptionId(usize);
 
 impl Plugin for DialogScene {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(GameState::Dialog), spawn_main)
-            .add_systems(OnExit(GameState::Dialog), unspawn)
+        app.add_systems(OnEnter(GameState::Dialog), (spawn_main, music_duck))
+            .add_systems(OnExit(GameState::Dialog), (unspawn, music_unduck))
             .add_systems(Update, dialog_options_panel_respawns)
             .add_systems(
                 Update,
                 (
                     option_input_handle,
+                    typewriter_skip_on_click,
                     dialog_options_updates,
                     current_replica_updates,
-                ),
-            );
+                    backlog_updates,
+                    typewriter_updates,
+                )
+                    .chain(),
+            )
+            .add_systems(Update, (backlog_input_handle, backlog_panel_updates));
     }
 }
 
 fn spawn_main(
     mut commands: Commands,
-    query: Query<(&DialogId)>,
+    query: Query<&DialogId>,
     asset_server: Res<AssetServer>,
     dialogs_storage: Res<DialogsStorage>,
+    localization: Res<Localization>,
+    variables: Res<DialogVariables>,
 ) {
     let dialog_id = query.single();
     let dialog = dialogs_storage
         .get_by_id(&dialog_id.0)
-        .expect(&format!("No dialog with id {}", &dialog_id.0));
+        .unwrap_or_else(|| panic!("No dialog with id {}", &dialog_id.0))
+        .localized(&localization);
     let root_stick = dialog.get_root_stick();
     let current_replica = if root_stick.replicas_size() > 0 {
-        CurrentReplica(root_stick.first_replica().text.clone())
+        CurrentReplica(interpolated(root_stick.first_replica(), &variables))
     } else {
-        CurrentReplica("".to_string())
+        CurrentReplica(Replica::from_text("".to_string()))
     };
     let current_branching = if root_stick.replicas_size() > 0 {
         CurrentBranching(None)
@@ -138,8 +240,9 @@ fn spawn_main(
                                     },
                                     ..default()
                                 },
-                                |parent| {},
+                                |_parent| {},
                             )
+                            .insert(Portrait)
                             .style()
                             .width(Val::Auto)
                             .height(Val::Percent(70.0))
@@ -154,16 +257,44 @@ fn spawn_main(
 
             parent
                 .column(|parent| {
+                    parent
+                        .configure_text("", TextConfig::small(Color::from(GOLD)))
+                        .insert(SpeakerName)
+                        .style()
+                        .margin(UiRect {
+                            left: Val::Px(20.0),
+                            right: Val::Px(20.0),
+                            top: Val::Px(10.0),
+                            bottom: Val::Px(0.0),
+                        });
                     parent
                         .configure_text("", TextConfig::small(Color::from(ANTIQUE_WHITE)))
-                        .insert(current_replica)
+                        .insert((current_replica, Typewriter::default()))
                         .style()
                         .margin(UiRect {
                             left: Val::Px(20.0),
                             right: Val::Px(20.0),
-                            top: Val::Px(20.0),
+                            top: Val::Px(10.0),
                             bottom: Val::Px(20.0),
                         });
+                    parent
+                        .configure_text_button(
+                            localization.get("dialog.backlog"),
+                            BacklogButton,
+                            TextConfig::small(Color::from(ANTIQUE_WHITE)),
+                            ButtonConfig {
+                                width: Val::Px(200.0),
+                                height: Val::Px(40.0),
+                                idle: BackgroundColor::from(Color::NONE),
+                                hover: BackgroundColor::from(PRESSED_HOVER_BUTTON_COLOR),
+                                pressed: BackgroundColor::from(PRESSED_HOVER_BUTTON_COLOR),
+                                justify_content: JustifyContent::Center,
+                            },
+                        )
+                        .style()
+                        .position_type(PositionType::Absolute)
+                        .top(Val::Px(5.0))
+                        .right(Val::Px(5.0));
                 })
                 .style()
                 .justify_content(JustifyContent::Start)
@@ -184,6 +315,7 @@ fn spawn_main(
             current_branching,
             dialog,
             Sticks(id_to_replica_position),
+            Backlog::default(),
         ))
         .style()
         .justify_content(JustifyContent::Start)
@@ -194,10 +326,23 @@ fn spawn_main(
 
 fn dialog_options_panel_respawns(
     mut commands: Commands,
+    localization: Res<Localization>,
+    variables: Res<DialogVariables>,
+    party_storage: Res<PartyStateStorage>,
+    character_storage: Res<CharacterStorage>,
+    dialog_query: Query<(&Dialog, &Sticks)>,
     options_query: Query<Entity, With<DialogOptions>>,
-    branching_query: Query<(&CurrentBranching), Changed<CurrentBranching>>,
+    branching_query: Query<&CurrentBranching, Changed<CurrentBranching>>,
 ) {
     for branching in branching_query.iter() {
+        let current = dialog_query
+            .get_single()
+            .ok()
+            .and_then(|(dialog, sticks)| Some((dialog.id.0, sticks.0.last()?.0)));
+        let is_seen = |pos: usize| match current {
+            Some((dialog_id, stick_id)) => party_storage.is_variant_seen(dialog_id, stick_id, pos),
+            None => false,
+        };
         for entity in options_query.iter() {
             commands.entity(entity).despawn_recursive();
         }
@@ -207,12 +352,37 @@ fn dialog_options_panel_respawns(
                 parent
                     .scroll_view(Some(ScrollAxis::Vertical), |parent| match &branching.0 {
                         None => {
-                            option_button(parent, BTN_NEXT_ID, "NEXT".to_string());
+                            option_button(
+                                parent,
+                                BTN_NEXT_ID,
+                                localization.get("dialog.next"),
+                                ANTIQUE_WHITE,
+                            );
                         }
                         Some(value) => {
-                            let variants = &value.variants;
-                            for (pos, item) in variants.iter().enumerate() {
-                                option_button(parent, pos, format!("{}. {}", pos + 1, item.label));
+                            let variants = value.variants.iter().enumerate().filter(|(_, item)| {
+                                item.condition
+                                    .as_ref()
+                                    .is_none_or(|condition| condition.is_met(&variables))
+                            });
+                            // buttons keep position of variant in branching, numbers count shown ones only
+                            for (number, (pos, item)) in variants.enumerate() {
+                                // already chosen variants are greyed out, but still can be chosen
+                                let color = if is_seen(pos) { GREY } else { ANTIQUE_WHITE };
+                                let mut label = variables.interpolate(&item.label);
+                                if let Some(check) = &item.check {
+                                    let class = character_storage.get_class_by_id(PLAYER_ID);
+                                    label = format!(
+                                        "{} {label}",
+                                        check_title(check, class, &localization)
+                                    );
+                                }
+                                option_button(
+                                    parent,
+                                    pos,
+                                    format!("{}. {}", number + 1, label),
+                                    color,
+                                );
                             }
                         }
                     })
@@ -238,12 +408,12 @@ fn dialog_options_panel_respawns(
     }
 }
 
-fn option_button(parent: &mut UiBuilder<Entity>, id: usize, label: String) {
+fn option_button(parent: &mut UiBuilder<Entity>, id: usize, label: String, color: Srgba) {
     parent
         .configure_text_button(
             label,
             OptionId(id),
-            TextConfig::small(Color::from(ANTIQUE_WHITE)),
+            TextConfig::small(Color::from(color)),
             ButtonConfig {
                 width: Val::Percent(100.0),
                 height: Val::Px(40.0),
@@ -260,11 +430,16 @@ fn option_button(parent: &mut UiBuilder<Entity>, id: usize, label: String) {
 
 fn option_input_handle(
     mut dialog_variant_source: ResMut<SelectedVariantsSource>,
-    query: Query<(&DialogId)>,
+    mut quest_events: EventWriter<QuestEvent>,
+    mut party_storage: ResMut<PartyStateStorage>,
+    query: Query<&DialogId>,
     mut game_state: ResMut<NextState<GameState>>,
     mut sticks_query: Query<&mut Sticks>,
     dialog_query: Query<&Dialog>,
-    mut replica_query: Query<&mut CurrentReplica>,
+    mut replica_query: Query<(&mut CurrentReplica, &mut Typewriter)>,
+    mut backlog_query: Query<&mut Backlog>,
+    mut variables: ResMut<DialogVariables>,
+    character_storage: Res<CharacterStorage>,
     branching_query: Query<&CurrentBranching>,
     mut button_query: Query<
         (&TextButton<OptionId>, &Interaction, &mut BackgroundColor),
@@ -280,19 +455,25 @@ fn option_input_handle(
                 *background_color = button.config.hover;
             }
             Interaction::Pressed => {
-                let mut stack = &mut sticks_query.single_mut().0;
+                let stack = &mut sticks_query.single_mut().0;
                 if stack.is_empty() {
                     return;
                 }
                 let last_idx = stack.len() - 1;
                 if button.payload.0 == BTN_NEXT_ID {
+                    let mut typewriter = replica_query.single_mut().1;
+                    if !typewriter.is_finished() {
+                        typewriter.finish();
+                        return;
+                    }
                     let (id, mut pos) = stack.last().expect("No value in stack");
                     let stick = dialog_query.single().get_stick_at(*id);
                     if pos + 1 < stick.replicas_size() {
                         pos += 1;
                         stack[last_idx].1 = pos;
 
-                        replica_query.single_mut().0 = stick.get_replica_at(pos).text.clone();
+                        let (mut replica, _) = replica_query.single_mut();
+                        replica.0 = interpolated(stick.get_replica_at(pos), &variables);
                     }
 
                     if pos == stick.replicas_size() - 1 {
@@ -310,24 +491,66 @@ fn option_input_handle(
                     None => {}
                     Some(branching) => {
                         let selected = &branching.variants[button.payload.0];
-                        match &selected.effect {
+                        let dialog_id = query.single();
+                        party_storage.mark_variant_seen(
+                            dialog_id.0,
+                            stack[last_idx].0,
+                            button.payload.0,
+                        );
+                        backlog_query
+                            .single_mut()
+                            .0
+                            .push(BacklogEntry::Choice(variables.interpolate(&selected.label)));
+                        let passed = match &selected.check {
+                            None => true,
+                            Some(check) => {
+                                let class = character_storage.get_class_by_id(PLAYER_ID);
+                                let passed =
+                                    check.perform(class.characteristic(check.characteristic));
+                                variables.set(&check.variable(), DialogValue::Flag(passed));
+                                backlog_query
+                                    .single_mut()
+                                    .0
+                                    .push(BacklogEntry::CheckResult(passed));
+                                quest_events.send(QuestEvent::SkillChecked {
+                                    name: check.name.clone(),
+                                    passed,
+                                });
+                                passed
+                            }
+                        };
+                        // actions are the reward of the variant, failed check gives nothing
+                        if passed {
+                            apply_actions(&selected.actions, &mut party_storage, &mut variables);
+                        }
+                        let (stick_id, effect) = match &selected.check {
+                            Some(check) if !passed => {
+                                (check.failure_stick_id, &check.failure_effect)
+                            }
+                            _ => (selected.stick_id, &selected.effect),
+                        };
+                        match effect {
                             None => {}
                             Some(effect) => match effect {
                                 DialogEffect::ReplaceDialog => {
                                     stack.pop();
                                 }
                                 DialogEffect::EndDialog(end_id) => {
-                                    let dialog_id = query.single();
                                     if let Some(id) = end_id {
                                         dialog_variant_source.produce(dialog_id.0, *id);
+                                        quest_events.send(QuestEvent::DialogEnded {
+                                            dialog_id: dialog_id.0,
+                                            end_id: *id,
+                                        });
                                     }
                                     stack.clear();
                                 }
                             },
                         }
-                        let stick = dialog_query.single().get_stick_at(selected.stick_id);
-                        replica_query.single_mut().0 = stick.first_replica().text.clone();
-                        stack.push((selected.stick_id, 0));
+                        let stick = dialog_query.single().get_stick_at(stick_id);
+                        let (mut replica, _) = replica_query.single_mut();
+                        replica.0 = interpolated(stick.first_replica(), &variables);
+                        stack.push((stick_id, 0));
                     }
                 }
             }
@@ -335,6 +558,42 @@ fn option_input_handle(
     }
 }
 
+fn apply_actions(
+    actions: &Vec<DialogAction>,
+    party_storage: &mut PartyStateStorage,
+    variables: &mut DialogVariables,
+) {
+    for action in actions {
+        match action {
+            DialogAction::GiveMoney(amount) => party_storage.add_money(*amount),
+            DialogAction::SetVariable(name, value) => variables.set(name, value.clone()),
+            DialogAction::GiveConsumable(name) => match ConsumableItem::from_name(name) {
+                Some(item) => party_storage.add_consumable(item),
+                None => warn!("Unknown consumable {name} in dialog action"),
+            },
+        }
+    }
+}
+
+fn check_title(check: &SkillCheck, class: &Class, localization: &Localization) -> String {
+    let name = check.characteristic.name(localization);
+    let value = class.characteristic(check.characteristic);
+    if check.dice {
+        let chance = check.chance(value).to_string();
+        localization.format("dialog.check_chance", &[("name", name), ("chance", chance)])
+    } else {
+        let value = format!("{value}/{}", check.difficulty);
+        localization.format("dialog.check", &[("name", name), ("value", value)])
+    }
+}
+
+fn interpolated(replica: &Replica, variables: &DialogVariables) -> Replica {
+    Replica {
+        text: variables.interpolate(&replica.text),
+        ..replica.clone()
+    }
+}
+
 fn dialog_options_updates(
     sticks_query: Query<&Sticks, Changed<Sticks>>,
     dialog_query: Query<&Dialog>,
@@ -360,31 +619,214 @@ fn dialog_options_updates(
 
 fn current_replica_updates(
     mut commands: Commands,
-    mut replica_query: Query<(&Children, &CurrentReplica), Changed<CurrentReplica>>,
+    asset_server: Res<AssetServer>,
+    localization: Res<Localization>,
+    dialog_query: Query<&Dialog>,
+    mut replica_query: Query<(&CurrentReplica, &mut Typewriter), Changed<CurrentReplica>>,
+    speaker_query: Query<&Children, With<SpeakerName>>,
+    mut portrait_query: Query<&mut UiImage, With<Portrait>>,
 ) {
-    for (children, replica) in replica_query.iter_mut() {
-        for &child in children.iter() {
-            match commands.get_entity(child) {
-                None => {
-                    warn!("Current replica component is not found")
-                }
-                Some(mut entity_commands) => {
-                    entity_commands.update_text(replica.0.clone());
+    for (replica, mut typewriter) in replica_query.iter_mut() {
+        let replica = &replica.0;
+        typewriter.start(replica.text.clone());
+
+        let name = match replica.speaker {
+            None => "".to_string(),
+            Some(speaker) => localization.get(speaker.name_key()),
+        };
+        for children in speaker_query.iter() {
+            update_children_text(&mut commands, children, name.clone());
+        }
+
+        if let Ok(dialog) = dialog_query.get_single() {
+            let path = replica
+                .portrait_path()
+                .unwrap_or(&dialog.character_path)
+                .to_string();
+            for mut image in portrait_query.iter_mut() {
+                image.texture = asset_server.load(path.clone());
+            }
+        }
+
+        if let Some(sound) = &replica.sound {
+            commands.play_sound(AudioBus::Sfx, asset_server.load(sound.clone()));
+        }
+    }
+}
+
+fn backlog_updates(
+    replica_query: Query<&CurrentReplica, Changed<CurrentReplica>>,
+    mut backlog_query: Query<&mut Backlog>,
+) {
+    for replica in replica_query.iter() {
+        if replica.0.text.is_empty() {
+            continue;
+        }
+        for mut backlog in backlog_query.iter_mut() {
+            backlog.0.push(BacklogEntry::Replica(replica.0.clone()));
+        }
+    }
+}
+
+fn backlog_input_handle(
+    mut commands: Commands,
+    localization: Res<Localization>,
+    backlog_query: Query<&Backlog>,
+    panel_query: Query<Entity, With<BacklogPanel>>,
+    mut button_query: Query<
+        (
+            &TextButton<BacklogButton>,
+            &Interaction,
+            &mut BackgroundColor,
+        ),
+        Changed<Interaction>,
+    >,
+) {
+    for (button, interaction, mut background_color) in &mut button_query {
+        match *interaction {
+            Interaction::None => {
+                *background_color = button.config.idle;
+            }
+            Interaction::Hovered => {
+                *background_color = button.config.hover;
+            }
+            Interaction::Pressed => {
+                if panel_query.is_empty() {
+                    spawn_backlog_panel(&mut commands, &localization, backlog_query.single());
+                } else {
+                    for entity in panel_query.iter() {
+                        commands.entity(entity).despawn_recursive();
+                    }
                 }
             }
         }
     }
 }
 
+// Keeps opened panel actual, while dialog goes on
+fn backlog_panel_updates(
+    mut commands: Commands,
+    localization: Res<Localization>,
+    backlog_query: Query<&Backlog, Changed<Backlog>>,
+    mut rows_query: Query<(Entity, &mut BacklogRows)>,
+) {
+    for backlog in backlog_query.iter() {
+        for (entity, mut rows) in rows_query.iter_mut() {
+            // New entries are appended, so the scroll position stays
+            let mut builder = commands.ui_builder(entity);
+            for entry in backlog.0.iter().skip(rows.0) {
+                spawn_backlog_row(&mut builder, &localization, entry);
+            }
+            rows.0 = backlog.0.len();
+        }
+    }
+}
+
+fn spawn_backlog_panel(commands: &mut Commands, localization: &Localization, backlog: &Backlog) {
+    commands
+        .ui_builder(UiRoot)
+        .column(|parent| {
+            parent
+                .scroll_view(Some(ScrollAxis::Vertical), |parent| {
+                    for entry in &backlog.0 {
+                        spawn_backlog_row(parent, localization, entry);
+                    }
+                    parent.insert(BacklogRows(backlog.0.len()));
+                })
+                .style()
+                .width(Val::Percent(100.0))
+                .height(Val::Percent(100.0));
+        })
+        .insert(BacklogPanel)
+        .style()
+        .position_type(PositionType::Absolute)
+        .top(Val::Px(0.0))
+        .width(Val::Percent(100.0))
+        .height(Val::Percent(60.0))
+        .border(UiRect::all(Val::Px(5.0)))
+        .border_color(Color::from(ANTIQUE_WHITE))
+        .background_color(Color::from(BACKLOG_BG));
+}
+
+fn spawn_backlog_row(
+    parent: &mut UiBuilder<Entity>,
+    localization: &Localization,
+    entry: &BacklogEntry,
+) {
+    let color = match entry {
+        BacklogEntry::Replica(_) => ANTIQUE_WHITE,
+        BacklogEntry::Choice(_) | BacklogEntry::CheckResult(_) => GOLD,
+    };
+    parent
+        .configure_text(
+            entry.line(localization),
+            TextConfig::small(Color::from(color)),
+        )
+        .style()
+        .margin(UiRect::all(Val::Px(10.0)));
+}
+
+fn typewriter_updates(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<Settings>,
+    mut typewriter_query: Query<(&Children, &mut Typewriter)>,
+) {
+    let speed = settings.text_speed.chars_per_second();
+    for (children, mut typewriter) in typewriter_query.iter_mut() {
+        if typewriter
+            .bypass_change_detection()
+            .advance(time.delta_seconds(), speed)
+        {
+            typewriter.set_changed();
+        }
+        if typewriter.is_changed() {
+            update_children_text(&mut commands, children, typewriter.visible_text());
+        }
+    }
+}
+
+fn typewriter_skip_on_click(
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut typewriter_query: Query<&mut Typewriter>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+    for mut typewriter in typewriter_query.iter_mut() {
+        if !typewriter.is_finished() {
+            typewriter.finish();
+        }
+    }
+}
+
+fn update_children_text(commands: &mut Commands, children: &Children, text: String) {
+    for &child in children.iter() {
+        match commands.get_entity(child) {
+            None => {
+                warn!("Text component is not found")
+            }
+            Some(mut entity_commands) => {
+                entity_commands.update_text(text.clone());
+            }
+        }
+    }
+}
+
 fn unspawn(
     mut commands: Commands,
     options_query: Query<Entity, With<DialogOptions>>,
+    backlog_query: Query<Entity, With<BacklogPanel>>,
     panel_query: Query<Entity, With<DialogSceneScreen>>,
 ) {
     for entity in options_query.iter() {
         commands.entity(entity).despawn_recursive();
     }
 
+    for entity in backlog_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
     for entity in panel_query.iter() {
         commands.entity(entity).despawn_recursive();
     }
@@ -393,3 +835,39 @@ fn unspawn(
 /// <div style="background-color:rgb(30%, 30%, 30%); width: 10px; padding: 10px; border: 1px solid;"></div>
 const PRESSED_HOVER_BUTTON_COLOR: Color = Color::srgba(0.50, 0.50, 0.50, 0.7);
 const BTN_NEXT_ID: usize = 999;
+// Checks are performed by the main character
+const PLAYER_ID: usize = 0;
+/// <div style="background-color:rgb(20%, 20%, 20%); width: 10px; padding: 10px; border: 1px solid;"></div>
+const BACKLOG_BG: Srgba = Srgba::new(0.2, 0.2, 0.2, 0.95);
+
+#[test]
+fn typewriter_advance_test() {
+    let mut typewriter = Typewriter::default();
+    typewriter.start("Привет".to_string());
+
+    assert!(!typewriter.advance(0.01, Some(10.0)));
+    assert!(typewriter.advance(0.2, Some(10.0)));
+    assert_eq!(typewriter.visible_text(), "Пр");
+
+    assert!(typewriter.advance(10.0, Some(10.0)));
+    assert!(typewriter.is_finished());
+    assert_eq!(typewriter.visible_text(), "Привет");
+
+    typewriter.start("Пока".to_string());
+    assert!(typewriter.advance(0.0, None));
+    assert_eq!(typewriter.visible_text(), "Пока");
+}
+
+#[test]
+fn backlog_entry_line_test() {
+    let localization = Localization::load(crate::settings::Language::English);
+    let narration = BacklogEntry::Replica(Replica::from_text("It's dark".to_string()));
+    let kid = BacklogEntry::Replica(
+        Replica::from_text("Two!".to_string()).with_speaker(crate::dialog::Speaker::Kid),
+    );
+    let choice = BacklogEntry::Choice("1. Run".to_string());
+
+    assert_eq!(narration.line(&localization), "It's dark");
+    assert_eq!(kid.line(&localization), "Kid: Two!");
+    assert_eq!(choice.line(&localization), "> 1. Run");
+}
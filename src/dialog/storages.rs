@@ -10,6 +10,8 @@ use crate::level::gopniks_dialog;
 use crate::level::guardian_first_dialog;
 use crate::level::guardian_second_dialog;
 use crate::level::guardian_third_dialog;
+use crate::level::judges_second_dialog;
+use crate::level::judges_third_dialog;
 use crate::level::old_woman_drevnira_dialog;
 use crate::level::sleeping_formidable_dog_dialog;
 use crate::level::BLOND_GIVE_DUMPLINGS_DIALOG;
@@ -20,6 +22,8 @@ use crate::level::GOPNIKS_DIALOG;
 use crate::level::GUARDIAN_FIRST_DIALOG;
 use crate::level::GUARDIAN_SECOND_DIALOG;
 use crate::level::GUARDIAN_THIRD_DIALOG;
+use crate::level::JUDGES_SECOND_DIALOG;
+use crate::level::JUDGES_THIRD_DIALOG;
 use crate::level::SLEEPING_FORMIDABLE_DOG_DIALOG;
 use crate::level::{
     crazy_man_dialog, hall_guardian_first_dialog, hall_guardian_second_dialog, judges_first_dialog,
@@ -63,6 +67,8 @@ fn test_dialogs() -> HashMap<usize, Dialog> {
     result.insert(TABLE_3_DIALOG, table_3_dialog());
     result.insert(CRAZY_MAN_DIALOG, crazy_man_dialog());
     result.insert(JUDGES_FIRST_DIALOG, judges_first_dialog());
+    result.insert(JUDGES_SECOND_DIALOG, judges_second_dialog());
+    result.insert(JUDGES_THIRD_DIALOG, judges_third_dialog());
 
     result
 }
@@ -72,7 +78,7 @@ pub struct SelectedVariantsSource(HashMap<usize, Vec<usize>>);
 
 impl SelectedVariantsSource {
     pub fn produce(&mut self, dialog_id: usize, variant_id: usize) {
-        let mut variants = self.0.get_mut(&dialog_id);
+        let variants = self.0.get_mut(&dialog_id);
         match variants {
             None => {
                 self.0.insert(dialog_id, vec![variant_id]);
@@ -87,3 +93,47 @@ impl SelectedVariantsSource {
         self.0.remove(dialog_id)
     }
 }
+
+#[test]
+fn dialogs_branchings_unconditional_variant_test() {
+    for dialog in DialogsStorage.get_all().values() {
+        for stick in dialog.get_sticks() {
+            if let Some(branching) = &stick.branching {
+                assert!(
+                    branching
+                        .variants
+                        .iter()
+                        .any(|variant| variant.condition.is_none()),
+                    "Stick {} of dialog {} has only conditional variants",
+                    stick.id,
+                    dialog.id.0
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn dialogs_end_names_test() {
+    use crate::dialog::DialogEffect;
+
+    for dialog in DialogsStorage.get_all().values() {
+        for stick in dialog.get_sticks() {
+            let Some(branching) = &stick.branching else {
+                continue;
+            };
+            for variant in &branching.variants {
+                let check_effect = variant.check.as_ref().map(|check| &check.failure_effect);
+                for effect in [Some(&variant.effect), check_effect].into_iter().flatten() {
+                    if let Some(DialogEffect::EndDialog(Some(end_id))) = effect {
+                        assert!(
+                            dialog.end_name(*end_id).is_some(),
+                            "End {end_id} of dialog {} has no name",
+                            dialog.id.0
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
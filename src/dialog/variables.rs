@@ -0,0 +1,178 @@
+use std::fmt::{Debug, Display, Formatter};
+
+use bevy::prelude::DetectChanges;
+use bevy::prelude::Res;
+use bevy::prelude::ResMut;
+use bevy::prelude::Resource;
+use bevy::prelude::State;
+use bevy::prelude::States;
+use bevy::utils::HashMap;
+
+use crate::localization::Localization;
+use crate::party::PartyStateStorage;
+use crate::rpg::ConsumableItem;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DialogValue {
+    Number(i32),
+    Text(String),
+    Flag(bool),
+}
+
+impl Display for DialogValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DialogValue::Number(value) => write!(f, "{value}"),
+            DialogValue::Text(value) => write!(f, "{value}"),
+            DialogValue::Flag(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// Values which dialogs can mention in texts and check in variant conditions.
+/// Party, inventory and quests values are kept in sync by systems,
+/// the rest are set by selected variants and live for the whole game.
+#[derive(Resource, Default)]
+pub struct DialogVariables(HashMap<String, DialogValue>);
+
+impl DialogVariables {
+    pub fn set(&mut self, name: &str, value: DialogValue) {
+        self.0.insert(name.to_string(), value);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&DialogValue> {
+        self.0.get(name)
+    }
+
+    /// Replaces {name} with value of the variable. Unknown names are left as is.
+    pub fn interpolate(&self, text: &str) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find('{') {
+            result.push_str(&rest[..start]);
+            let after = &rest[start + 1..];
+            match after.find('}') {
+                None => {
+                    rest = &rest[start..];
+                    break;
+                }
+                Some(end) => {
+                    let name = &after[..end];
+                    match self.get(name) {
+                        Some(value) => result.push_str(&value.to_string()),
+                        None => {
+                            result.push('{');
+                            result.push_str(name);
+                            result.push('}');
+                        }
+                    }
+                    rest = &after[end + 1..];
+                }
+            }
+        }
+        result.push_str(rest);
+        result
+    }
+}
+
+/// Variant is shown only when condition is met.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DialogCondition {
+    Equals(String, DialogValue),
+    AtLeast(String, i32),
+    Not(Box<DialogCondition>),
+}
+
+impl DialogCondition {
+    pub fn is_met(&self, variables: &DialogVariables) -> bool {
+        match self {
+            DialogCondition::Equals(name, expected) => variables.get(name) == Some(expected),
+            DialogCondition::AtLeast(name, min) => match variables.get(name) {
+                Some(DialogValue::Number(value)) => value >= min,
+                _ => false,
+            },
+            DialogCondition::Not(condition) => !condition.is_met(variables),
+        }
+    }
+}
+
+pub fn party_variables_updates(
+    party_storage: Res<PartyStateStorage>,
+    localization: Res<Localization>,
+    mut variables: ResMut<DialogVariables>,
+) {
+    if !party_storage.is_changed() && !localization.is_changed() {
+        return;
+    }
+    let members = party_storage.get_party_members();
+    let dumplings = party_storage
+        .get_consumables()
+        .iter()
+        .filter(|item| matches!(item, ConsumableItem::Dumplings(_)))
+        .count();
+    let names: Vec<String> = members
+        .iter()
+        .map(|member| localization.get(&member.name))
+        .collect();
+
+    variables.set("money", DialogValue::Number(party_storage.get_money()));
+    variables.set("dumplings", DialogValue::Number(dumplings as i32));
+    variables.set("party_size", DialogValue::Number(members.len() as i32));
+    variables.set("party_names", DialogValue::Text(names.join(", ")));
+    if let Some(name) = names.first() {
+        variables.set("player_name", DialogValue::Text(name.clone()));
+    }
+}
+
+/// Current state of a quest is available as quest.<QuestName>, e.g. quest.Trial = Wait
+pub fn quest_variables_updates<S: States + Debug>(
+    state: Res<State<S>>,
+    mut variables: ResMut<DialogVariables>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+    let type_name = std::any::type_name::<S>();
+    let quest_name = type_name.rsplit("::").next().unwrap_or(type_name);
+    variables.set(
+        &format!("quest.{quest_name}"),
+        DialogValue::Text(format!("{:?}", state.get())),
+    );
+}
+
+#[test]
+fn dialog_variables_interpolate_test() {
+    let mut variables = DialogVariables::default();
+    variables.set("dumplings", DialogValue::Number(2));
+    variables.set(
+        "player_name",
+        DialogValue::Text("Грозный Личик".to_string()),
+    );
+
+    assert_eq!(
+        variables.interpolate("{player_name}, у тебя {dumplings} пачки"),
+        "Грозный Личик, у тебя 2 пачки"
+    );
+    assert_eq!(
+        variables.interpolate("{unknown} {dumplings"),
+        "{unknown} {dumplings"
+    );
+    assert_eq!(variables.interpolate("no variables"), "no variables");
+}
+
+#[test]
+fn dialog_condition_test() {
+    let mut variables = DialogVariables::default();
+    variables.set("money", DialogValue::Number(30));
+    variables.set("knows_truth", DialogValue::Flag(true));
+
+    let rich = DialogCondition::AtLeast("money".to_string(), 25);
+    let knows = DialogCondition::Equals("knows_truth".to_string(), DialogValue::Flag(true));
+    let unknown = DialogCondition::Equals("lied".to_string(), DialogValue::Flag(true));
+
+    assert!(rich.is_met(&variables));
+    assert!(knows.is_met(&variables));
+    assert!(!unknown.is_met(&variables));
+    assert!(DialogCondition::Not(Box::new(unknown)).is_met(&variables));
+    assert!(!DialogCondition::AtLeast("knows_truth".to_string(), 1).is_met(&variables));
+}
@@ -11,11 +11,19 @@ use crate::gui::{ButtonConfig, TextButtonExt, TextConfig};
 pub struct ActionId(pub usize);
 
 pub trait ActionItemExt<'a> {
-    fn action_item<S: Into<String> + Clone>(&mut self, id: ActionId, text: S) -> UiBuilder<Entity>;
+    fn action_item<S: Into<String> + Clone>(
+        &mut self,
+        id: ActionId,
+        text: S,
+    ) -> UiBuilder<'_, Entity>;
 }
 
 impl<'a> ActionItemExt<'a> for UiBuilder<'a, Entity> {
-    fn action_item<S: Into<String> + Clone>(&mut self, id: ActionId, text: S) -> UiBuilder<Entity> {
+    fn action_item<S: Into<String> + Clone>(
+        &mut self,
+        id: ActionId,
+        text: S,
+    ) -> UiBuilder<'_, Entity> {
         let mut item = self.configure_text_button(
             text,
             id,
@@ -8,16 +8,13 @@ use sickle_ui::prelude::UiContainerExt;
 #[derive(Component)]
 pub struct EnemyId(pub usize);
 
-#[derive(Component)]
-pub struct EnemyHealth;
-
 pub trait EnemyItemExt<'a> {
-    fn enemy_item(&mut self, id: EnemyId, image: Handle<Image>) -> UiBuilder<Entity>;
+    fn enemy_item(&mut self, id: EnemyId, image: Handle<Image>) -> UiBuilder<'_, Entity>;
 }
 
 impl<'a> EnemyItemExt<'a> for UiBuilder<'a, Entity> {
-    fn enemy_item(&mut self, id: EnemyId, image: Handle<Image>) -> UiBuilder<Entity> {
-        let mut item = self.container((ButtonBundle::default(), id), |parent| {
+    fn enemy_item(&mut self, id: EnemyId, image: Handle<Image>) -> UiBuilder<'_, Entity> {
+        let item = self.container((ButtonBundle::default(), id), |parent| {
             parent.container(
                 ImageBundle {
                     image: UiImage {
@@ -1,17 +1,29 @@
 use bevy::prelude::Component;
 
 use crate::rpg::{DirectionalAttack, TargetProps};
+use crate::sound::Track;
 
 #[derive(Component)]
 pub struct Fight {
     pub id: FightId,
     pub arena_bg_path: String,
     pub enemies: Vec<Enemy>,
+    pub music: FightMusic,
+}
+
+/// Tense track is played when the party is close to losing.
+#[derive(Debug, Clone, Copy)]
+pub struct FightMusic {
+    pub main: Track,
+    pub tense: Track,
+    pub victory_excerpt: Track,
+    pub defeat_excerpt: Track,
 }
 
 #[derive(Component)]
 pub struct Enemy {
     pub id: usize,
+    #[allow(dead_code)]
     pub name: String,
     pub asset_path: String,
     pub relative_x: f32,
@@ -24,6 +36,12 @@ pub struct Enemy {
 #[derive(Component)]
 pub struct FightId(pub usize);
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FightResult {
+    Won,
+    Lost,
+}
+
 pub enum ActionTarget {
     Enemy,
     Ally,
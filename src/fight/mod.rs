@@ -12,4 +12,5 @@ mod storages;
 pub use entities::*;
 pub use plugin::FightPlugin;
 pub use scene::*;
+pub use selector_ui::{pick_item_handle, SelectedItemPosHolder, SelectorExt};
 pub use storages::*;
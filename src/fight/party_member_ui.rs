@@ -18,10 +18,6 @@ use sickle_ui::prelude::UiBuilder;
 use sickle_ui::prelude::UiColumnExt;
 use sickle_ui::prelude::UiContainerExt;
 
-pub struct PartyMemberItem {
-    id: MemberId,
-}
-
 #[derive(Component)]
 pub struct MemberId(pub usize);
 
@@ -32,11 +28,11 @@ pub struct Health;
 pub struct Energy;
 
 pub trait PartyMemberItemExt<'a> {
-    fn party_member_item(&mut self, id: MemberId) -> UiBuilder<Entity>;
+    fn party_member_item(&mut self, id: MemberId) -> UiBuilder<'_, Entity>;
 }
 
 impl<'a> PartyMemberItemExt<'a> for UiBuilder<'a, Entity> {
-    fn party_member_item(&mut self, id: MemberId) -> UiBuilder<Entity> {
+    fn party_member_item(&mut self, id: MemberId) -> UiBuilder<'_, Entity> {
         let mut item = self.container((ButtonBundle::default(), id), |parent| {
             parent
                 .column(|parent| {
@@ -19,11 +19,11 @@ use bevy::prelude::Color;
 use bevy::prelude::Commands;
 use bevy::prelude::Component;
 use bevy::prelude::Entity;
+use bevy::prelude::EventWriter;
 use bevy::prelude::ImageBundle;
 use bevy::prelude::Interaction;
 use bevy::prelude::IntoSystemConfigs;
 use bevy::prelude::JustifyContent;
-use bevy::prelude::KeyCode;
 use bevy::prelude::NextState;
 use bevy::prelude::OnEnter;
 use bevy::prelude::OnExit;
@@ -38,7 +38,7 @@ use bevy::prelude::With;
 use bevy::prelude::{AlignItems, PositionType};
 use bevy::text::Text;
 use bevy::ui::{UiRect, Val};
-use bevy::utils::{warn, HashMap, HashSet};
+use bevy::utils::{HashMap, HashSet};
 use sickle_ui::prelude::SetBackgroundColorExt;
 use sickle_ui::prelude::SetHeightExt;
 use sickle_ui::prelude::SetJustifyContentExt;
@@ -50,7 +50,6 @@ use sickle_ui::prelude::UiContainerExt;
 use sickle_ui::prelude::UiRowExt;
 use sickle_ui::prelude::{SetAlignItemsExt, SetLeftExt, SetPositionTypeExt, SetTopExt};
 use sickle_ui::ui_builder::{UiBuilder, UiBuilderExt, UiRoot};
-use std::fmt::format;
 
 use crate::core::states::GameState;
 use crate::fight::actions_ui::{ActionId, ActionItemExt};
@@ -58,10 +57,16 @@ use crate::fight::enemy_ui::{EnemyId, EnemyItemExt};
 use crate::fight::party_member_ui::{Health, MemberId, PartyMemberItemExt};
 use crate::fight::selector_ui::{pick_item_handle, SelectedItemPosHolder, SelectorExt};
 use crate::fight::step::decide_next_step;
-use crate::fight::{ActionTarget, Enemy, Fight, FightId, FightStorage, GetActionTarget};
+use crate::fight::{
+    ActionTarget, Fight, FightId, FightMusic, FightResult, FightStorage, GetActionTarget,
+};
 use crate::gui::{GetSelectorItem, TextButton};
+use crate::input::InputAction;
+use crate::localization::Localization;
 use crate::party::{PartyMember, PartyStateStorage};
 use crate::rpg::{Ability, AttackResult, ConsumableItem, DirectionalAttack, TargetProps};
+use crate::sound::{MusicPlayer, Soundtrack};
+use crate::world_state::QuestEvent;
 
 pub struct FightingScene;
 
@@ -75,6 +80,7 @@ enum ScreenState {
     SelectEnemyTarget,
     SelectAllyTarget,
     PlayerStepApply,
+    #[allow(dead_code)]
     EnemyStepApply,
     EnemyStep,
 }
@@ -118,6 +124,14 @@ struct AvailableMembers {
 #[derive(Component)]
 struct AllyTargets {
     items: HashMap<usize, TargetProps>,
+    // members at 0 HP, they can't act and can't be attacked
+    downed: HashMap<usize, TargetProps>,
+}
+
+#[derive(Component)]
+struct FightSoundtrack {
+    music: FightMusic,
+    is_tense: bool,
 }
 
 #[derive(Component)]
@@ -139,15 +153,15 @@ impl CurrentAllyStep {
             None => {}
             Some(step) => match step {
                 AllyStep::OnEnemy {
-                    action,
-                    member_id,
+                    action: _,
+                    member_id: _,
                     target_id,
                 } => {
                     *target_id = Some(id);
                 }
                 AllyStep::OnAlly {
-                    action,
-                    member_id,
+                    action: _,
+                    member_id: _,
                     target_id,
                 } => *target_id = Some(id),
                 AllyStep::Guard => {}
@@ -160,6 +174,7 @@ impl CurrentAllyStep {
 enum AllyStep {
     OnEnemy {
         action: StepAction,
+        #[allow(dead_code)]
         member_id: usize,
         target_id: Option<usize>,
     },
@@ -168,6 +183,7 @@ enum AllyStep {
         member_id: usize,
         target_id: Option<usize>,
     },
+    #[allow(dead_code)]
     Guard,
 }
 
@@ -194,7 +210,12 @@ impl Plugin for FightingScene {
             .add_systems(OnExit(GameState::Fighting), unspawn::<FightingMainScreen>)
             .add_systems(
                 Update,
-                keyboard_input_handle.run_if(in_state(GameState::Fighting)),
+                (
+                    keyboard_input_handle,
+                    fight_music_intensity_update,
+                    fight_end_handle,
+                )
+                    .run_if(in_state(GameState::Fighting)),
             )
             .add_systems(
                 Update,
@@ -210,7 +231,7 @@ impl Plugin for FightingScene {
             .add_systems(OnExit(ScreenState::AttacksList), unspawn::<AttacksScreen>)
             .add_systems(
                 Update,
-                (selected_attacks_handle, pick_item_handle::<AttacksScreen>)
+                (selected_attacks_handle, pick_item_handle)
                     .run_if(in_state(ScreenState::AttacksList)),
             )
             .add_systems(OnEnter(ScreenState::AbilitiesList), spawn_abilities_list)
@@ -220,14 +241,14 @@ impl Plugin for FightingScene {
             )
             .add_systems(
                 Update,
-                (selected_ability_handle, pick_item_handle::<AbilitiesScreen>)
+                (selected_ability_handle, pick_item_handle)
                     .run_if(in_state(ScreenState::AbilitiesList)),
             )
             .add_systems(OnEnter(ScreenState::ItemsList), spawn_items_list)
             .add_systems(OnExit(ScreenState::ItemsList), unspawn::<ItemsScreen>)
             .add_systems(
                 Update,
-                (selected_consumable_handle, pick_item_handle::<ItemsScreen>)
+                (selected_consumable_handle, pick_item_handle)
                     .run_if(in_state(ScreenState::ItemsList)),
             )
             .add_systems(
@@ -267,8 +288,6 @@ fn actions_menu_input_handle(
                     next_state.set(ScreenState::AttacksList);
                 }
 
-                if button.payload.0 == PROTECT_BUTTON_ID.0 {}
-
                 if button.payload.0 == ABILITIES_BUTTON_ID.0 {
                     next_state.set(ScreenState::AbilitiesList);
                 }
@@ -284,21 +303,21 @@ fn actions_menu_input_handle(
 fn keyboard_input_handle(
     current_state: Res<State<ScreenState>>,
     mut next_state: ResMut<NextState<ScreenState>>,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    actions: Res<ButtonInput<InputAction>>,
 ) {
     if *current_state.get() == ScreenState::Main {
         return;
     }
 
-    if keyboard.pressed(KeyCode::Escape) && keyboard.just_pressed(KeyCode::Escape) {
+    if actions.pressed(InputAction::Back) && actions.just_pressed(InputAction::Back) {
         next_state.set(ScreenState::Main);
     }
 }
 
 fn party_member_selection_input_handle(
     query: Query<(&MemberId, &Interaction), (Changed<Interaction>, With<MemberId>)>,
-    mut selected_member_query: Query<(&mut SelectedMemberId)>,
-    available_members_query: Query<(&AvailableMembers)>,
+    mut selected_member_query: Query<&mut SelectedMemberId>,
+    available_members_query: Query<&AvailableMembers>,
 ) {
     for (member_id, interaction) in &query {
         let members = available_members_query.single();
@@ -310,7 +329,7 @@ fn party_member_selection_input_handle(
             Interaction::Hovered => {}
             Interaction::Pressed => {
                 let mut new_id = selected_member_query.single_mut();
-                *new_id = SelectedMemberId(Some((*member_id).0));
+                *new_id = SelectedMemberId(Some(member_id.0));
             }
         }
     }
@@ -318,22 +337,18 @@ fn party_member_selection_input_handle(
 
 fn party_member_selection_state_changes(
     mut query: Query<(&MemberId, &mut BackgroundColor), With<MemberId>>,
-    selected_member_query: Query<(&SelectedMemberId), Changed<SelectedMemberId>>,
+    selected_member_query: Query<&SelectedMemberId, Changed<SelectedMemberId>>,
 ) {
-    for selected_member_id in selected_member_query.iter() {
-        for (member_id, mut background) in &mut query {
-            if let Some(id) = selected_member_id.0 {
-                if member_id.0 == id {
-                    *background = YELLOW.into();
-                } else {
-                    *background = ANTIQUE_WHITE.into();
-                }
-            } else {
-                *background = ANTIQUE_WHITE.into();
-            }
+    // only one selected member expected here
+    let Some(selected_member_id) = selected_member_query.iter().next() else {
+        return;
+    };
+    for (member_id, mut background) in &mut query {
+        if selected_member_id.0 == Some(member_id.0) {
+            *background = YELLOW.into();
+        } else {
+            *background = ANTIQUE_WHITE.into();
         }
-        // only one selected member expected here
-        break;
     }
 }
 
@@ -343,7 +358,7 @@ fn target_ally_selection_input_handle(
         (&MemberId, &Interaction, &mut BackgroundColor),
         (Changed<Interaction>, With<MemberId>),
     >,
-    mut current_step_query: Query<(&mut CurrentAllyStep)>,
+    mut current_step_query: Query<&mut CurrentAllyStep>,
 ) {
     for (id, interaction, mut background) in &mut query {
         match interaction {
@@ -366,12 +381,12 @@ fn target_ally_selection_input_handle(
 fn ally_step_handle(
     mut commands: Commands,
     mut next_state: ResMut<NextState<ScreenState>>,
-    mut selected_member_query: Query<(&mut SelectedMemberId)>,
-    mut current_step_query: Query<(&mut CurrentAllyStep)>,
-    mut available_members_query: Query<(&mut AvailableMembers)>,
-    mut allies_targets_query: Query<(&mut AllyTargets)>,
-    mut enemies_targets_query: Query<(&mut EnemyTargets)>,
-    mut enemies_attacks_query: Query<(&mut EnemyAttacks)>,
+    mut selected_member_query: Query<&mut SelectedMemberId>,
+    current_step_query: Query<&mut CurrentAllyStep>,
+    mut available_members_query: Query<&mut AvailableMembers>,
+    mut allies_targets_query: Query<&mut AllyTargets>,
+    mut enemies_targets_query: Query<&mut EnemyTargets>,
+    mut enemies_attacks_query: Query<&mut EnemyAttacks>,
     enemies_query: Query<(Entity, &EnemyId)>,
 ) {
     for curr_step in current_step_query.iter() {
@@ -447,13 +462,13 @@ fn apply_step(
     match step {
         AllyStep::OnEnemy {
             action,
-            member_id,
+            member_id: _,
             target_id,
         } => {
             let id = target_id.expect("target_id must be set");
             let target = enemies
                 .get_mut(&id)
-                .expect(&format!("No target with {:?} found", id));
+                .unwrap_or_else(|| panic!("No target with {:?} found", id));
             let result = apply_action(action, target);
             if target.is_defeated() {
                 StepActionResult::TargetDefeated(id)
@@ -469,11 +484,11 @@ fn apply_step(
             let id = target_id.expect("target_id must be set");
             let actor = allies
                 .get_mut(member_id)
-                .expect(&format!("No ally with {:?} found", member_id));
+                .unwrap_or_else(|| panic!("No ally with {:?} found", member_id));
             apply_cost(action, actor);
             let target = allies
                 .get_mut(&id)
-                .expect(&format!("No target with {:?} found", id));
+                .unwrap_or_else(|| panic!("No target with {:?} found", id));
             apply_action(action, target)
         }
         AllyStep::Guard => {
@@ -497,45 +512,47 @@ fn apply_action(action: &StepAction, target: &mut TargetProps) -> StepActionResu
 }
 
 fn apply_attack(attack: &DirectionalAttack, target: &mut TargetProps) -> StepActionResult {
-    return match attack.apply(target) {
+    match attack.apply(target) {
         AttackResult::Hit => StepActionResult::AttackHit,
         AttackResult::Miss => StepActionResult::AttackMiss,
-    };
+    }
 }
 
 fn apply_cost(action: &StepAction, target: &mut TargetProps) {
-    match action {
-        StepAction::Ability(ability) => ability.apply_cost(target),
-        _ => {}
+    if let StepAction::Ability(ability) = action {
+        ability.apply_cost(target)
     }
 }
 
 fn enemy_step_handle(
     mut next_state: ResMut<NextState<ScreenState>>,
-    mut available_members_query: Query<(&mut AvailableMembers)>,
-    mut allies_targets_query: Query<(&mut AllyTargets)>,
-    mut enemy_attacks_query: Query<(&mut EnemyAttacks)>,
+    mut available_members_query: Query<&mut AvailableMembers>,
+    mut allies_targets_query: Query<&mut AllyTargets>,
+    enemy_attacks_query: Query<&mut EnemyAttacks>,
 ) {
     let mut targets = allies_targets_query.single_mut();
     let mut available_members = available_members_query.single_mut();
     let ids_to_attacks = enemy_attacks_query.single();
     for (id, attacks) in &ids_to_attacks.items {
+        if targets.items.is_empty() {
+            break;
+        }
         println!("Enemy {:?} step", id);
         println!("Enemy {:?} attacks {:?}", id, &attacks);
 
-        let decision = decide_next_step(&attacks, &targets.items);
+        let decision = decide_next_step(attacks, &targets.items);
         println!("Enemy {:?} decision {:?}", id, decision);
 
-        let mut target = targets.items.get_mut(&decision.target_id).expect(&format!(
-            "No target with id = {:?} found",
-            decision.target_id
-        ));
+        let target = targets
+            .items
+            .get_mut(&decision.target_id)
+            .unwrap_or_else(|| panic!("No target with id = {:?} found", decision.target_id));
         let attack = &attacks[decision.attack_id];
         let mut result = apply_attack(attack, target);
         if target.is_defeated() {
             result = StepActionResult::TargetDefeated(decision.target_id);
         }
-        handle_enemy_action_result(&result, &mut available_members, &mut targets.items);
+        handle_enemy_action_result(&result, &mut available_members, &mut targets);
         println!("!!! result {:?}", result)
     }
 
@@ -551,7 +568,7 @@ fn enemy_step_handle(
 fn handle_enemy_action_result(
     result: &StepActionResult,
     available_members: &mut AvailableMembers,
-    allies_targets: &mut HashMap<usize, TargetProps>,
+    allies_targets: &mut AllyTargets,
 ) {
     match result {
         StepActionResult::AttackHit => {}
@@ -559,17 +576,107 @@ fn handle_enemy_action_result(
         StepActionResult::AbilitySuccess => {}
         StepActionResult::ConsumableSuccess => {}
         StepActionResult::TargetDefeated(target_id) => {
-            allies_targets.remove(target_id);
+            if let Some(target) = allies_targets.items.remove(target_id) {
+                allies_targets.downed.insert(*target_id, target);
+            }
             available_members.all.remove(target_id);
         }
     }
 }
 
+fn fight_end_handle(
+    mut next_game_state: ResMut<NextState<GameState>>,
+    mut next_screen_state: ResMut<NextState<ScreenState>>,
+    mut party_storage: ResMut<PartyStateStorage>,
+    mut quest_events: EventWriter<QuestEvent>,
+    mut music_player: ResMut<MusicPlayer>,
+    soundtrack_res: Res<Soundtrack>,
+    fight_id_query: Query<&FightId>,
+    soundtrack_query: Query<&FightSoundtrack>,
+    allies_targets_query: Query<&AllyTargets>,
+    enemies_targets_query: Query<&EnemyTargets>,
+) {
+    let (Ok(allies), Ok(enemies)) = (
+        allies_targets_query.get_single(),
+        enemies_targets_query.get_single(),
+    ) else {
+        return;
+    };
+
+    let result = if enemies.items.is_empty() {
+        FightResult::Won
+    } else if allies.items.is_empty() {
+        FightResult::Lost
+    } else {
+        return;
+    };
+
+    // write damage back, so it persists until the next fight or rest
+    for (id, target) in allies.items.iter().chain(allies.downed.iter()) {
+        party_storage.update_target_props_by_id(*id, target.clone());
+    }
+
+    let fight_id = fight_id_query.single();
+    quest_events.send(QuestEvent::FightFinished {
+        fight_id: fight_id.0,
+        result,
+    });
+    if let Ok(soundtrack) = soundtrack_query.get_single() {
+        let excerpt = match result {
+            FightResult::Won => soundtrack.music.victory_excerpt,
+            FightResult::Lost => soundtrack.music.defeat_excerpt,
+        };
+        music_player.play_excerpt(soundtrack_res.get(excerpt));
+    }
+    next_screen_state.set(ScreenState::Main);
+    match result {
+        FightResult::Won => next_game_state.set(GameState::Exploration),
+        FightResult::Lost => next_game_state.set(GameState::GameOver),
+    }
+}
+
+fn fight_music_intensity_update(
+    mut music_player: ResMut<MusicPlayer>,
+    soundtrack_res: Res<Soundtrack>,
+    mut soundtrack_query: Query<&mut FightSoundtrack>,
+    allies_targets_query: Query<&AllyTargets, Changed<AllyTargets>>,
+) {
+    let (Ok(mut soundtrack), Ok(allies)) = (
+        soundtrack_query.get_single_mut(),
+        allies_targets_query.get_single(),
+    ) else {
+        return;
+    };
+
+    let is_tense = is_party_in_danger(allies);
+    if soundtrack.is_tense == is_tense {
+        return;
+    }
+    soundtrack.is_tense = is_tense;
+    let track = if is_tense {
+        soundtrack.music.tense
+    } else {
+        soundtrack.music.main
+    };
+    music_player.play(soundtrack_res.get(track));
+}
+
+/// Some member is downed or has health below the threshold.
+fn is_party_in_danger(allies: &AllyTargets) -> bool {
+    if !allies.downed.is_empty() {
+        return true;
+    }
+    allies
+        .items
+        .values()
+        .any(|target| target.health.current * 100 < target.health.max * TENSE_HEALTH_PERCENT)
+}
+
 fn party_state_changes(
     parent_query: Query<(&PartyMember, &Children), Changed<PartyMember>>,
-    mut children_query: Query<(&mut Text), With<Health>>,
+    mut children_query: Query<&mut Text, With<Health>>,
 ) {
-    for (member, mut children) in parent_query.iter() {
+    for (member, children) in parent_query.iter() {
         for &child in children.iter() {
             let mut health = children_query.get_mut(child).expect("");
             health.sections[1].value = format!("{}", member.target.armor)
@@ -583,7 +690,7 @@ fn target_enemy_selection_input_handle(
         (&EnemyId, &Interaction, &mut BackgroundColor),
         (Changed<Interaction>, With<EnemyId>),
     >,
-    mut current_step_query: Query<(&mut CurrentAllyStep)>,
+    mut current_step_query: Query<&mut CurrentAllyStep>,
 ) {
     for (id, interaction, mut background) in &mut query {
         match interaction {
@@ -603,15 +710,16 @@ fn target_enemy_selection_input_handle(
 
 fn spawn_attacks_list(
     mut commands: Commands,
-    selected_member_query: Query<(&SelectedMemberId)>,
-    attacks_query: Query<(&Attacks)>,
+    localization: Res<Localization>,
+    selected_member_query: Query<&SelectedMemberId>,
+    attacks_query: Query<&Attacks>,
 ) {
     for selected_member in selected_member_query.iter() {
         if let Some(id) = selected_member.0 {
             let attacks = &attacks_query.single().items[&id];
             let items = attacks
                 .iter()
-                .map(|attack| attack.selector_item())
+                .map(|attack| attack.selector_item(&localization))
                 .collect();
 
             commands
@@ -626,10 +734,10 @@ fn spawn_attacks_list(
 
 fn selected_attacks_handle(
     mut next_state: ResMut<NextState<ScreenState>>,
-    selected_member_query: Query<(&SelectedMemberId)>,
-    mut current_step_query: Query<(&mut CurrentAllyStep)>,
-    attacks_query: Query<(&Attacks)>,
-    mut holder_query: Query<(&mut SelectedItemPosHolder), Changed<SelectedItemPosHolder>>,
+    selected_member_query: Query<&SelectedMemberId>,
+    mut current_step_query: Query<&mut CurrentAllyStep>,
+    attacks_query: Query<&Attacks>,
+    mut holder_query: Query<&mut SelectedItemPosHolder, Changed<SelectedItemPosHolder>>,
 ) {
     for mut holder in holder_query.iter_mut() {
         match holder.take_away() {
@@ -657,10 +765,10 @@ fn selected_attacks_handle(
 
 fn selected_ability_handle(
     mut next_state: ResMut<NextState<ScreenState>>,
-    selected_member_query: Query<(&SelectedMemberId)>,
-    mut current_step_query: Query<(&mut CurrentAllyStep)>,
-    abilities_query: Query<(&Abilities)>,
-    mut holder_query: Query<(&mut SelectedItemPosHolder), Changed<SelectedItemPosHolder>>,
+    selected_member_query: Query<&SelectedMemberId>,
+    mut current_step_query: Query<&mut CurrentAllyStep>,
+    abilities_query: Query<&Abilities>,
+    mut holder_query: Query<&mut SelectedItemPosHolder, Changed<SelectedItemPosHolder>>,
 ) {
     for mut holder in holder_query.iter_mut() {
         match holder.take_away() {
@@ -701,10 +809,10 @@ fn selected_ability_handle(
 
 fn selected_consumable_handle(
     mut next_state: ResMut<NextState<ScreenState>>,
-    selected_member_query: Query<(&SelectedMemberId)>,
-    mut current_step_query: Query<(&mut CurrentAllyStep)>,
-    consumables_query: Query<(&Consumables)>,
-    mut holder_query: Query<(&mut SelectedItemPosHolder), Changed<SelectedItemPosHolder>>,
+    selected_member_query: Query<&SelectedMemberId>,
+    mut current_step_query: Query<&mut CurrentAllyStep>,
+    consumables_query: Query<&Consumables>,
+    mut holder_query: Query<&mut SelectedItemPosHolder, Changed<SelectedItemPosHolder>>,
 ) {
     for mut holder in holder_query.iter_mut() {
         match holder.take_away() {
@@ -732,8 +840,9 @@ fn selected_consumable_handle(
 
 fn spawn_abilities_list(
     mut commands: Commands,
-    selected_member_query: Query<(&SelectedMemberId)>,
-    abilities_query: Query<(&Abilities)>,
+    localization: Res<Localization>,
+    selected_member_query: Query<&SelectedMemberId>,
+    abilities_query: Query<&Abilities>,
 ) {
     for selected_member in selected_member_query.iter() {
         if selected_member.0.is_none() {
@@ -743,7 +852,7 @@ fn spawn_abilities_list(
         let abilities = &abilities_query.single().items[&selected_member_id];
         let items = abilities
             .iter()
-            .map(|ability| ability.selector_item())
+            .map(|ability| ability.selector_item(&localization))
             .collect();
         commands
             .ui_builder(UiRoot)
@@ -754,11 +863,15 @@ fn spawn_abilities_list(
     }
 }
 
-fn spawn_items_list(mut commands: Commands, consumables_query: Query<(&Consumables)>) {
+fn spawn_items_list(
+    mut commands: Commands,
+    localization: Res<Localization>,
+    consumables_query: Query<&Consumables>,
+) {
     let consumables = &consumables_query.single().items;
     let items = consumables
         .iter()
-        .map(|consumable| consumable.selector_item())
+        .map(|consumable| consumable.selector_item(&localization))
         .collect();
     commands
         .ui_builder(UiRoot)
@@ -770,30 +883,44 @@ fn spawn_items_list(mut commands: Commands, consumables_query: Query<(&Consumabl
 
 fn spawn_main(
     mut commands: Commands,
-    query: Query<(&FightId)>,
+    query: Query<&FightId>,
     asset_server: Res<AssetServer>,
     fight_storage: Res<FightStorage>,
     party_storage: Res<PartyStateStorage>,
+    localization: Res<Localization>,
+    soundtrack_res: Res<Soundtrack>,
+    mut music_player: ResMut<MusicPlayer>,
 ) {
     let fight_id = query.single();
     let fight = fight_storage.load(&fight_id.0).expect("");
+    let music = fight.music;
+    music_player.play(soundtrack_res.get(music.main));
     let members = party_storage.get_party_members();
-    let ids: HashSet<usize> = members.iter().map(|m| m.id).collect();
+    let ids: HashSet<usize> = members
+        .iter()
+        .filter(|m| !m.is_downed())
+        .map(|m| m.id)
+        .collect();
     let items = party_storage.get_consumables();
 
-    let default_selected = members.first().expect("Members should not be empty").id;
+    // all members can be downed, then fight is lost immediately
+    let default_selected = members.iter().find(|m| !m.is_downed()).map(|m| m.id);
     commands
         .ui_builder(UiRoot)
         .column(|parent| {
             spawn_fight_area(parent, 70.0, &asset_server, fight);
-            spawn_player_menu(parent, 30.0, &asset_server, members);
+            spawn_player_menu(parent, 30.0, &localization, members);
         })
         .insert((
             FightingMainScreen,
-            SelectedMemberId(Some(default_selected)),
+            SelectedMemberId(default_selected),
             Consumables { items },
             SelectedItemPosHolder::new(),
             CurrentAllyStep(None),
+            FightSoundtrack {
+                music,
+                is_tense: false,
+            },
             AvailableMembers {
                 all: ids.clone(),
                 remaining: ids,
@@ -852,7 +979,7 @@ fn spawn_fight_area(
 fn spawn_player_menu(
     parent: &mut UiBuilder<Entity>,
     height_percent: f32,
-    asset_server: &Res<AssetServer>,
+    localization: &Localization,
     members: Vec<PartyMember>,
 ) {
     parent
@@ -860,12 +987,18 @@ fn spawn_player_menu(
             let mut attacks = HashMap::new();
             let mut abilities = HashMap::new();
             let mut targets = HashMap::new();
+            let mut downed = HashMap::new();
             parent
                 .row(|parent| {
                     for item in members {
+                        let is_downed = item.is_downed();
                         attacks.insert(item.id, item.attacks);
                         abilities.insert(item.id, item.abilities);
-                        targets.insert(item.id, item.target);
+                        if is_downed {
+                            downed.insert(item.id, item.target);
+                        } else {
+                            targets.insert(item.id, item.target);
+                        }
                         parent
                             .party_member_item(MemberId(item.id))
                             .style()
@@ -881,7 +1014,10 @@ fn spawn_player_menu(
                 })
                 .insert(Attacks { items: attacks })
                 .insert(Abilities { items: abilities })
-                .insert(AllyTargets { items: targets })
+                .insert(AllyTargets {
+                    items: targets,
+                    downed,
+                })
                 .style()
                 .background_color(Color::from(DIM_GREY))
                 .justify_content(JustifyContent::FlexStart)
@@ -889,7 +1025,7 @@ fn spawn_player_menu(
                 .width(Val::Percent(75.0))
                 .height(Val::Percent(100.0));
 
-            spawn_actions(parent, 25.0, 100.0);
+            spawn_actions(parent, localization, 25.0, 100.0);
         })
         .style()
         .justify_content(JustifyContent::FlexStart)
@@ -898,13 +1034,18 @@ fn spawn_player_menu(
         .height(Val::Percent(height_percent));
 }
 
-fn spawn_actions(parent: &mut UiBuilder<Entity>, width_percent: f32, height_percent: f32) {
+fn spawn_actions(
+    parent: &mut UiBuilder<Entity>,
+    localization: &Localization,
+    width_percent: f32,
+    height_percent: f32,
+) {
     parent
         .column(|parent| {
-            parent.action_item(ATTACKS_BUTTON_ID, "Attacks");
-            parent.action_item(PROTECT_BUTTON_ID, "Protect");
-            parent.action_item(ABILITIES_BUTTON_ID, "Abilities");
-            parent.action_item(ITEMS_BUTTON_ID, "Items");
+            parent.action_item(ATTACKS_BUTTON_ID, localization.get("fight.attacks"));
+            parent.action_item(PROTECT_BUTTON_ID, localization.get("fight.protect"));
+            parent.action_item(ABILITIES_BUTTON_ID, localization.get("fight.abilities"));
+            parent.action_item(ITEMS_BUTTON_ID, localization.get("fight.items"));
         })
         .style()
         .width(Val::Percent(width_percent))
@@ -925,5 +1066,8 @@ const PROTECT_BUTTON_ID: ActionId = ActionId(1);
 const ABILITIES_BUTTON_ID: ActionId = ActionId(2);
 const ITEMS_BUTTON_ID: ActionId = ActionId(3);
 
+// the tense track starts when some member has less health
+const TENSE_HEALTH_PERCENT: i32 = 30;
+
 /// <div style="background-color:rgb(30%, 30%, 30%); width: 10px; padding: 10px; border: 1px solid;"></div>
 const HOVER_BUTTON_COLOR: Srgba = Srgba::new(0.302, 0.302, 0.302, 0.7);
@@ -1,7 +1,5 @@
-use bevy::audio::{AudioBundle, PlaybackSettings};
 use bevy::color::palettes::css::ANTIQUE_WHITE;
 use bevy::color::{Color, Srgba};
-use bevy::ecs::system::EntityCommands;
 use bevy::hierarchy::Children;
 use bevy::log::warn;
 use bevy::prelude::BackgroundColor;
@@ -19,6 +17,7 @@ use bevy::ui::{FocusPolicy, UiRect};
 use sickle_ui::prelude::ScrollAxis;
 use sickle_ui::prelude::SetAlignItemsExt;
 use sickle_ui::prelude::SetBackgroundColorExt;
+use sickle_ui::prelude::SetFocusPolicyExt;
 use sickle_ui::prelude::SetHeightExt;
 use sickle_ui::prelude::SetJustifyContentExt;
 use sickle_ui::prelude::SetMarginExt;
@@ -28,17 +27,10 @@ use sickle_ui::prelude::UiColumnExt;
 use sickle_ui::prelude::UiRoot;
 use sickle_ui::prelude::UiRowExt;
 use sickle_ui::prelude::UiScrollViewExt;
-use sickle_ui::prelude::{LabelConfig, SetFocusPolicyExt};
 use sickle_ui::ui_commands::UpdateTextExt;
-use sickle_ui::widgets::layout::label::SetLabelTextExt;
 
-use crate::fight::party_member_ui::Health;
-use crate::gui::{
-    ButtonConfig, SelectorItem, Text, TextButton, TextButtonExt, TextConfig, TextExt,
-};
-use crate::sound::ButtonSounds;
-
-pub struct Selector;
+use crate::gui::{ButtonConfig, SelectorItem, TextButton, TextButtonExt, TextConfig, TextExt};
+use crate::sound::{AudioBus, AudioCommandsExt, ButtonSounds};
 
 #[derive(Component)]
 pub struct PosAndDescr(pub usize, pub String);
@@ -60,12 +52,6 @@ impl SelectedItemPosHolder {
         self.value = Some(value);
     }
 
-    pub fn take_away_unsafe(&mut self) -> usize {
-        let value = self.value.expect("Value was not be stored");
-        self.value = None;
-        value
-    }
-
     pub fn take_away(&mut self) -> Option<usize> {
         let value = self.value;
         self.value = None;
@@ -74,11 +60,11 @@ impl SelectedItemPosHolder {
 }
 
 pub trait SelectorExt<'a> {
-    fn selector(&mut self, items: Vec<SelectorItem>) -> UiBuilder<Entity>;
+    fn selector(&mut self, items: Vec<SelectorItem>) -> UiBuilder<'_, Entity>;
 }
 
 impl<'a> SelectorExt<'a> for UiBuilder<'a, UiRoot> {
-    fn selector(&mut self, items: Vec<SelectorItem>) -> UiBuilder<Entity> {
+    fn selector(&mut self, items: Vec<SelectorItem>) -> UiBuilder<'_, Entity> {
         let mut selector = self.row(|parent| {
             parent
                 .column(|parent| {
@@ -148,14 +134,14 @@ impl<'a> SelectorExt<'a> for UiBuilder<'a, UiRoot> {
     }
 }
 
-pub fn pick_item_handle<S>(
+pub fn pick_item_handle(
     mut commands: Commands,
     mut query: Query<
         (&TextButton<PosAndDescr>, &Interaction, &mut BackgroundColor),
         Changed<Interaction>,
     >,
-    mut description_query: Query<(&Children), With<Description>>,
-    mut holder_query: Query<(&mut SelectedItemPosHolder)>,
+    description_query: Query<&Children, With<Description>>,
+    mut holder_query: Query<&mut SelectedItemPosHolder>,
     audio_res: Res<ButtonSounds>,
 ) {
     for (item, interaction, mut background_color) in &mut query {
@@ -164,7 +150,7 @@ pub fn pick_item_handle<S>(
                 *background_color = item.config.idle;
             }
             Interaction::Hovered => {
-                for mut children in description_query.iter() {
+                for children in description_query.iter() {
                     for &child in children.iter() {
                         match commands.get_entity(child) {
                             None => {
@@ -179,10 +165,7 @@ pub fn pick_item_handle<S>(
                 *background_color = item.config.hover
             }
             Interaction::Pressed => {
-                commands.spawn(AudioBundle {
-                    source: audio_res.iron_click.clone(),
-                    settings: PlaybackSettings::ONCE,
-                });
+                commands.play_sound(AudioBus::Ui, audio_res.iron_click.clone());
                 let mut holder = holder_query.single_mut();
                 holder.store(item.payload.0);
             }
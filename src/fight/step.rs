@@ -1,6 +1,8 @@
 use bevy::utils::HashMap;
 
-use crate::rpg::{DirectionalAttack, RangedProp, TargetProps};
+#[cfg(test)]
+use crate::rpg::RangedProp;
+use crate::rpg::{DirectionalAttack, TargetProps};
 
 #[derive(Default, Debug, Eq, PartialEq)]
 pub struct StepDecision {
@@ -9,7 +11,7 @@ pub struct StepDecision {
 }
 
 pub fn decide_next_step(
-    attacks: &Vec<DirectionalAttack>,
+    attacks: &[DirectionalAttack],
     targets: &HashMap<usize, TargetProps>,
 ) -> StepDecision {
     let mut min_health = f32::MAX;
@@ -21,7 +23,7 @@ pub fn decide_next_step(
             let m_e = mathematical_expectation(attack, target);
             if m_e < min_health {
                 min_health = m_e;
-                target_id = id.clone();
+                target_id = *id;
                 attack_id = pos;
             }
         }
@@ -1,20 +1,21 @@
 use bevy::prelude::Resource;
 use bevy::utils::HashMap;
 
-use crate::fight::{Enemy, Fight, FightId};
+use crate::fight::{Enemy, Fight, FightId, FightMusic};
 use crate::rpg::{DirectionalAttack, RangedProp, TargetProps};
+use crate::sound::Track;
 
 #[derive(Resource)]
 pub struct FightStorage;
 
 impl FightStorage {
     pub fn load(&self, id: &usize) -> Option<Fight> {
-        let mut fights = test_fights();
+        let mut fights = fights();
         fights.remove(id)
     }
 
     pub fn get_all(&self) -> Vec<Fight> {
-        let mut fights = test_fights();
+        let fights = fights();
         let mut items = vec![];
         for (_, item) in fights {
             items.push(item)
@@ -23,13 +24,19 @@ impl FightStorage {
     }
 }
 
-pub fn test_fights() -> HashMap<usize, Fight> {
-    let mut test_fights = HashMap::new();
-    test_fights.insert(
-        TEST_FIGHT_ID_0.0,
+pub fn fights() -> HashMap<usize, Fight> {
+    let mut fights = HashMap::new();
+    fights.insert(
+        GOPNIKS_FIGHT_ID.0,
         Fight {
-            id: TEST_FIGHT_ID_0,
+            id: GOPNIKS_FIGHT_ID,
             arena_bg_path: "background/fight/gopniks_1.png".to_string(),
+            music: FightMusic {
+                main: Track::IntoBattle,
+                tense: Track::WarriorRoutine,
+                victory_excerpt: Track::TheLastSpurt,
+                defeat_excerpt: Track::ColdAnger,
+            },
             enemies: vec![
                 Enemy {
                     id: 0,
@@ -132,7 +139,7 @@ pub fn test_fights() -> HashMap<usize, Fight> {
             ],
         },
     );
-    test_fights
+    fights
 }
 
-pub const TEST_FIGHT_ID_0: FightId = FightId(10);
+pub const GOPNIKS_FIGHT_ID: FightId = FightId(10);
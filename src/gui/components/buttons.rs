@@ -45,7 +45,7 @@ pub trait TextButtonExt<'a> {
         &mut self,
         text: S,
         payload: P,
-    ) -> UiBuilder<Entity> {
+    ) -> UiBuilder<'_, Entity> {
         self.configure_text_button(
             text,
             payload,
@@ -59,7 +59,7 @@ pub trait TextButtonExt<'a> {
         payload: P,
         text_config: TextConfig,
         button_config: ButtonConfig,
-    ) -> UiBuilder<Entity>;
+    ) -> UiBuilder<'_, Entity>;
 }
 
 impl<'a> TextButtonExt<'a> for UiBuilder<'a, Entity> {
@@ -69,7 +69,7 @@ impl<'a> TextButtonExt<'a> for UiBuilder<'a, Entity> {
         payload: P,
         text_config: TextConfig,
         button_config: ButtonConfig,
-    ) -> UiBuilder<Entity> {
+    ) -> UiBuilder<'_, Entity> {
         let width = button_config.width;
         let height = button_config.height;
         let color = button_config.idle;
@@ -107,4 +107,3 @@ impl<'a> TextButtonExt<'a> for UiBuilder<'a, Entity> {
 
 /// <div style="background-color:rgb(90%, 90%, 90%); width: 10px; padding: 10px; border: 1px solid;"></div>
 const HOVER_PRESSED_BUTTON_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
-const BUTTON_TEXT_SIZE_MEDIUM: f32 = 36.0;
@@ -48,21 +48,13 @@ impl TextConfig {
 
 struct SetFont(String, f32, Color);
 
-struct SetTextSize(f32);
-
-struct SetTextColor(Color);
-
 trait BannerWidgetCommands<'a> {
     fn font(
         &'a mut self,
         font: impl Into<String>,
         size: f32,
         color: Color,
-    ) -> &mut EntityCommands<'a>;
-
-    fn text_size(&'a mut self, size: f32) -> &mut EntityCommands<'a>;
-
-    fn text_color(&'a mut self, color: Color) -> &mut EntityCommands<'a>;
+    ) -> &'a mut EntityCommands<'a>;
 }
 
 impl<'a> BannerWidgetCommands<'a> for EntityCommands<'a> {
@@ -71,17 +63,9 @@ impl<'a> BannerWidgetCommands<'a> for EntityCommands<'a> {
         font: impl Into<String>,
         size: f32,
         color: Color,
-    ) -> &mut EntityCommands<'a> {
+    ) -> &'a mut EntityCommands<'a> {
         self.add(SetFont(font.into(), size, color))
     }
-
-    fn text_size(&'a mut self, size: f32) -> &mut EntityCommands<'a> {
-        self.add(SetTextSize(size))
-    }
-
-    fn text_color(&'a mut self, color: Color) -> &mut EntityCommands<'a> {
-        self.add(SetTextColor(color))
-    }
 }
 
 impl EntityCommand for SetFont {
@@ -99,36 +83,12 @@ impl EntityCommand for SetFont {
     }
 }
 
-impl EntityCommand for SetTextSize {
-    fn apply(self, entity: Entity, world: &mut World) {
-        if let Some(mut text) = world.entity_mut(entity).get_mut::<BevyText>() {
-            for text_section in &mut text.sections {
-                text_section.style.font_size = self.0;
-            }
-        }
-    }
-}
-
-impl EntityCommand for SetTextColor {
-    fn apply(self, entity: Entity, world: &mut World) {
-        if let Some(mut text) = world.entity_mut(entity).get_mut::<BevyText>() {
-            for text_section in &mut text.sections {
-                text_section.style.color = self.0;
-            }
-        }
-    }
-}
-
 pub trait TextExt<'a> {
     fn configure_text<S: Into<String> + Clone>(
         &mut self,
         text: S,
         config: TextConfig,
-    ) -> UiBuilder<Entity>;
-
-    fn medium_text<S: Into<String> + Clone>(&mut self, text: S) -> UiBuilder<Entity> {
-        self.configure_text(text, TextConfig::default())
-    }
+    ) -> UiBuilder<'_, Entity>;
 }
 
 impl<'a> TextExt<'a> for UiBuilder<'a, Entity> {
@@ -136,7 +96,7 @@ impl<'a> TextExt<'a> for UiBuilder<'a, Entity> {
         &mut self,
         text: S,
         config: TextConfig,
-    ) -> UiBuilder<Entity> {
+    ) -> UiBuilder<'_, Entity> {
         self.container((NodeBundle::default(), Text), |parent| {
             parent
                 .label(LabelConfig {
@@ -159,6 +119,5 @@ impl<'a> TextExt<'a> for UiBuilder<'a, Entity> {
 const TEXT_SIZE_SMALL: f32 = 24.0;
 const TEXT_SIZE_MEDIUM: f32 = 36.0;
 const TEXT_SIZE_LARGE: f32 = 90.0;
-const TEXT_SIZE_EXTRA_LARGE: f32 = 108.0;
 
 const DEFAULT_FONT_PATH: &str = "fonts/Deledda Open Light.ttf";
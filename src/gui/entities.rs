@@ -1,5 +1,7 @@
 use bevy::prelude::Component;
 
+use crate::localization::Localization;
+
 #[derive(Component)]
 pub struct SelectorItem {
     pub name: String,
@@ -7,12 +9,12 @@ pub struct SelectorItem {
 }
 
 pub trait GetSelectorItem {
-    fn selector_item(&self) -> SelectorItem {
+    fn selector_item(&self, localization: &Localization) -> SelectorItem {
         SelectorItem {
-            name: self.localised_name(),
-            description: self.localised_description(),
+            name: self.localised_name(localization),
+            description: self.localised_description(localization),
         }
     }
-    fn localised_name(&self) -> String;
-    fn localised_description(&self) -> String;
+    fn localised_name(&self, localization: &Localization) -> String;
+    fn localised_description(&self, localization: &Localization) -> String;
 }
@@ -0,0 +1,204 @@
+use bevy::ecs::component::Tick;
+use bevy::math::Vec2;
+use bevy::prelude::{
+    Button, ButtonInput, DetectChanges, DetectChangesMut, Entity, GlobalTransform,
+    HierarchyQueryExt, Interaction, Parent, Query, Res, ResMut, Resource, ViewVisibility, With,
+};
+use bevy::ui::UiStack;
+use bevy::utils::HashMap;
+
+use crate::input::InputAction;
+
+// Items on the other line are less preferred than the ones on the same line
+const ACROSS_DISTANCE_WEIGHT: f32 = 2.0;
+
+/// Button focused by keyboard or gamepad. Focused button is kept hovered,
+/// so usual `Interaction` handlers work for it as for mouse.
+#[derive(Resource, Default)]
+pub struct UiFocus {
+    entity: Option<Entity>,
+    hovered_tick: Tick,
+    is_pressed: bool,
+}
+
+pub fn ui_focus_handle(
+    actions: Res<ButtonInput<InputAction>>,
+    ui_stack: Res<UiStack>,
+    mut focus: ResMut<UiFocus>,
+    parent_query: Query<&Parent>,
+    mut button_query: Query<
+        (Entity, &mut Interaction, &GlobalTransform, &ViewVisibility),
+        With<Button>,
+    >,
+) {
+    // Mouse takes the focus too, so there is only one hovered button
+    for (entity, interaction, _, _) in button_query.iter_mut() {
+        let is_hovered = interaction.is_changed() && *interaction == Interaction::Hovered;
+        if is_hovered && focus.entity != Some(entity) {
+            focus.entity = Some(entity);
+            focus.hovered_tick = interaction.last_changed();
+        }
+    }
+
+    let candidates = top_layer_buttons(&ui_stack, &parent_query, &button_query);
+    if focus
+        .entity
+        .is_some_and(|entity| !candidates.iter().any(|(it, _)| *it == entity))
+    {
+        focus.entity = None;
+    }
+
+    let direction = focus_direction(&actions);
+    let confirmed = actions.just_pressed(InputAction::Confirm);
+    let next = match focus.entity {
+        None if direction.is_some() || confirmed => first_focus(&candidates),
+        Some(current) => direction.and_then(|direction| {
+            let from = candidates.iter().find(|(it, _)| *it == current)?.1;
+            next_focus(from, direction, &candidates)
+        }),
+        None => None,
+    };
+
+    if let Some(next) = next {
+        if let Some(Ok((_, mut interaction, _, _))) =
+            focus.entity.map(|it| button_query.get_mut(it))
+        {
+            if *interaction == Interaction::Hovered {
+                *interaction = Interaction::None;
+            }
+        }
+        if let Ok((_, mut interaction, _, _)) = button_query.get_mut(next) {
+            interaction.set_if_neq(Interaction::Hovered);
+            focus.hovered_tick = interaction.last_changed();
+        }
+        focus.entity = Some(next);
+        focus.is_pressed = false;
+        return;
+    }
+
+    let Some(Ok((_, mut interaction, _, _))) = focus.entity.map(|it| button_query.get_mut(it))
+    else {
+        return;
+    };
+    if confirmed {
+        *interaction = Interaction::Pressed;
+        focus.is_pressed = true;
+    } else if focus.is_pressed {
+        // Button is released on the next frame as after mouse click
+        *interaction = Interaction::Hovered;
+        focus.hovered_tick = interaction.last_changed();
+        focus.is_pressed = false;
+    } else if *interaction == Interaction::None {
+        // Mouse is not over the button, it's reset by the ui focus system every frame,
+        // so the button is hovered back without triggering the handlers again
+        *interaction.bypass_change_detection() = Interaction::Hovered;
+        interaction.set_last_changed(focus.hovered_tick);
+    }
+}
+
+fn focus_direction(actions: &ButtonInput<InputAction>) -> Option<Vec2> {
+    // Ui is laid out from top to bottom
+    if actions.just_pressed(InputAction::FocusUp) {
+        return Some(Vec2::new(0.0, -1.0));
+    }
+    if actions.just_pressed(InputAction::FocusDown) {
+        return Some(Vec2::new(0.0, 1.0));
+    }
+    if actions.just_pressed(InputAction::FocusLeft) {
+        return Some(Vec2::new(-1.0, 0.0));
+    }
+    if actions.just_pressed(InputAction::FocusRight) {
+        return Some(Vec2::new(1.0, 0.0));
+    }
+    None
+}
+
+/// Visible buttons of the topmost ui root, the ones under it are covered by it.
+fn top_layer_buttons(
+    ui_stack: &UiStack,
+    parent_query: &Query<&Parent>,
+    button_query: &Query<
+        (Entity, &mut Interaction, &GlobalTransform, &ViewVisibility),
+        With<Button>,
+    >,
+) -> Vec<(Entity, Vec2)> {
+    let stack_positions: HashMap<Entity, usize> = ui_stack
+        .uinodes
+        .iter()
+        .enumerate()
+        .map(|(pos, entity)| (*entity, pos))
+        .collect();
+    let buttons: Vec<(Entity, Vec2, usize)> = button_query
+        .iter()
+        .filter(|(_, _, _, visibility)| visibility.get())
+        .map(|(entity, _, transform, _)| {
+            let root = parent_query.iter_ancestors(entity).last().unwrap_or(entity);
+            let root_pos = stack_positions.get(&root).copied().unwrap_or_default();
+            (entity, transform.translation().truncate(), root_pos)
+        })
+        .collect();
+    let Some(top) = buttons.iter().map(|(_, _, root_pos)| *root_pos).max() else {
+        return vec![];
+    };
+    buttons
+        .into_iter()
+        .filter(|(_, _, root_pos)| *root_pos == top)
+        .map(|(entity, position, _)| (entity, position))
+        .collect()
+}
+
+fn first_focus(candidates: &[(Entity, Vec2)]) -> Option<Entity> {
+    candidates
+        .iter()
+        .min_by(|(_, a), (_, b)| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)))
+        .map(|(entity, _)| *entity)
+}
+
+/// Nearest item in the direction, items on the same line are preferred.
+fn next_focus(from: Vec2, direction: Vec2, candidates: &[(Entity, Vec2)]) -> Option<Entity> {
+    candidates
+        .iter()
+        .filter_map(|(entity, position)| {
+            let shift = *position - from;
+            let along = shift.dot(direction);
+            if along < 1.0 {
+                return None;
+            }
+            let across = (shift - direction * along).length();
+            Some((entity, along + across * ACROSS_DISTANCE_WEIGHT))
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(entity, _)| *entity)
+}
+
+#[test]
+fn next_focus_test() {
+    let top = Entity::from_raw(1);
+    let bottom = Entity::from_raw(2);
+    let bottom_right = Entity::from_raw(3);
+    let right = Entity::from_raw(4);
+    let candidates = vec![
+        (top, Vec2::new(0.0, 0.0)),
+        (bottom, Vec2::new(0.0, 100.0)),
+        (bottom_right, Vec2::new(300.0, 100.0)),
+        (right, Vec2::new(300.0, 0.0)),
+    ];
+
+    assert_eq!(first_focus(&candidates), Some(top));
+    assert_eq!(
+        next_focus(Vec2::ZERO, Vec2::new(0.0, 1.0), &candidates),
+        Some(bottom)
+    );
+    assert_eq!(
+        next_focus(Vec2::ZERO, Vec2::new(1.0, 0.0), &candidates),
+        Some(right)
+    );
+    assert_eq!(
+        next_focus(Vec2::ZERO, Vec2::new(0.0, -1.0), &candidates),
+        None
+    );
+    assert_eq!(
+        next_focus(Vec2::new(300.0, 100.0), Vec2::new(-1.0, 0.0), &candidates),
+        Some(bottom)
+    );
+}
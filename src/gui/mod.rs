@@ -1,15 +1,28 @@
 mod components;
 mod entities;
+mod focus;
 
-use bevy::app::{App, Plugin};
+use bevy::app::{App, Plugin, PreUpdate};
+use bevy::prelude::IntoSystemConfigs;
+use bevy::ui::UiSystem;
 pub use components::*;
 pub use entities::*;
+pub use focus::*;
 use sickle_ui::SickleUiPlugin;
 
+use crate::input::input_actions_update;
+
 pub struct UiPlugin;
 
 impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(SickleUiPlugin);
+        app.add_plugins(SickleUiPlugin)
+            .init_resource::<UiFocus>()
+            .add_systems(
+                PreUpdate,
+                ui_focus_handle
+                    .after(UiSystem::Focus)
+                    .after(input_actions_update),
+            );
     }
 }
@@ -0,0 +1,122 @@
+use bevy::input::gamepad::{Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, Gamepads};
+use bevy::input::{Axis, ButtonInput};
+use bevy::prelude::{KeyCode, Res, ResMut};
+use serde::{Deserialize, Serialize};
+
+use crate::input::bindings::InputBindings;
+
+// Stick is treated as a pressed direction when it's pushed further
+const STICK_THRESHOLD: f32 = 0.5;
+
+/// What player wants to do, independent of a keyboard or a gamepad.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum InputAction {
+    Interact,
+    Inspect,
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    OpenCharacter,
+    OpenInventory,
+    OpenJournal,
+    Back,
+    Confirm,
+    FocusUp,
+    FocusDown,
+    FocusLeft,
+    FocusRight,
+}
+
+impl InputAction {
+    pub const ALL: [InputAction; 15] = [
+        InputAction::Interact,
+        InputAction::Inspect,
+        InputAction::MoveUp,
+        InputAction::MoveDown,
+        InputAction::MoveLeft,
+        InputAction::MoveRight,
+        InputAction::OpenCharacter,
+        InputAction::OpenInventory,
+        InputAction::OpenJournal,
+        InputAction::Back,
+        InputAction::Confirm,
+        InputAction::FocusUp,
+        InputAction::FocusDown,
+        InputAction::FocusLeft,
+        InputAction::FocusRight,
+    ];
+
+    /// Key of the localized action title.
+    pub fn title_key(&self) -> &'static str {
+        match self {
+            InputAction::Interact => "input.interact",
+            InputAction::Inspect => "input.inspect",
+            InputAction::MoveUp => "input.move_up",
+            InputAction::MoveDown => "input.move_down",
+            InputAction::MoveLeft => "input.move_left",
+            InputAction::MoveRight => "input.move_right",
+            InputAction::OpenCharacter => "input.character",
+            InputAction::OpenInventory => "input.inventory",
+            InputAction::OpenJournal => "input.journal",
+            InputAction::Back => "input.back",
+            InputAction::Confirm => "input.confirm",
+            InputAction::FocusUp => "input.menu_up",
+            InputAction::FocusDown => "input.menu_down",
+            InputAction::FocusLeft => "input.menu_left",
+            InputAction::FocusRight => "input.menu_right",
+        }
+    }
+}
+
+pub fn input_actions_update(
+    bindings: Res<InputBindings>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    gamepad_axes: Res<Axis<GamepadAxis>>,
+    mut actions: ResMut<ButtonInput<InputAction>>,
+) {
+    actions.clear();
+    for action in InputAction::ALL {
+        let binding = bindings.get(action);
+        let key_pressed = binding.key.is_some_and(|key| keyboard.pressed(key));
+        let button_pressed = binding.button.is_some_and(|button_type| {
+            gamepads
+                .iter()
+                .any(|gamepad| gamepad_buttons.pressed(GamepadButton::new(gamepad, button_type)))
+        });
+        let stick_pushed = gamepads
+            .iter()
+            .any(|gamepad| stick_pushed(action, gamepad, &gamepad_axes));
+
+        if key_pressed || button_pressed || stick_pushed {
+            actions.press(action);
+        } else {
+            actions.release(action);
+        }
+    }
+}
+
+/// Left stick is not rebindable, it always moves the player and the menu focus.
+fn stick_pushed(action: InputAction, gamepad: Gamepad, axes: &Axis<GamepadAxis>) -> bool {
+    let value = |axis_type| {
+        axes.get(GamepadAxis::new(gamepad, axis_type))
+            .unwrap_or(0.0)
+    };
+    match action {
+        InputAction::MoveUp | InputAction::FocusUp => {
+            value(GamepadAxisType::LeftStickY) > STICK_THRESHOLD
+        }
+        InputAction::MoveDown | InputAction::FocusDown => {
+            value(GamepadAxisType::LeftStickY) < -STICK_THRESHOLD
+        }
+        InputAction::MoveLeft | InputAction::FocusLeft => {
+            value(GamepadAxisType::LeftStickX) < -STICK_THRESHOLD
+        }
+        InputAction::MoveRight | InputAction::FocusRight => {
+            value(GamepadAxisType::LeftStickX) > STICK_THRESHOLD
+        }
+        _ => false,
+    }
+}
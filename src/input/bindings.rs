@@ -0,0 +1,315 @@
+use bevy::input::gamepad::GamepadButtonType;
+use bevy::prelude::{KeyCode, Resource};
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::config::{load_config, save_config};
+use crate::input::actions::InputAction;
+
+const INPUT_CONFIG_NAME: &str = "input";
+
+/// Keys which can be bound to actions, their debug names are stored in the config.
+const BINDABLE_KEYS: [KeyCode; 54] = [
+    KeyCode::KeyA,
+    KeyCode::KeyB,
+    KeyCode::KeyC,
+    KeyCode::KeyD,
+    KeyCode::KeyE,
+    KeyCode::KeyF,
+    KeyCode::KeyG,
+    KeyCode::KeyH,
+    KeyCode::KeyI,
+    KeyCode::KeyJ,
+    KeyCode::KeyK,
+    KeyCode::KeyL,
+    KeyCode::KeyM,
+    KeyCode::KeyN,
+    KeyCode::KeyO,
+    KeyCode::KeyP,
+    KeyCode::KeyQ,
+    KeyCode::KeyR,
+    KeyCode::KeyS,
+    KeyCode::KeyT,
+    KeyCode::KeyU,
+    KeyCode::KeyV,
+    KeyCode::KeyW,
+    KeyCode::KeyX,
+    KeyCode::KeyY,
+    KeyCode::KeyZ,
+    KeyCode::Digit0,
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+    KeyCode::Digit8,
+    KeyCode::Digit9,
+    KeyCode::ArrowUp,
+    KeyCode::ArrowDown,
+    KeyCode::ArrowLeft,
+    KeyCode::ArrowRight,
+    KeyCode::Escape,
+    KeyCode::Enter,
+    KeyCode::Space,
+    KeyCode::Tab,
+    KeyCode::Backspace,
+    KeyCode::ShiftLeft,
+    KeyCode::ShiftRight,
+    KeyCode::ControlLeft,
+    KeyCode::ControlRight,
+    KeyCode::AltLeft,
+    KeyCode::AltRight,
+    KeyCode::Backquote,
+    KeyCode::Minus,
+    KeyCode::Equal,
+];
+
+const BINDABLE_BUTTONS: [GamepadButtonType; 19] = [
+    GamepadButtonType::South,
+    GamepadButtonType::East,
+    GamepadButtonType::North,
+    GamepadButtonType::West,
+    GamepadButtonType::C,
+    GamepadButtonType::Z,
+    GamepadButtonType::LeftTrigger,
+    GamepadButtonType::LeftTrigger2,
+    GamepadButtonType::RightTrigger,
+    GamepadButtonType::RightTrigger2,
+    GamepadButtonType::Select,
+    GamepadButtonType::Start,
+    GamepadButtonType::Mode,
+    GamepadButtonType::LeftThumb,
+    GamepadButtonType::RightThumb,
+    GamepadButtonType::DPadUp,
+    GamepadButtonType::DPadDown,
+    GamepadButtonType::DPadLeft,
+    GamepadButtonType::DPadRight,
+];
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Binding {
+    pub key: Option<KeyCode>,
+    pub button: Option<GamepadButtonType>,
+}
+
+impl Binding {
+    fn new(key: KeyCode, button: Option<GamepadButtonType>) -> Self {
+        Binding {
+            key: Some(key),
+            button,
+        }
+    }
+}
+
+/// Keyboard key and gamepad button of each action.
+#[derive(Resource, Debug, Clone, Eq, PartialEq)]
+pub struct InputBindings {
+    bindings: HashMap<InputAction, Binding>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BindingData {
+    key: Option<String>,
+    button: Option<String>,
+}
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        let bindings = HashMap::from([
+            (
+                InputAction::Interact,
+                Binding::new(KeyCode::KeyE, Some(GamepadButtonType::South)),
+            ),
+            (
+                InputAction::Inspect,
+                Binding::new(KeyCode::KeyF, Some(GamepadButtonType::West)),
+            ),
+            (
+                InputAction::MoveUp,
+                Binding::new(KeyCode::KeyW, Some(GamepadButtonType::DPadUp)),
+            ),
+            (
+                InputAction::MoveDown,
+                Binding::new(KeyCode::KeyS, Some(GamepadButtonType::DPadDown)),
+            ),
+            (
+                InputAction::MoveLeft,
+                Binding::new(KeyCode::KeyA, Some(GamepadButtonType::DPadLeft)),
+            ),
+            (
+                InputAction::MoveRight,
+                Binding::new(KeyCode::KeyD, Some(GamepadButtonType::DPadRight)),
+            ),
+            (
+                InputAction::OpenCharacter,
+                Binding::new(KeyCode::KeyC, Some(GamepadButtonType::North)),
+            ),
+            (
+                InputAction::OpenInventory,
+                Binding::new(KeyCode::KeyI, Some(GamepadButtonType::Select)),
+            ),
+            (
+                InputAction::OpenJournal,
+                Binding::new(KeyCode::KeyJ, Some(GamepadButtonType::RightTrigger)),
+            ),
+            (
+                InputAction::Back,
+                Binding::new(KeyCode::Escape, Some(GamepadButtonType::East)),
+            ),
+            (
+                InputAction::Confirm,
+                Binding::new(KeyCode::Enter, Some(GamepadButtonType::South)),
+            ),
+            (
+                InputAction::FocusUp,
+                Binding::new(KeyCode::ArrowUp, Some(GamepadButtonType::DPadUp)),
+            ),
+            (
+                InputAction::FocusDown,
+                Binding::new(KeyCode::ArrowDown, Some(GamepadButtonType::DPadDown)),
+            ),
+            (
+                InputAction::FocusLeft,
+                Binding::new(KeyCode::ArrowLeft, Some(GamepadButtonType::DPadLeft)),
+            ),
+            (
+                InputAction::FocusRight,
+                Binding::new(KeyCode::ArrowRight, Some(GamepadButtonType::DPadRight)),
+            ),
+        ]);
+        InputBindings { bindings }
+    }
+}
+
+impl InputBindings {
+    /// Bindings from the config, defaults are used for actions missing there.
+    pub fn load() -> Self {
+        load_config::<HashMap<InputAction, BindingData>>(INPUT_CONFIG_NAME)
+            .map(InputBindings::from_data)
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        save_config(INPUT_CONFIG_NAME, &self.to_data());
+    }
+
+    pub fn get(&self, action: InputAction) -> Binding {
+        self.bindings.get(&action).copied().unwrap_or_default()
+    }
+
+    pub fn bind_key(&mut self, action: InputAction, key: KeyCode) {
+        self.bindings.entry(action).or_default().key = Some(key);
+    }
+
+    pub fn bind_button(&mut self, action: InputAction, button: GamepadButtonType) {
+        self.bindings.entry(action).or_default().button = Some(button);
+    }
+
+    fn from_data(data: HashMap<InputAction, BindingData>) -> Self {
+        let mut result = InputBindings::default();
+        for (action, binding) in data {
+            result.bindings.insert(
+                action,
+                Binding {
+                    key: binding.key.as_deref().and_then(key_from_name),
+                    button: binding.button.as_deref().and_then(button_from_name),
+                },
+            );
+        }
+        result
+    }
+
+    fn to_data(&self) -> HashMap<InputAction, BindingData> {
+        self.bindings
+            .iter()
+            .map(|(action, binding)| {
+                let data = BindingData {
+                    key: binding.key.map(|key| format!("{key:?}")),
+                    button: binding.button.map(|button| format!("{button:?}")),
+                };
+                (*action, data)
+            })
+            .collect()
+    }
+}
+
+pub fn is_bindable_key(key: KeyCode) -> bool {
+    BINDABLE_KEYS.contains(&key)
+}
+
+pub fn is_bindable_button(button: GamepadButtonType) -> bool {
+    BINDABLE_BUTTONS.contains(&button)
+}
+
+pub fn key_title(key: Option<KeyCode>) -> String {
+    match key {
+        Some(key) => {
+            let name = format!("{key:?}");
+            let short_name = name
+                .strip_prefix("Key")
+                .or(name.strip_prefix("Digit"))
+                .unwrap_or(&name);
+            short_name.to_string()
+        }
+        None => "-".to_string(),
+    }
+}
+
+pub fn button_title(button: Option<GamepadButtonType>) -> String {
+    match button {
+        Some(button) => format!("{button:?}"),
+        None => "-".to_string(),
+    }
+}
+
+fn key_from_name(name: &str) -> Option<KeyCode> {
+    BINDABLE_KEYS
+        .iter()
+        .copied()
+        .find(|key| format!("{key:?}") == name)
+}
+
+fn button_from_name(name: &str) -> Option<GamepadButtonType> {
+    BINDABLE_BUTTONS
+        .iter()
+        .copied()
+        .find(|button| format!("{button:?}") == name)
+}
+
+#[test]
+fn input_bindings_data_test() {
+    let mut bindings = InputBindings::default();
+    bindings.bind_key(InputAction::Interact, KeyCode::Space);
+    bindings.bind_button(InputAction::Back, GamepadButtonType::Start);
+
+    let json = serde_json::to_string(&bindings.to_data()).unwrap();
+    let restored = InputBindings::from_data(serde_json::from_str(&json).unwrap());
+
+    assert_eq!(restored, bindings);
+    assert_eq!(
+        restored.get(InputAction::Interact).key,
+        Some(KeyCode::Space)
+    );
+}
+
+#[test]
+fn input_bindings_from_partial_data_test() {
+    let json = r#"{"Back": {"key": "Backspace", "button": "Unknown"}}"#;
+    let bindings = InputBindings::from_data(serde_json::from_str(json).unwrap());
+
+    assert_eq!(
+        bindings.get(InputAction::Back),
+        Binding {
+            key: Some(KeyCode::Backspace),
+            button: None,
+        }
+    );
+    assert_eq!(
+        bindings.get(InputAction::Interact),
+        InputBindings::default().get(InputAction::Interact)
+    );
+    assert_eq!(key_title(Some(KeyCode::KeyE)), "E");
+}
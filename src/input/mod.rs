@@ -0,0 +1,19 @@
+use bevy::app::{App, Plugin, PreUpdate};
+use bevy::input::{ButtonInput, InputSystem};
+use bevy::prelude::IntoSystemConfigs;
+
+pub use actions::*;
+pub use bindings::*;
+
+mod actions;
+mod bindings;
+
+pub struct InputPlugin;
+
+impl Plugin for InputPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ButtonInput<InputAction>>()
+            .insert_resource(InputBindings::load())
+            .add_systems(PreUpdate, input_actions_update.after(InputSystem));
+    }
+}
@@ -1,12 +1,14 @@
 use bevy::input::ButtonInput;
-use bevy::prelude::{Bundle, Commands, Entity, KeyCode, Query, Res, ResMut, Time, Transform, With};
+use bevy::prelude::{Added, Changed, Query, Res, ResMut, Time, Transform};
 use bevy::{prelude::Component, time::Timer};
 
 use crate::core::{
     geometry::BBox,
-    state_machines::{CycleLinearTransition, FiniteLinearTransition},
+    state_machines::{CycleLinearTransition, Transition},
 };
-use crate::party::PartyStateStorage;
+use crate::input::InputAction;
+use crate::interaction::storages::ContainersStorage;
+use crate::rpg::{Class, Loot, QuestItem};
 
 #[derive(Component)]
 pub struct ActiveInteractor {
@@ -17,42 +19,111 @@ pub struct ActiveInteractor {
 #[derive(Component)]
 pub struct PassiveInteractor {
     pub area: InteractionArea,
+    #[allow(dead_code)]
     pub side: InteractionSide,
 }
 
+// Id is used to keep state of the container between level reloads,
+// so it must be unique across all levels.
 #[derive(Component)]
-pub struct LimitedInteractor;
-
-#[derive(Component)]
-pub struct Container<T: Bundle + Clone> {
+pub struct Container {
+    pub id: usize,
     pub state: ContainerState,
-    pub items: Vec<T>,
+    pub items: Vec<Loot>,
+    pub lock: Option<Lock>,
+}
+
+impl Container {
+    pub fn create(id: usize, items: Vec<Loot>, lock: Option<Lock>) -> Self {
+        let state = if lock.is_some() {
+            ContainerState::Locked
+        } else {
+            ContainerState::initial_state()
+        };
+        Container {
+            id,
+            state,
+            items,
+            lock,
+        }
+    }
+}
+
+// Any of requirements is enough to unlock
+#[derive(Clone, Debug, Default)]
+pub struct Lock {
+    pub key: Option<QuestItem>,
+    pub strength: Option<i32>,
+    pub agility: Option<i32>,
+}
+
+impl Lock {
+    pub fn can_unlock(&self, quest_items: &[QuestItem], class: &Class) -> bool {
+        if let Some(key) = &self.key {
+            if quest_items.contains(key) {
+                return true;
+            }
+        }
+        if let Some(strength) = self.strength {
+            if class.strength() >= strength {
+                return true;
+            }
+        }
+        if let Some(agility) = self.agility {
+            if class.agility() >= agility {
+                return true;
+            }
+        }
+        false
+    }
 }
-#[derive(PartialEq)]
+
+#[derive(PartialEq, Clone, Copy, Debug)]
 pub enum ContainerState {
+    Locked,
     Closed,
-    Full,
-    Empty,
+    Opened,
 }
 
-impl FiniteLinearTransition for ContainerState {
-    fn transit(&self) -> Self {
-        match self {
-            ContainerState::Closed => ContainerState::Full,
-            _ => ContainerState::Empty,
+pub enum ContainerAction {
+    Unlock,
+    Open,
+    Close,
+}
+
+impl Transition<ContainerAction> for ContainerState {
+    fn transit(&self, action: &ContainerAction) -> Self {
+        match (self, action) {
+            (ContainerState::Locked, ContainerAction::Unlock) => ContainerState::Closed,
+            (ContainerState::Closed, ContainerAction::Open) => ContainerState::Opened,
+            (ContainerState::Opened, ContainerAction::Close) => ContainerState::Closed,
+            (state, _) => *state,
         }
     }
 
     fn initial_state() -> Self {
         ContainerState::Closed
     }
+}
 
-    fn final_state() -> Self {
-        ContainerState::Empty
+pub fn restore_containers_state(
+    storage: Res<ContainersStorage>,
+    mut containers: Query<&mut Container, Added<Container>>,
+) {
+    for mut container in containers.iter_mut() {
+        if let Some((state, items)) = storage.get_by_id(&container.id) {
+            container.state = state;
+            container.items = items;
+        }
     }
+}
 
-    fn is_finished(&self) -> bool {
-        *self == Self::final_state()
+pub fn store_containers_state(
+    mut storage: ResMut<ContainersStorage>,
+    containers: Query<&Container, Changed<Container>>,
+) {
+    for container in containers.iter() {
+        storage.update_by_id(container.id, container.state, container.items.clone());
     }
 }
 
@@ -71,11 +142,7 @@ pub enum SwitcherState {
 
 impl SwitcherState {
     pub fn is_in_transition(&self) -> bool {
-        match self {
-            SwitcherState::ToOff => true,
-            SwitcherState::ToOn => true,
-            _ => false,
-        }
+        matches!(self, SwitcherState::ToOff | SwitcherState::ToOn)
     }
 }
 
@@ -162,7 +229,7 @@ pub fn detect_active_interaction(
 
 pub fn change_switcher_state(
     time: Res<Time>,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    actions: Res<ButtonInput<InputAction>>,
     active: Query<(&ActiveInteractor, &Transform)>,
     mut interactors: Query<(&PassiveInteractor, &Transform, &mut Switcher)>,
 ) {
@@ -173,8 +240,8 @@ pub fn change_switcher_state(
                 switcher.state = switcher.state.transit();
             }
         } else {
-            let is_pressed =
-                keyboard.pressed(KeyCode::KeyE) && keyboard.just_pressed(KeyCode::KeyE);
+            let is_pressed = actions.pressed(InputAction::Interact)
+                && actions.just_pressed(InputAction::Interact);
             if is_pressed && detect_active_interaction(&active, (interactor, transform)) {
                 switcher.timer.reset();
                 switcher.state = switcher.state.transit();
@@ -182,3 +249,60 @@ pub fn change_switcher_state(
         }
     }
 }
+
+#[test]
+fn container_state_transit_test() {
+    let locked = ContainerState::Locked;
+    assert_eq!(
+        locked.transit(&ContainerAction::Open),
+        ContainerState::Locked
+    );
+    assert_eq!(
+        locked.transit(&ContainerAction::Unlock),
+        ContainerState::Closed
+    );
+
+    let closed = ContainerState::Closed;
+    assert_eq!(
+        closed.transit(&ContainerAction::Open),
+        ContainerState::Opened
+    );
+    assert_eq!(
+        closed.transit(&ContainerAction::Close),
+        ContainerState::Closed
+    );
+
+    let opened = ContainerState::Opened;
+    assert_eq!(
+        opened.transit(&ContainerAction::Close),
+        ContainerState::Closed
+    );
+}
+
+#[test]
+fn lock_can_unlock_test() {
+    let lock = Lock {
+        key: Some(QuestItem::ChestKey),
+        strength: Some(3),
+        agility: None,
+    };
+    let weak = Class::FormidableFace {
+        strength: 1,
+        agility: 5,
+        stamina: 1,
+        fortitude: 1,
+        charisma: 1,
+    };
+    let strong = Class::FormidableFace {
+        strength: 3,
+        agility: 1,
+        stamina: 1,
+        fortitude: 1,
+        charisma: 1,
+    };
+
+    assert!(!lock.can_unlock(&[], &weak));
+    assert!(!lock.can_unlock(&[QuestItem::Feather], &weak));
+    assert!(lock.can_unlock(&[QuestItem::ChestKey], &weak));
+    assert!(lock.can_unlock(&[], &strong));
+}
@@ -1,29 +1,42 @@
-use bevy::app::{Plugin, Update};
+use bevy::app::{Plugin, Startup, Update};
 use bevy::input::ButtonInput;
-use bevy::prelude::{KeyCode, Query, Res, Transform};
+use bevy::prelude::{Commands, IntoSystemConfigs, Query, Res, Transform};
 
 use crate::core::entities::Description;
+use crate::input::InputAction;
 use crate::interaction::interactors::change_switcher_state;
 use crate::interaction::interactors::detect_active_interaction;
 use crate::interaction::interactors::ActiveInteractor;
 use crate::interaction::interactors::PassiveInteractor;
+use crate::interaction::interactors::{restore_containers_state, store_containers_state};
+use crate::interaction::storages::ContainersStorage;
 
 pub mod interactors;
+pub mod storages;
 
 pub struct BaseInteractionPlugin;
 
 impl Plugin for BaseInteractionPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.add_systems(Update, (show_lookups, change_switcher_state));
+        app.add_systems(Startup, init_storages)
+            .add_systems(Update, (show_lookups, change_switcher_state))
+            .add_systems(
+                Update,
+                (restore_containers_state, store_containers_state).chain(),
+            );
     }
 }
 
+fn init_storages(mut commands: Commands) {
+    commands.insert_resource(ContainersStorage::default());
+}
+
 fn show_lookups(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    actions: Res<ButtonInput<InputAction>>,
     active: Query<(&ActiveInteractor, &Transform)>,
     interactors: Query<(&PassiveInteractor, &Transform, &Description)>,
 ) {
-    if !(keyboard.pressed(KeyCode::KeyF) && keyboard.just_pressed(KeyCode::KeyF)) {
+    if !(actions.pressed(InputAction::Inspect) && actions.just_pressed(InputAction::Inspect)) {
         return;
     }
     for (inteactor, transform, description) in interactors.iter() {
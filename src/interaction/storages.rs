@@ -0,0 +1,18 @@
+use bevy::prelude::Resource;
+use bevy::utils::HashMap;
+
+use crate::interaction::interactors::ContainerState;
+use crate::rpg::Loot;
+
+#[derive(Resource, Default)]
+pub struct ContainersStorage(HashMap<usize, (ContainerState, Vec<Loot>)>);
+
+impl ContainersStorage {
+    pub fn get_by_id(&self, id: &usize) -> Option<(ContainerState, Vec<Loot>)> {
+        self.0.get(id).cloned()
+    }
+
+    pub fn update_by_id(&mut self, id: usize, state: ContainerState, items: Vec<Loot>) {
+        self.0.insert(id, (state, items));
+    }
+}
@@ -1,8 +1,9 @@
 use bevy::app::{Plugin, Update};
 use bevy::asset::{AssetServer, Assets};
-use bevy::hierarchy::BuildChildren;
-use bevy::math::{UVec2, Vec3};
+use bevy::hierarchy::{BuildChildren, DespawnRecursiveExt};
+use bevy::math::UVec2;
 use bevy::prelude::in_state;
+use bevy::prelude::Entity;
 use bevy::prelude::IntoSystemConfigs;
 use bevy::prelude::OnEnter;
 use bevy::prelude::OnExit;
@@ -16,24 +17,30 @@ use bevy::prelude::TextureAtlasLayout;
 use bevy::prelude::Timer;
 use bevy::prelude::Transform;
 use bevy::prelude::TransformBundle;
-use bevy::prelude::{Commands, Component, NextState};
+use bevy::prelude::With;
+use bevy::prelude::{Commands, Component};
 use bevy_rapier2d::dynamics::RigidBody;
 use bevy_rapier2d::geometry::Collider;
 
 use crate::core::collisions::recalculate_z;
 use crate::core::entities::{BodyYOffset, LevelYMax};
-use crate::core::z_index::{calculate_z, DEFAULT_OBJECT_Z, FLOOR_Z, ON_WALL_OBJECT_Z, WALL_Z};
-use crate::dialog::SelectedVariantsSource;
+use crate::core::z_index::{calculate_z, DEFAULT_OBJECT_Z};
+use crate::cutscene::{CutsceneActor, CutscenePlayer};
 use crate::interaction::interactors::{InteractionArea, InteractionSide, PassiveInteractor};
-use crate::level::objects::spawn_object;
+use crate::level::dialog_starts;
+use crate::level::states::Level;
+use crate::level::tiled::TiledLevel;
 use crate::level::HasDialogId;
 use crate::level::JUDGES_FIRST_DIALOG;
 use crate::level::JUDGES_SECOND_DIALOG;
 use crate::level::JUDGES_THIRD_DIALOG;
-use crate::level::{dialog_starts, JUDGES_FIRST_DIALOG_BEATEN, JUDGES_FIRST_DIALOG_COMPLETED};
+use crate::level::{JUDGES_ACTOR, TRIAL_VERDICT_CUTSCENE};
 use crate::npc::IdleAnimation;
 use crate::world_state::Trial;
 
+#[derive(Component)]
+struct CourtLevel;
+
 #[derive(Component)]
 struct JudgesDialog;
 
@@ -83,161 +90,19 @@ impl<S: States> Plugin for CourtPlugin<S> {
                 dialog_starts::<JudgesFormidableFaceFailed>
                     .run_if(in_state(Trial::FormidableFaceFailed)),
             )
-            .add_systems(
-                Update,
-                (dialog_variants_handles, recalculate_z).run_if(in_state(self.state.clone())),
-            );
+            .add_systems(OnEnter(Trial::Wait), verdict_cutscene_starts)
+            .add_systems(Update, recalculate_z.run_if(in_state(self.state.clone())));
     }
 }
 
-fn load(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    mut trial_state: ResMut<NextState<Trial>>,
-) {
-    trial_state.set(Trial::SpeakWithJudges);
-    let y_max = LevelYMax::create(226.0);
-    commands.spawn(y_max);
-
-    spawn_floor(&mut commands, &asset_server);
-
-    let wall = asset_server.load("court/wall.png");
-    spawn_object(
-        &mut commands,
-        wall,
-        0.0,
-        y_max.value,
-        WALL_Z,
-        359.0,
-        30.0,
-        0.0,
-    );
-
-    spawn_doors(&mut commands, &asset_server, -200.0, 218.0);
-    spawn_doors(&mut commands, &asset_server, 0.0, 218.0);
-    spawn_doors(&mut commands, &asset_server, 200.0, 218.0);
-
-    // -------------------------------------------------------------------
-    let flower = asset_server.load("court/bench.png");
-    let x = -270.0;
-    let y = -60.0;
-    let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 65.0, 18.0, -5.0);
-
-    let flower = asset_server.load("court/bench.png");
-    let x = -120.0;
-    let y = -60.0;
-    let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 65.0, 18.0, -5.0);
-
-    let flower = asset_server.load("court/bench.png");
-    let x = -270.0;
-    let y = -125.0;
-    let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 65.0, 18.0, -5.0);
-
-    let flower = asset_server.load("court/bench.png");
-    let x = -120.0;
-    let y = -125.0;
-    let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 65.0, 18.0, -5.0);
-
-    let flower = asset_server.load("court/bench.png");
-    let x = -270.0;
-    let y = -190.0;
-    let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 65.0, 18.0, -5.0);
-
-    let flower = asset_server.load("court/bench.png");
-    let x = -120.0;
-    let y = -190.0;
-    let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 65.0, 18.0, -5.0);
-
-    let flower = asset_server.load("court/bench.png");
-    let x = 270.0;
-    let y = -60.0;
-    let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 65.0, 18.0, -5.0);
-
-    let flower = asset_server.load("court/bench.png");
-    let x = 120.0;
-    let y = -60.0;
-    let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 65.0, 18.0, -5.0);
-
-    let flower = asset_server.load("court/bench.png");
-    let x = 270.0;
-    let y = -125.0;
-    let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 65.0, 18.0, -5.0);
-
-    let flower = asset_server.load("court/bench.png");
-    let x = 120.0;
-    let y = -125.0;
-    let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 65.0, 18.0, -5.0);
-
-    let flower = asset_server.load("court/bench.png");
-    let x = 270.0;
-    let y = -190.0;
-    let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 65.0, 18.0, -5.0);
-
-    let flower = asset_server.load("court/bench.png");
-    let x = 120.0;
-    let y = -190.0;
-    let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 65.0, 18.0, -5.0);
-    // -------------------------------------------------------------------
-
-    let flower = asset_server.load("courthouse_hall/work_place.png");
-    let x = 280.0;
-    let y = 180.0;
-    let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 15.0, 37.0, -5.0);
-
-    let flower = asset_server.load("court/cage.png");
-    let x = -297.0;
-    let y = 169.0;
-    let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 64.0, 87.0, -5.0);
-
-    // -------------------------------------------------------------------
-    let tribune = asset_server.load("court/tribune.png");
-    let x = -110.0;
-    let y = 30.0;
-    let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, tribune, x, y, z, 22.0, 23.0, -5.0);
-
-    let tribune = asset_server.load("court/tribune.png");
-    let x = 90.0;
-    let y = 30.0;
-    let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, tribune, x, y, z, 22.0, 23.0, -5.0);
-    // -------------------------------------------------------------------
-}
-
-fn spawn_floor(commands: &mut Commands, asset_server: &Res<AssetServer>) {
-    commands.spawn(RigidBody::Fixed).insert(SpriteBundle {
-        texture: asset_server.load("court/floor.png"),
-        transform: Transform {
-            translation: Vec3::new(0.0, 0.0, FLOOR_Z),
-            ..Default::default()
+fn load(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        TiledLevel {
+            level: Level::Court,
+            map: asset_server.load("court/court.tmj"),
         },
-        ..Default::default()
-    });
-}
-
-fn spawn_doors(commands: &mut Commands, asset_server: &Res<AssetServer>, x: f32, y: f32) {
-    commands.spawn(RigidBody::Fixed).insert(SpriteBundle {
-        texture: asset_server.load("courthouse_hall/doors.png"),
-        transform: Transform {
-            translation: Vec3::new(x, y, ON_WALL_OBJECT_Z),
-            ..Default::default()
-        },
-        ..Default::default()
-    });
+        CourtLevel,
+    ));
 }
 
 fn spawn_judges(
@@ -273,6 +138,8 @@ fn spawn_judges(
             JudgesDialog,
             JudgesFormidableFaceWon,
             JudgesFormidableFaceFailed,
+            CourtLevel,
+            CutsceneActor(JUDGES_ACTOR.to_string()),
         ))
         .insert(IdleAnimation {
             timer: Timer::from_seconds(0.15, bevy::time::TimerMode::Repeating),
@@ -295,27 +162,13 @@ fn spawn_judges(
         });
 }
 
-fn dialog_variants_handles(
-    mut dialog_variant_source: ResMut<SelectedVariantsSource>,
-    mut trial_state: ResMut<NextState<Trial>>,
-) {
-    // todo: it calculates on each frame.
-    // make it when dialog_variant_source updates only.
-    let selected = dialog_variant_source.consume(&JUDGES_FIRST_DIALOG);
-    match selected {
-        None => {}
-        Some(ids) => {
-            for id in ids {
-                if id == JUDGES_FIRST_DIALOG_COMPLETED {
-                    trial_state.set(Trial::Wait);
-                }
+// Judges go to deliberation right after the trial
+fn verdict_cutscene_starts(mut cutscene_player: ResMut<CutscenePlayer>) {
+    cutscene_player.start(TRIAL_VERDICT_CUTSCENE);
+}
 
-                if id == JUDGES_FIRST_DIALOG_BEATEN {
-                    trial_state.set(Trial::GoAtHome);
-                }
-            }
-        }
+fn unload(mut commands: Commands, query: Query<Entity, With<CourtLevel>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
     }
 }
-
-fn unload() {}
@@ -1,11 +1,14 @@
-use crate::animation::entities::MoveDirection;
+use crate::movement::entities::MoveDirection;
 use bevy::app::{Plugin, Update};
 use bevy::asset::{AssetServer, Assets};
-use bevy::hierarchy::BuildChildren;
+use bevy::hierarchy::{BuildChildren, DespawnRecursiveExt};
 use bevy::math::{UVec2, Vec3};
 use bevy::prelude::in_state;
 use bevy::prelude::Commands;
 use bevy::prelude::Component;
+use bevy::prelude::Condition;
+use bevy::prelude::Entity;
+use bevy::prelude::EventReader;
 use bevy::prelude::IntoSystemConfigs;
 use bevy::prelude::NextState;
 use bevy::prelude::OnEnter;
@@ -20,6 +23,7 @@ use bevy::prelude::TextureAtlasLayout;
 use bevy::prelude::Timer;
 use bevy::prelude::Transform;
 use bevy::prelude::TransformBundle;
+use bevy::prelude::With;
 use bevy_rapier2d::dynamics::RigidBody;
 use bevy_rapier2d::geometry::Collider;
 
@@ -32,39 +36,36 @@ use crate::core::z_index::FLOOR_Z;
 use crate::core::z_index::MIN_RANGE_Z;
 use crate::core::z_index::ON_WALL_OBJECT_Z;
 use crate::core::z_index::WALL_Z;
-use crate::dialog::SelectedVariantsSource;
+use crate::fight::{FightId, FightResult, GOPNIKS_FIGHT_ID};
 use crate::interaction::interactors::InteractionArea;
 use crate::interaction::interactors::InteractionSide;
+use crate::interaction::interactors::Lock;
 use crate::interaction::interactors::PassiveInteractor;
-use crate::level::objects::spawn_object;
+use crate::level::objects::{spawn_container, spawn_object, COURTHOUSE_FRONT_CHEST_ID};
+use crate::level::registry::LevelRegistry;
+use crate::level::states::Level;
+use crate::level::transitions::{level_exit_conditions, LevelExit, LevelExitCondition, SpawnPoint};
 use crate::level::BLOND_GIVE_DUMPLINGS_DIALOG;
 use crate::level::BLOND_TAKE_DUMPLINGS_DIALOG;
 use crate::level::DREVNIRA_DIALOG;
-use crate::level::END_DIALOG_BLOND_FIRST_ACCEPTED;
-use crate::level::END_DIALOG_BLOND_GIVE_DUMPLINGS_COMPLETED;
-use crate::level::END_DIALOG_BLOND_TAKE_DUMPLINGS_JUST_COMPLETED;
-use crate::level::END_DIALOG_BLOND_TAKE_DUMPLINGS_NECK_TWISTED;
-use crate::level::END_DIALOG_DREVNIRA_BEATEN;
-use crate::level::END_DIALOG_GOPNIKS_DIALOG_ASK_BLOND;
-use crate::level::END_DIALOG_GUARDIAN_FIRST_BEATEN;
-use crate::level::END_DIALOG_GUARDIAN_FIRST_DREVNIRA_STOP_ACCEPTED;
-use crate::level::END_DIALOG_GUARDIAN_FIRST_JUST_COMPLETED;
-use crate::level::END_DIALOG_GUARDIAN_SECOND_BEATEN;
-use crate::level::END_DIALOG_GUARDIAN_SECOND_COMPLETED;
-use crate::level::END_DIALOG_GUARDIAN_THIRD_BEATEN;
-use crate::level::END_DIALOG_GUARDIAN_THIRD_COMPLETED;
 use crate::level::GOPNIKS_DIALOG;
 use crate::level::GUARDIAN_FIRST_DIALOG;
 use crate::level::GUARDIAN_SECOND_DIALOG;
 use crate::level::GUARDIAN_THIRD_DIALOG;
 use crate::level::{dialog_starts, HasDialogId, BLOND_FIRST_DIALOG};
+use crate::level::{trade_starts, HasShopId, PIE_SELLER_SHOP};
 use crate::npc::{spawn_fixed_npc, IdleAnimation};
-use crate::world_state::{BlondAndGopniks, Court, StrangeOldWoman};
+use crate::party::PartyStateStorage;
+use crate::rpg::{ConsumableItem, EquipmentItem, Loot, QuestItem};
+use crate::world_state::{BlondAndGopniks, Court, QuestEvent, StrangeOldWoman};
 
 pub struct CourtHouseFrontPlugin<S: States> {
     pub state: S,
 }
 
+#[derive(Component)]
+struct CourtHouseFrontLevel;
+
 #[derive(Component)]
 struct Drevnira;
 
@@ -137,6 +138,15 @@ impl HasDialogId for GuardianThirdStage {
     }
 }
 
+#[derive(Component)]
+struct PieSeller;
+
+impl HasShopId for PieSeller {
+    fn shop_id(&self) -> usize {
+        PIE_SELLER_SHOP
+    }
+}
+
 impl<S: States> Plugin for CourtHouseFrontPlugin<S> {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_systems(OnEnter(self.state.clone()), load)
@@ -145,10 +155,13 @@ impl<S: States> Plugin for CourtHouseFrontPlugin<S> {
             .add_systems(OnEnter(self.state.clone()), spawn_guardians)
             .add_systems(OnEnter(self.state.clone()), spawn_gopniks)
             .add_systems(OnEnter(self.state.clone()), spawn_blond_man)
-            .add_systems(OnExit(GameState::Exploration), unload)
+            .add_systems(OnEnter(self.state.clone()), spawn_pie_seller)
             .add_systems(
                 Update,
-                dialog_starts::<Drevnira>.run_if(in_state(StrangeOldWoman::GiveMeFeather)),
+                dialog_starts::<Drevnira>.run_if(
+                    in_state(StrangeOldWoman::GiveMeFeather)
+                        .or_else(in_state(StrangeOldWoman::FeatherFound)),
+                ),
             )
             .add_systems(
                 Update,
@@ -158,6 +171,10 @@ impl<S: States> Plugin for CourtHouseFrontPlugin<S> {
                 Update,
                 dialog_starts::<Gopnik>.run_if(in_state(BlondAndGopniks::TalkWithGopniks)),
             )
+            .add_systems(
+                OnExit(GameState::Dialog),
+                gopniks_fight_starts.run_if(in_state(BlondAndGopniks::FightGopniks)),
+            )
             .add_systems(
                 Update,
                 dialog_starts::<BlondGiveDumplings>
@@ -182,29 +199,66 @@ impl<S: States> Plugin for CourtHouseFrontPlugin<S> {
             )
             .add_systems(
                 Update,
-                (dialog_variants_handles, recalculate_z).run_if(in_state(self.state.clone())),
+                (
+                    recalculate_z,
+                    trade_starts::<PieSeller>,
+                    gopniks_fight_rewards,
+                    level_exit_conditions::<Court>,
+                )
+                    .run_if(in_state(self.state.clone())),
             );
     }
 }
 
+// Fight starts when the gopniks dialog is closed, so its last replica is still shown
+fn gopniks_fight_starts(
+    mut commands: Commands,
+    mut fight_id_query: Query<&mut FightId>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+) {
+    match fight_id_query.get_single_mut() {
+        Ok(mut fight_id) => fight_id.0 = GOPNIKS_FIGHT_ID.0,
+        Err(_) => {
+            commands.spawn(FightId(GOPNIKS_FIGHT_ID.0));
+        }
+    }
+    next_game_state.set(GameState::Fighting);
+}
+
+// Beaten gopniks give up the same two packs of dumplings as intimidated ones
+fn gopniks_fight_rewards(
+    mut quest_events: EventReader<QuestEvent>,
+    mut party_storage: ResMut<PartyStateStorage>,
+) {
+    for event in quest_events.read() {
+        if let QuestEvent::FightFinished {
+            fight_id,
+            result: FightResult::Won,
+        } = event
+        {
+            if *fight_id == GOPNIKS_FIGHT_ID.0 {
+                party_storage.add_consumables(vec![
+                    ConsumableItem::default_dumplings(),
+                    ConsumableItem::default_dumplings(),
+                ]);
+            }
+        }
+    }
+}
+
 fn load(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    mut drevnira_state: ResMut<NextState<StrangeOldWoman>>,
-    mut blond_state: ResMut<NextState<BlondAndGopniks>>,
-    mut court_state: ResMut<NextState<Court>>,
+    level_registry: Res<LevelRegistry>,
 ) {
-    drevnira_state.set(StrangeOldWoman::GiveMeFeather);
-    blond_state.set(BlondAndGopniks::TalkWithBlond);
-    court_state.set(Court::TalkWithGuardian);
-
-    let y_max = LevelYMax::create(500.0);
-    commands.spawn(y_max);
+    let y_max = LevelYMax::create(level_registry.y_max(&Level::CourtHouseFront));
+    commands.spawn((y_max, CourtHouseFrontLevel));
 
     spawn_ground(&mut commands, &asset_server);
+    spawn_arrival_points(&mut commands);
 
     commands
-        .spawn_empty()
+        .spawn(CourtHouseFrontLevel)
         .insert(TransformBundle::from(Transform::from_xyz(0.0, 0.0, 1.0)))
         .with_children(|children| {
             children
@@ -222,7 +276,8 @@ fn load(
         500.0,
         65.0,
         0.0,
-    );
+    )
+    .insert(CourtHouseFrontLevel);
 
     spawn_court_doors(&mut commands, &asset_server, 0.0, 371.0);
 
@@ -236,7 +291,8 @@ fn load(
         33.0,
         450.0,
         0.0,
-    );
+    )
+    .insert(CourtHouseFrontLevel);
 
     let right_forest = asset_server.load("courthouse_front/vertical_forest_0.png");
     spawn_object(
@@ -248,164 +304,226 @@ fn load(
         20.0,
         500.0,
         0.0,
-    );
+    )
+    .insert(CourtHouseFrontLevel);
 
     let tree_1 = asset_server.load("courthouse_front/tree_1.png");
     let x = 230.0;
     let y = 200.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, tree_1, x, y, z, 31.0, 20.0, -5.0);
+    spawn_object(&mut commands, tree_1, x, y, z, 31.0, 20.0, -5.0).insert(CourtHouseFrontLevel);
+
+    let chest = asset_server.load("chest/wooden.png");
+    let x = -400.0;
+    let y = -420.0;
+    let z = calculate_z(y, y_max.value);
+    spawn_container(
+        &mut commands,
+        CourtHouseFrontLevel,
+        chest,
+        x,
+        y,
+        z,
+        -2.0,
+        COURTHOUSE_FRONT_CHEST_ID,
+        vec![
+            Loot::Equipment(EquipmentItem::LeatherJacket { armor: 2 }),
+            Loot::Money(20),
+            Loot::Quest(QuestItem::Feather),
+        ],
+        Some(Lock {
+            key: Some(QuestItem::ChestKey),
+            strength: Some(3),
+            agility: None,
+        }),
+    );
 
     // -------------------------------------------------------------------
     let tree_3 = asset_server.load("courthouse_front/tree_3.png");
     let x = 265.0;
     let y = 215.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, tree_3, x, y, z, 24.0, 20.0, -5.0);
+    spawn_object(&mut commands, tree_3, x, y, z, 24.0, 20.0, -5.0).insert(CourtHouseFrontLevel);
 
     let tree_1 = asset_server.load("courthouse_front/tree_1.png");
     let x = 210.0;
     let y = 220.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, tree_1, x, y, z, 31.0, 20.0, -5.0);
+    spawn_object(&mut commands, tree_1, x, y, z, 31.0, 20.0, -5.0).insert(CourtHouseFrontLevel);
 
     let tree_1 = asset_server.load("courthouse_front/tree_1.png");
     let x = 253.0;
     let y = 225.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, tree_1, x, y, z, 31.0, 20.0, -5.0);
+    spawn_object(&mut commands, tree_1, x, y, z, 31.0, 20.0, -5.0).insert(CourtHouseFrontLevel);
     // -------------------------------------------------------------------
 
     let tree_3 = asset_server.load("courthouse_front/tree_3.png");
     let x = 95.0;
     let y = 165.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, tree_3, x, y, z, 24.0, 20.0, -5.0);
+    spawn_object(&mut commands, tree_3, x, y, z, 24.0, 20.0, -5.0).insert(CourtHouseFrontLevel);
 
     let tree_2 = asset_server.load("courthouse_front/tree_2.png");
     let x = 105.0;
     let y = 155.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, tree_2, x, y, z, 23.0, 20.0, -5.0);
+    spawn_object(&mut commands, tree_2, x, y, z, 23.0, 20.0, -5.0).insert(CourtHouseFrontLevel);
 
     // -------------------------------------------------------------------
     let tree_2 = asset_server.load("courthouse_front/tree_2.png");
     let x = -295.0;
     let y = 295.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, tree_2, x, y, z, 23.0, 20.0, -5.0);
+    spawn_object(&mut commands, tree_2, x, y, z, 23.0, 20.0, -5.0).insert(CourtHouseFrontLevel);
 
     let tree_3 = asset_server.load("courthouse_front/tree_3.png");
     let x = -280.0;
     let y = 275.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, tree_3, x, y, z, 24.0, 20.0, -5.0);
+    spawn_object(&mut commands, tree_3, x, y, z, 24.0, 20.0, -5.0).insert(CourtHouseFrontLevel);
     // -------------------------------------------------------------------
 
     let tree_1 = asset_server.load("courthouse_front/tree_1.png");
     let x = -320.0;
     let y = -70.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, tree_1, x, y, z, 31.0, 20.0, -5.0);
+    spawn_object(&mut commands, tree_1, x, y, z, 31.0, 20.0, -5.0).insert(CourtHouseFrontLevel);
 
     // -------------------------------------------------------------------
     let tree_1 = asset_server.load("courthouse_front/tree_1.png");
     let x = -350.0;
     let y = -170.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, tree_1, x, y, z, 31.0, 20.0, -5.0);
+    spawn_object(&mut commands, tree_1, x, y, z, 31.0, 20.0, -5.0).insert(CourtHouseFrontLevel);
 
     let tree_3 = asset_server.load("courthouse_front/tree_3.png");
     let x = -340.0;
     let y = -150.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, tree_3, x, y, z, 24.0, 20.0, -5.0);
+    spawn_object(&mut commands, tree_3, x, y, z, 24.0, 20.0, -5.0).insert(CourtHouseFrontLevel);
     // -------------------------------------------------------------------
 
     let tree_3 = asset_server.load("courthouse_front/tree_3.png");
     let x = 235.0;
     let y = -60.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, tree_3, x, y, z, 24.0, 20.0, -5.0);
+    spawn_object(&mut commands, tree_3, x, y, z, 24.0, 20.0, -5.0).insert(CourtHouseFrontLevel);
 
     // -------------------------------------------------------------------
     let tree_2 = asset_server.load("courthouse_front/tree_2.png");
     let x = 220.0;
     let y = -245.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, tree_2, x, y, z, 23.0, 20.0, -5.0);
+    spawn_object(&mut commands, tree_2, x, y, z, 23.0, 20.0, -5.0).insert(CourtHouseFrontLevel);
 
     let tree_2 = asset_server.load("courthouse_front/tree_2.png");
     let x = 210.0;
     let y = -240.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, tree_2, x, y, z, 23.0, 20.0, -5.0);
+    spawn_object(&mut commands, tree_2, x, y, z, 23.0, 20.0, -5.0).insert(CourtHouseFrontLevel);
 
     let tree_3 = asset_server.load("courthouse_front/tree_3.png");
     let x = 225.0;
     let y = -255.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, tree_3, x, y, z, 24.0, 20.0, -5.0);
+    spawn_object(&mut commands, tree_3, x, y, z, 24.0, 20.0, -5.0).insert(CourtHouseFrontLevel);
 
     let tree_2 = asset_server.load("courthouse_front/tree_2.png");
     let x = 210.0;
     let y = -215.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, tree_2, x, y, z, 23.0, 20.0, -5.0);
+    spawn_object(&mut commands, tree_2, x, y, z, 23.0, 20.0, -5.0).insert(CourtHouseFrontLevel);
     // -------------------------------------------------------------------
 
     let bench = asset_server.load("courthouse_front/bench.png");
     let x = 80.0;
     let y = 0.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, bench.clone(), x, y, z, 20.0, 40.0, -2.0);
+    spawn_object(&mut commands, bench.clone(), x, y, z, 20.0, 40.0, -2.0)
+        .insert(CourtHouseFrontLevel);
 
     let x = 430.0;
     let y = 225.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, bench.clone(), x, y, z, 20.0, 40.0, -2.0);
+    spawn_object(&mut commands, bench.clone(), x, y, z, 20.0, 40.0, -2.0)
+        .insert(CourtHouseFrontLevel);
 
     let y = 125.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, bench.clone(), x, y, z, 20.0, 40.0, -2.0);
+    spawn_object(&mut commands, bench.clone(), x, y, z, 20.0, 40.0, -2.0)
+        .insert(CourtHouseFrontLevel);
 
     let y = 25.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, bench.clone(), x, y, z, 20.0, 40.0, -2.0);
+    spawn_object(&mut commands, bench.clone(), x, y, z, 20.0, 40.0, -2.0)
+        .insert(CourtHouseFrontLevel);
 
     let y = -75.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, bench.clone(), x, y, z, 20.0, 40.0, -2.0);
+    spawn_object(&mut commands, bench.clone(), x, y, z, 20.0, 40.0, -2.0)
+        .insert(CourtHouseFrontLevel);
 
     let y = -175.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, bench.clone(), x, y, z, 20.0, 40.0, -2.0);
+    spawn_object(&mut commands, bench.clone(), x, y, z, 20.0, 40.0, -2.0)
+        .insert(CourtHouseFrontLevel);
 
     let y = -275.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, bench.clone(), x, y, z, 20.0, 40.0, -2.0);
+    spawn_object(&mut commands, bench.clone(), x, y, z, 20.0, 40.0, -2.0)
+        .insert(CourtHouseFrontLevel);
 }
 
 fn spawn_ground(commands: &mut Commands, asset_server: &Res<AssetServer>) {
-    commands.spawn(RigidBody::Fixed).insert(SpriteBundle {
-        texture: asset_server.load("courthouse_front/ground.png"),
-        transform: Transform {
-            translation: Vec3::new(0.0, 0.0, FLOOR_Z),
+    commands.spawn(RigidBody::Fixed).insert((
+        SpriteBundle {
+            texture: asset_server.load("courthouse_front/ground.png"),
+            transform: Transform {
+                translation: Vec3::new(0.0, 0.0, FLOOR_Z),
+                ..Default::default()
+            },
             ..Default::default()
         },
-        ..Default::default()
-    });
+        CourtHouseFrontLevel,
+    ));
+}
+
+fn spawn_arrival_points(commands: &mut Commands) {
+    commands.spawn((
+        SpawnPoint::create("house"),
+        TransformBundle::from(Transform::from_xyz(20.0, -400.0, DEFAULT_OBJECT_Z)),
+        CourtHouseFrontLevel,
+    ));
+    commands.spawn((
+        SpawnPoint::create("court_doors"),
+        TransformBundle::from(Transform::from_xyz(0.0, 320.0, DEFAULT_OBJECT_Z)),
+        CourtHouseFrontLevel,
+    ));
 }
 
 fn spawn_court_doors(commands: &mut Commands, asset_server: &Res<AssetServer>, x: f32, y: f32) {
-    commands.spawn(RigidBody::Fixed).insert(SpriteBundle {
-        texture: asset_server.load("courthouse_front/doors.png"),
-        transform: Transform {
-            translation: Vec3::new(x, y, ON_WALL_OBJECT_Z),
-            ..Default::default()
-        },
-        ..Default::default()
-    });
+    commands
+        .spawn(RigidBody::Fixed)
+        .insert((
+            SpriteBundle {
+                texture: asset_server.load("courthouse_front/doors.png"),
+                transform: Transform {
+                    translation: Vec3::new(x, y, ON_WALL_OBJECT_Z),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            CourtHouseFrontLevel,
+        ))
+        .insert(PassiveInteractor {
+            area: InteractionArea::from_sizes(27.0, 25.0),
+            side: InteractionSide::Bottom,
+        })
+        .insert(LevelExit::create(Level::CourtHouseHall, "entrance"))
+        .insert(LevelExitCondition {
+            state: Court::Completed,
+        });
 }
 
 fn spawn_old_woman_drevnira(
@@ -427,7 +545,7 @@ fn spawn_old_woman_drevnira(
 
     commands
         .spawn(RigidBody::Fixed)
-        .insert(Drevnira)
+        .insert((Drevnira, CourtHouseFrontLevel))
         .insert((
             SpriteBundle {
                 texture: image_handle,
@@ -459,6 +577,30 @@ fn spawn_old_woman_drevnira(
         });
 }
 
+fn spawn_pie_seller(
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+    level_y_max: Query<&LevelYMax>,
+) {
+    let default = LevelYMax::create(500.0);
+    let y_max = level_y_max.get_single().unwrap_or(&default);
+    let x = -150.0;
+    let y = -300.0;
+    let z = calculate_z(y - 20.0, y_max.value);
+    spawn_fixed_npc(
+        &asset_server,
+        &mut commands,
+        &mut layouts,
+        (PieSeller, CourtHouseFrontLevel),
+        "npc/woman_pink.png".to_string(),
+        MoveDirection::BottomIdle,
+        x,
+        y,
+        z,
+    );
+}
+
 fn spawn_guardians(
     asset_server: Res<AssetServer>,
     mut commands: Commands,
@@ -468,9 +610,14 @@ fn spawn_guardians(
         &asset_server,
         &mut commands,
         &mut layouts,
-        (GuardianFirstStage, GuardianSecondStage, GuardianThirdStage),
+        (
+            GuardianFirstStage,
+            GuardianSecondStage,
+            GuardianThirdStage,
+            CourtHouseFrontLevel,
+        ),
         "npc/guardian.png".to_string(),
-        MoveDirection::ForwardIdle,
+        MoveDirection::BottomIdle,
         -50.0,
         370.0,
         ON_WALL_OBJECT_Z + 1.5,
@@ -480,9 +627,14 @@ fn spawn_guardians(
         &asset_server,
         &mut commands,
         &mut layouts,
-        (GuardianFirstStage, GuardianSecondStage, GuardianThirdStage),
+        (
+            GuardianFirstStage,
+            GuardianSecondStage,
+            GuardianThirdStage,
+            CourtHouseFrontLevel,
+        ),
         "npc/guardian.png".to_string(),
-        MoveDirection::ForwardIdle,
+        MoveDirection::BottomIdle,
         50.0,
         370.0,
         ON_WALL_OBJECT_Z + 1.5,
@@ -506,9 +658,9 @@ fn spawn_gopniks(
         &asset_server,
         &mut commands,
         &mut layouts,
-        Gopnik,
+        (Gopnik, CourtHouseFrontLevel),
         "npc/gopnik_red.png".to_string(),
-        MoveDirection::ForwardIdle,
+        MoveDirection::BottomIdle,
         x,
         y,
         z,
@@ -522,9 +674,9 @@ fn spawn_gopniks(
         &asset_server,
         &mut commands,
         &mut layouts,
-        Gopnik,
+        (Gopnik, CourtHouseFrontLevel),
         "npc/gopnik_red.png".to_string(),
-        MoveDirection::ForwardIdle,
+        MoveDirection::BottomIdle,
         x,
         y,
         z,
@@ -538,9 +690,9 @@ fn spawn_gopniks(
         &asset_server,
         &mut commands,
         &mut layouts,
-        Gopnik,
+        (Gopnik, CourtHouseFrontLevel),
         "npc/gopnik_red.png".to_string(),
-        MoveDirection::ForwardIdle,
+        MoveDirection::BottomIdle,
         x,
         y,
         z,
@@ -554,9 +706,9 @@ fn spawn_gopniks(
         &asset_server,
         &mut commands,
         &mut layouts,
-        Gopnik,
+        (Gopnik, CourtHouseFrontLevel),
         "npc/gopnik_red.png".to_string(),
-        MoveDirection::ForwardIdle,
+        MoveDirection::BottomIdle,
         x,
         y,
         z,
@@ -580,138 +732,22 @@ fn spawn_blond_man(
         &asset_server,
         &mut commands,
         &mut layouts,
-        (BlondStart, BlondGiveDumplings, BlondTakeDumplings),
+        (
+            BlondStart,
+            BlondGiveDumplings,
+            BlondTakeDumplings,
+            CourtHouseFrontLevel,
+        ),
         "npc/clerk_blond.png".to_string(),
-        MoveDirection::ForwardIdle,
+        MoveDirection::BottomIdle,
         x,
         y,
         z,
     );
 }
 
-fn dialog_variants_handles(
-    mut dialog_variant_source: ResMut<SelectedVariantsSource>,
-    mut drevnira_state: ResMut<NextState<StrangeOldWoman>>,
-    mut blond_state: ResMut<NextState<BlondAndGopniks>>,
-    mut court_state: ResMut<NextState<Court>>,
-) {
-    // todo: it calculates on each frame.
-    // make it when dialog_variant_source updates only.
-    let selected = dialog_variant_source.consume(&DREVNIRA_DIALOG);
-    match selected {
-        None => {}
-        Some(ids) => {
-            for id in ids {
-                if id == END_DIALOG_DREVNIRA_BEATEN {
-                    drevnira_state.set(StrangeOldWoman::Beaten);
-                    court_state.set(Court::DrevniraStopped)
-                }
-            }
-        }
-    }
-
-    let selected = dialog_variant_source.consume(&BLOND_FIRST_DIALOG);
-    match selected {
-        None => {}
-        Some(ids) => {
-            for id in ids {
-                if id == END_DIALOG_BLOND_FIRST_ACCEPTED {
-                    blond_state.set(BlondAndGopniks::TalkWithGopniks);
-                } else {
-                    blond_state.set(BlondAndGopniks::Completed);
-                }
-            }
-        }
-    }
-
-    let selected = dialog_variant_source.consume(&GOPNIKS_DIALOG);
-    match selected {
-        None => {}
-        Some(ids) => {
-            for id in ids {
-                if id == END_DIALOG_GOPNIKS_DIALOG_ASK_BLOND {
-                    blond_state.set(BlondAndGopniks::TakeDumplingsFromBlond);
-                } else {
-                    blond_state.set(BlondAndGopniks::GiveDumplingsToBlond);
-                }
-            }
-        }
-    }
-
-    let selected = dialog_variant_source.consume(&BLOND_GIVE_DUMPLINGS_DIALOG);
-    match selected {
-        None => {}
-        Some(ids) => {
-            for id in ids {
-                if id == END_DIALOG_BLOND_GIVE_DUMPLINGS_COMPLETED {
-                    blond_state.set(BlondAndGopniks::Completed);
-                }
-            }
-        }
-    }
-
-    let selected = dialog_variant_source.consume(&BLOND_TAKE_DUMPLINGS_DIALOG);
-    match selected {
-        None => {}
-        Some(ids) => {
-            for id in ids {
-                if id == END_DIALOG_BLOND_TAKE_DUMPLINGS_JUST_COMPLETED {
-                    blond_state.set(BlondAndGopniks::Completed);
-                }
-                if id == END_DIALOG_BLOND_TAKE_DUMPLINGS_NECK_TWISTED {
-                    blond_state.set(BlondAndGopniks::Completed);
-                }
-            }
-        }
-    }
-
-    let selected = dialog_variant_source.consume(&GUARDIAN_FIRST_DIALOG);
-    match selected {
-        None => {}
-        Some(ids) => {
-            for id in ids {
-                if id == END_DIALOG_GUARDIAN_FIRST_JUST_COMPLETED {
-                    court_state.set(Court::Completed)
-                }
-                if id == END_DIALOG_GUARDIAN_FIRST_BEATEN {
-                    court_state.set(Court::Completed)
-                }
-                if id == END_DIALOG_GUARDIAN_FIRST_DREVNIRA_STOP_ACCEPTED {
-                    court_state.set(Court::StopDrevnira)
-                }
-            }
-        }
-    }
-
-    let selected = dialog_variant_source.consume(&GUARDIAN_SECOND_DIALOG);
-    match selected {
-        None => {}
-        Some(ids) => {
-            for id in ids {
-                if id == END_DIALOG_GUARDIAN_SECOND_COMPLETED {
-                    court_state.set(Court::Completed)
-                }
-                if id == END_DIALOG_GUARDIAN_SECOND_BEATEN {
-                    court_state.set(Court::Completed)
-                }
-            }
-        }
-    }
-
-    let selected = dialog_variant_source.consume(&GUARDIAN_THIRD_DIALOG);
-    match selected {
-        None => {}
-        Some(ids) => {
-            for id in ids {
-                if id == END_DIALOG_GUARDIAN_THIRD_COMPLETED {
-                    court_state.set(Court::Completed)
-                }
-                if id == END_DIALOG_GUARDIAN_THIRD_BEATEN {
-                    court_state.set(Court::Completed)
-                }
-            }
-        }
+fn unload(mut commands: Commands, query: Query<Entity, With<CourtHouseFrontLevel>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
     }
 }
-
-fn unload() {}
@@ -1,7 +1,9 @@
 use bevy::app::{Plugin, Update};
 use bevy::asset::{AssetServer, Assets};
+use bevy::hierarchy::DespawnRecursiveExt;
 use bevy::math::Vec3;
 use bevy::prelude::in_state;
+use bevy::prelude::Entity;
 use bevy::prelude::IntoSystemConfigs;
 use bevy::prelude::OnEnter;
 use bevy::prelude::OnExit;
@@ -12,30 +14,39 @@ use bevy::prelude::SpriteBundle;
 use bevy::prelude::States;
 use bevy::prelude::TextureAtlasLayout;
 use bevy::prelude::Transform;
-use bevy::prelude::{Commands, Component, NextState};
+use bevy::prelude::TransformBundle;
+use bevy::prelude::With;
+use bevy::prelude::{Commands, Component};
 use bevy_rapier2d::dynamics::RigidBody;
 
-use crate::animation::entities::MoveDirection;
 use crate::core::collisions::recalculate_z;
 use crate::core::entities::LevelYMax;
-use crate::core::z_index::{calculate_z, FLOOR_Z, ON_WALL_OBJECT_Z, WALL_Z};
-use crate::dialog::SelectedVariantsSource;
+use crate::core::geometry::Point2D;
+use crate::core::z_index::{calculate_z, DEFAULT_OBJECT_Z, FLOOR_Z, ON_WALL_OBJECT_Z, WALL_Z};
+use crate::interaction::interactors::{InteractionArea, InteractionSide, PassiveInteractor};
 use crate::level::dialog_starts;
 use crate::level::objects::spawn_object;
+use crate::level::registry::LevelRegistry;
+use crate::level::states::Level;
+use crate::level::transitions::{level_exit_conditions, LevelExit, LevelExitCondition, SpawnPoint};
 use crate::level::HasDialogId;
 use crate::level::CRAZY_MAN_DIALOG;
-use crate::level::CRAZY_MAN_DIALOG_BEATEN;
-use crate::level::CRAZY_MAN_DIALOG_COMPLETED;
 use crate::level::HALL_GUARDIAN_FIRST_DIALOG;
-use crate::level::HALL_GUARDIAN_FIRST_DIALOG_BEATEN;
-use crate::level::HALL_GUARDIAN_SECOND_COMPLETED;
 use crate::level::HALL_GUARDIAN_SECOND_DIALOG;
 use crate::level::TABLE_1_DIALOG;
 use crate::level::TABLE_2_DIALOG;
 use crate::level::TABLE_3_DIALOG;
-use crate::npc::spawn_fixed_npc;
+use crate::movement::entities::MoveArea;
+use crate::movement::entities::MoveDirection;
+use crate::npc::{
+    npc_behaviour_triggers, spawn_fixed_npc, spawn_moving_npc, NpcBehaviour, NpcBehaviourTrigger,
+    NpcMovement,
+};
 use crate::world_state::GoIntoCourt;
 
+#[derive(Component)]
+struct CourtHouseHallLevel;
+
 #[derive(Component)]
 struct GuardianFirstStage;
 
@@ -129,7 +140,12 @@ impl<S: States> Plugin for CourtHouseHallPlugin<S> {
             )
             .add_systems(
                 Update,
-                (dialog_variants_handles, recalculate_z).run_if(in_state(self.state.clone())),
+                (
+                    recalculate_z,
+                    level_exit_conditions::<GoIntoCourt>,
+                    npc_behaviour_triggers::<GoIntoCourt>,
+                )
+                    .run_if(in_state(self.state.clone())),
             );
     }
 }
@@ -137,13 +153,13 @@ impl<S: States> Plugin for CourtHouseHallPlugin<S> {
 fn load(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    mut go_into_court_state: ResMut<NextState<GoIntoCourt>>,
+    level_registry: Res<LevelRegistry>,
 ) {
-    go_into_court_state.set(GoIntoCourt::Wait);
-    let y_max = LevelYMax::create(359.0);
-    commands.spawn(y_max);
+    let y_max = LevelYMax::create(level_registry.y_max(&Level::CourtHouseHall));
+    commands.spawn((y_max, CourtHouseHallLevel));
 
     spawn_floor(&mut commands, &asset_server);
+    spawn_entrance(&mut commands);
 
     let wall_top = asset_server.load("courthouse_hall/wall_top.png");
     spawn_object(
@@ -155,7 +171,8 @@ fn load(
         256.0,
         30.0,
         0.0,
-    );
+    )
+    .insert(CourtHouseHallLevel);
 
     spawn_doors(&mut commands, &asset_server, 0.0, 351.0);
 
@@ -169,7 +186,8 @@ fn load(
         17.0,
         359.0,
         0.0,
-    );
+    )
+    .insert(CourtHouseHallLevel);
 
     let wall_right = asset_server.load("courthouse_hall/wall_right.png");
     spawn_object(
@@ -181,62 +199,63 @@ fn load(
         17.0,
         359.0,
         0.0,
-    );
+    )
+    .insert(CourtHouseHallLevel);
 
     // -------------------------------------------------------------------
     let flower = asset_server.load("courthouse_hall/flower.png");
     let x = -190.0;
     let y = 330.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 10.0, 20.0, -5.0);
+    spawn_object(&mut commands, flower, x, y, z, 10.0, 20.0, -5.0).insert(CourtHouseHallLevel);
 
     let sofa = asset_server.load("courthouse_hall/sofa_violet.png");
     let x = -190.0;
     let y = 260.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, sofa, x, y, z, 17.0, 43.0, -5.0);
+    spawn_object(&mut commands, sofa, x, y, z, 17.0, 43.0, -5.0).insert(CourtHouseHallLevel);
 
     let flower = asset_server.load("courthouse_hall/flower.png");
     let x = -190.0;
     let y = 195.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 10.0, 20.0, -5.0);
+    spawn_object(&mut commands, flower, x, y, z, 10.0, 20.0, -5.0).insert(CourtHouseHallLevel);
 
     let sofa = asset_server.load("courthouse_hall/sofa_blue.png");
     let x = -190.0;
     let y = 125.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, sofa, x, y, z, 17.0, 43.0, -5.0);
+    spawn_object(&mut commands, sofa, x, y, z, 17.0, 43.0, -5.0).insert(CourtHouseHallLevel);
 
     let flower = asset_server.load("courthouse_hall/flower.png");
     let x = -190.0;
     let y = 60.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 10.0, 20.0, -5.0);
+    spawn_object(&mut commands, flower, x, y, z, 10.0, 20.0, -5.0).insert(CourtHouseHallLevel);
 
     let sofa = asset_server.load("courthouse_hall/sofa_violet.png");
     let x = -190.0;
     let y = -10.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, sofa, x, y, z, 17.0, 43.0, -5.0);
+    spawn_object(&mut commands, sofa, x, y, z, 17.0, 43.0, -5.0).insert(CourtHouseHallLevel);
 
     let flower = asset_server.load("courthouse_hall/flower.png");
     let x = -190.0;
     let y = -75.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 10.0, 20.0, -5.0);
+    spawn_object(&mut commands, flower, x, y, z, 10.0, 20.0, -5.0).insert(CourtHouseHallLevel);
 
     let sofa = asset_server.load("courthouse_hall/sofa_blue.png");
     let x = -190.0;
     let y = -145.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, sofa, x, y, z, 17.0, 43.0, -5.0);
+    spawn_object(&mut commands, sofa, x, y, z, 17.0, 43.0, -5.0).insert(CourtHouseHallLevel);
 
     let flower = asset_server.load("courthouse_hall/flower.png");
     let x = -190.0;
     let y = -210.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 10.0, 20.0, -5.0);
+    spawn_object(&mut commands, flower, x, y, z, 10.0, 20.0, -5.0).insert(CourtHouseHallLevel);
     // -------------------------------------------------------------------
 
     // -------------------------------------------------------------------
@@ -244,42 +263,82 @@ fn load(
     let x = 160.0;
     let y = 190.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 15.0, 37.0, -5.0);
+    spawn_object(&mut commands, flower, x, y, z, 15.0, 37.0, -5.0).insert(CourtHouseHallLevel);
 
     let flower = asset_server.load("courthouse_hall/work_place.png");
     let x = 160.0;
     let y = 80.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 15.0, 37.0, -5.0);
+    spawn_object(&mut commands, flower, x, y, z, 15.0, 37.0, -5.0).insert(CourtHouseHallLevel);
 
     let flower = asset_server.load("courthouse_hall/work_place.png");
     let x = 160.0;
     let y = -30.0;
     let z = calculate_z(y, y_max.value);
-    spawn_object(&mut commands, flower, x, y, z, 15.0, 37.0, -5.0);
+    spawn_object(&mut commands, flower, x, y, z, 15.0, 37.0, -5.0).insert(CourtHouseHallLevel);
     // -------------------------------------------------------------------
 }
 
 fn spawn_floor(commands: &mut Commands, asset_server: &Res<AssetServer>) {
-    commands.spawn(RigidBody::Fixed).insert(SpriteBundle {
-        texture: asset_server.load("courthouse_hall/floor.png"),
-        transform: Transform {
-            translation: Vec3::new(0.0, 0.0, FLOOR_Z),
+    commands.spawn(RigidBody::Fixed).insert((
+        SpriteBundle {
+            texture: asset_server.load("courthouse_hall/floor.png"),
+            transform: Transform {
+                translation: Vec3::new(0.0, 0.0, FLOOR_Z),
+                ..Default::default()
+            },
             ..Default::default()
         },
-        ..Default::default()
-    });
+        CourtHouseHallLevel,
+    ));
+}
+
+fn spawn_entrance(commands: &mut Commands) {
+    commands
+        .spawn((
+            TransformBundle::from(Transform::from_xyz(0.0, -329.0, FLOOR_Z)),
+            CourtHouseHallLevel,
+        ))
+        .insert(PassiveInteractor {
+            area: InteractionArea::from_sizes(60.0, 10.0),
+            side: InteractionSide::Bottom,
+        })
+        .insert(LevelExit::create(Level::CourtHouseFront, "court_doors"));
+
+    commands.spawn((
+        SpawnPoint::create("entrance"),
+        TransformBundle::from(Transform::from_xyz(0.0, -290.0, DEFAULT_OBJECT_Z)),
+        CourtHouseHallLevel,
+    ));
+    commands.spawn((
+        SpawnPoint::create("court_doors"),
+        TransformBundle::from(Transform::from_xyz(0.0, 300.0, DEFAULT_OBJECT_Z)),
+        CourtHouseHallLevel,
+    ));
 }
 
 fn spawn_doors(commands: &mut Commands, asset_server: &Res<AssetServer>, x: f32, y: f32) {
-    commands.spawn(RigidBody::Fixed).insert(SpriteBundle {
-        texture: asset_server.load("courthouse_hall/doors.png"),
-        transform: Transform {
-            translation: Vec3::new(x, y, ON_WALL_OBJECT_Z),
-            ..Default::default()
-        },
-        ..Default::default()
-    });
+    commands
+        .spawn(RigidBody::Fixed)
+        .insert((
+            SpriteBundle {
+                texture: asset_server.load("courthouse_hall/doors.png"),
+                transform: Transform {
+                    translation: Vec3::new(x, y, ON_WALL_OBJECT_Z),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            CourtHouseHallLevel,
+        ))
+        .insert(PassiveInteractor {
+            area: InteractionArea::from_sizes(25.0, 22.0),
+            side: InteractionSide::Bottom,
+        })
+        .insert(LevelExit::create(Level::Court, "entrance"))
+        .insert(LevelExitCondition {
+            state: GoIntoCourt::Go,
+        });
 }
 
 fn spawn_guardians(
@@ -291,9 +350,9 @@ fn spawn_guardians(
         &asset_server,
         &mut commands,
         &mut layouts,
-        (GuardianFirstStage, GuardianSecondStage),
+        (GuardianFirstStage, GuardianSecondStage, CourtHouseHallLevel),
         "npc/guardian.png".to_string(),
-        MoveDirection::ForwardIdle,
+        MoveDirection::BottomIdle,
         -50.0,
         350.0,
         ON_WALL_OBJECT_Z + 1.5,
@@ -303,9 +362,9 @@ fn spawn_guardians(
         &asset_server,
         &mut commands,
         &mut layouts,
-        (GuardianFirstStage, GuardianSecondStage),
+        (GuardianFirstStage, GuardianSecondStage, CourtHouseHallLevel),
         "npc/guardian.png".to_string(),
-        MoveDirection::ForwardIdle,
+        MoveDirection::BottomIdle,
         50.0,
         350.0,
         ON_WALL_OBJECT_Z + 1.5,
@@ -329,7 +388,7 @@ fn spawn_working_clerks(
         &asset_server,
         &mut commands,
         &mut layouts,
-        Clerc3,
+        (Clerc3, CourtHouseHallLevel),
         "npc/clerk.png".to_string(),
         MoveDirection::LeftIdle,
         x,
@@ -344,7 +403,7 @@ fn spawn_working_clerks(
         &asset_server,
         &mut commands,
         &mut layouts,
-        Clerc2,
+        (Clerc2, CourtHouseHallLevel),
         "npc/clerk_blond.png".to_string(),
         MoveDirection::LeftIdle,
         x,
@@ -359,7 +418,7 @@ fn spawn_working_clerks(
         &asset_server,
         &mut commands,
         &mut layouts,
-        Clerc1,
+        (Clerc1, CourtHouseHallLevel),
         "npc/clerk.png".to_string(),
         MoveDirection::LeftIdle,
         x,
@@ -385,7 +444,7 @@ fn spawn_speaking_clerks(
         &asset_server,
         &mut commands,
         &mut layouts,
-        (),
+        CourtHouseHallLevel,
         "npc/clerk.png".to_string(),
         MoveDirection::RightIdle,
         x,
@@ -398,7 +457,7 @@ fn spawn_speaking_clerks(
         &asset_server,
         &mut commands,
         &mut layouts,
-        (),
+        CourtHouseHallLevel,
         "npc/clerk_blond.png".to_string(),
         MoveDirection::LeftIdle,
         x,
@@ -420,29 +479,50 @@ fn spawn_visitors(
     let y = 190.0;
     let shifted_y = y - 20.0;
     let z = calculate_z(shifted_y, y_max.value);
-    spawn_fixed_npc(
+    spawn_moving_npc(
         &asset_server,
         &mut commands,
         &mut layouts,
-        (),
+        CourtHouseHallLevel,
         "npc/woman_pink.png".to_string(),
-        MoveDirection::ForwardIdle,
+        NpcMovement {
+            behaviour: NpcBehaviour::wander(
+                MoveArea {
+                    half_width: 50,
+                    half_height: 60,
+                    center_position: Point2D::new(-80, 180),
+                },
+                3.0,
+            ),
+            priority: 1,
+        },
         x,
         y,
         z,
     );
 
-    let x = -160.0;
+    let x = -150.0;
     let y = 150.0;
     let shifted_y = y - 20.0;
     let z = calculate_z(shifted_y, y_max.value);
-    spawn_fixed_npc(
+    spawn_moving_npc(
         &asset_server,
         &mut commands,
         &mut layouts,
-        (),
+        CourtHouseHallLevel,
         "npc/clerk.png".to_string(),
-        MoveDirection::RightIdle,
+        NpcMovement {
+            behaviour: NpcBehaviour::patrol(
+                vec![
+                    Point2D::new(-150, 150),
+                    Point2D::new(-40, 150),
+                    Point2D::new(-40, -100),
+                    Point2D::new(-150, -100),
+                ],
+                2.0,
+            ),
+            priority: 2,
+        },
         x,
         y,
         z,
@@ -456,9 +536,9 @@ fn spawn_visitors(
         &asset_server,
         &mut commands,
         &mut layouts,
-        (),
+        CourtHouseHallLevel,
         "npc/clerk.png".to_string(),
-        MoveDirection::ForwardIdle,
+        MoveDirection::BottomIdle,
         x,
         y,
         z,
@@ -468,29 +548,49 @@ fn spawn_visitors(
     let y = 10.0;
     let shifted_y = y - 20.0;
     let z = calculate_z(shifted_y, y_max.value);
-    spawn_fixed_npc(
+    spawn_moving_npc(
         &asset_server,
         &mut commands,
         &mut layouts,
-        (),
+        CourtHouseHallLevel,
         "npc/woman_green.png".to_string(),
-        MoveDirection::ForwardIdle,
+        NpcMovement {
+            behaviour: NpcBehaviour::wander(
+                MoveArea {
+                    half_width: 50,
+                    half_height: 60,
+                    center_position: Point2D::new(-60, -20),
+                },
+                4.0,
+            ),
+            priority: 1,
+        },
         x,
         y,
         z,
     );
 
+    // Goes to the court doors as soon as the court is open
     let x = -150.0;
     let y = -150.0;
     let shifted_y = y - 20.0;
     let z = calculate_z(shifted_y, y_max.value);
-    spawn_fixed_npc(
+    spawn_moving_npc(
         &asset_server,
         &mut commands,
         &mut layouts,
-        (),
+        (
+            CourtHouseHallLevel,
+            NpcBehaviourTrigger {
+                state: GoIntoCourt::CanGo,
+                behaviour: NpcBehaviour::go_to(Point2D::new(-60, 280), MoveDirection::TopIdle),
+            },
+        ),
         "npc/clerk.png".to_string(),
-        MoveDirection::RightIdle,
+        NpcMovement {
+            behaviour: NpcBehaviour::Idle,
+            priority: 1,
+        },
         x,
         y,
         z,
@@ -514,60 +614,17 @@ fn spawn_crazy_man(
         &asset_server,
         &mut commands,
         &mut layouts,
-        CrazyMan,
+        (CrazyMan, CourtHouseHallLevel),
         "npc/crazy_man.png".to_string(),
-        MoveDirection::ForwardIdle,
+        MoveDirection::BottomIdle,
         x,
         y,
         z,
     );
 }
 
-fn dialog_variants_handles(
-    mut dialog_variant_source: ResMut<SelectedVariantsSource>,
-    mut go_into_court_state: ResMut<NextState<GoIntoCourt>>,
-) {
-    // todo: it calculates on each frame.
-    // make it when dialog_variant_source updates only.
-    let selected = dialog_variant_source.consume(&HALL_GUARDIAN_FIRST_DIALOG);
-    match selected {
-        None => {}
-        Some(ids) => {
-            for id in ids {
-                if id == HALL_GUARDIAN_FIRST_DIALOG_BEATEN {
-                    go_into_court_state.set(GoIntoCourt::Go);
-                }
-            }
-        }
-    }
-
-    let selected = dialog_variant_source.consume(&HALL_GUARDIAN_SECOND_DIALOG);
-    match selected {
-        None => {}
-        Some(ids) => {
-            for id in ids {
-                if id == HALL_GUARDIAN_SECOND_COMPLETED {
-                    go_into_court_state.set(GoIntoCourt::Go);
-                }
-            }
-        }
-    }
-
-    let selected = dialog_variant_source.consume(&CRAZY_MAN_DIALOG);
-    match selected {
-        None => {}
-        Some(ids) => {
-            for id in ids {
-                if id == CRAZY_MAN_DIALOG_COMPLETED {
-                    go_into_court_state.set(GoIntoCourt::CanGo);
-                }
-
-                if id == CRAZY_MAN_DIALOG_BEATEN {
-                    go_into_court_state.set(GoIntoCourt::CanGo);
-                }
-            }
-        }
+fn unload(mut commands: Commands, query: Query<Entity, With<CourtHouseHallLevel>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
     }
 }
-
-fn unload() {}
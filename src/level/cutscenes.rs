@@ -0,0 +1,204 @@
+use bevy::log::warn;
+use bevy::math::Vec2;
+use serde::Deserialize;
+
+use crate::cutscene::{Cutscene, CutsceneStep};
+use crate::dialog::{DialogCondition, DialogValue, Replica, Speaker};
+use crate::world_state::QuestEvent;
+
+pub const JUDGES_ACTOR: &str = "judges";
+
+// Judges leave for deliberation and come back with the verdict,
+// it depends on whether Formidable Face has admitted guilt during the trial
+pub const TRIAL_VERDICT_CUTSCENE: usize = 1;
+
+pub const TRIAL_VERDICT_WON: usize = 1;
+pub const TRIAL_VERDICT_FAILED: usize = 2;
+
+// Animation frame time divides the elapsed one, so it can not be zero or negative
+const MIN_FRAME_SECONDS: f32 = 0.01;
+
+#[derive(Deserialize)]
+struct CutsceneData {
+    id: usize,
+    steps: Vec<TimelineStepData>,
+}
+
+#[derive(Deserialize)]
+struct TimelineStepData {
+    #[serde(flatten)]
+    step: StepData,
+    #[serde(rename = "if")]
+    condition: Option<ConditionData>,
+}
+
+/// Same as CutsceneStep, end is the id of CutsceneEnded event sent to quests.
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum StepData {
+    MoveNpc {
+        actor: String,
+        to: [f32; 2],
+        speed: f32,
+    },
+    Animation {
+        actor: String,
+        first: usize,
+        last: usize,
+        frame_seconds: f32,
+    },
+    Replica(ReplicaData),
+    PanCamera {
+        to: [f32; 2],
+        seconds: f32,
+    },
+    Wait(f32),
+    Fade {
+        alpha: f32,
+        seconds: f32,
+    },
+    Sound(String),
+    End(usize),
+}
+
+/// Key is a stable part of the string table key, text is the original one.
+#[derive(Deserialize)]
+struct ReplicaData {
+    key: String,
+    text: String,
+    speaker: Option<Speaker>,
+    sound: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum ConditionData {
+    Flag(String),
+    NotFlag(String),
+}
+
+pub fn cutscenes() -> Vec<Cutscene> {
+    let data: Vec<CutsceneData> =
+        match serde_json::from_str(include_str!("../../assets/cutscenes/cutscenes.json")) {
+            Ok(data) => data,
+            Err(error) => {
+                warn!("Could not parse cutscenes: {error}");
+                vec![]
+            }
+        };
+    data.into_iter().map(cutscene_from_data).collect()
+}
+
+fn cutscene_from_data(data: CutsceneData) -> Cutscene {
+    let cutscene_id = data.id;
+    data.steps
+        .into_iter()
+        .fold(Cutscene::new(cutscene_id), |cutscene, timeline_step| {
+            let step = step_from_data(cutscene_id, timeline_step.step);
+            match timeline_step.condition {
+                None => cutscene.step(step),
+                Some(condition) => cutscene.step_if(condition_from_data(condition), step),
+            }
+        })
+}
+
+fn step_from_data(cutscene_id: usize, data: StepData) -> CutsceneStep {
+    match data {
+        StepData::MoveNpc { actor, to, speed } => CutsceneStep::MoveNpc {
+            actor,
+            to: Vec2::from(to),
+            speed,
+        },
+        StepData::Animation {
+            actor,
+            first,
+            last,
+            frame_seconds,
+        } => {
+            if frame_seconds < MIN_FRAME_SECONDS {
+                warn!("Cutscene {cutscene_id} animates {actor} with frame_seconds {frame_seconds}");
+            }
+            CutsceneStep::Animation {
+                actor,
+                first,
+                last,
+                frame_seconds: frame_seconds.max(MIN_FRAME_SECONDS),
+            }
+        }
+        StepData::Replica(data) => {
+            let mut replica = Replica::from_text(data.text);
+            replica.speaker = data.speaker;
+            replica.sound = data.sound;
+            CutsceneStep::Replica {
+                key: data.key,
+                replica,
+            }
+        }
+        StepData::PanCamera { to, seconds } => CutsceneStep::PanCamera {
+            to: Vec2::from(to),
+            seconds,
+        },
+        StepData::Wait(seconds) => CutsceneStep::Wait(seconds),
+        StepData::Fade { alpha, seconds } => CutsceneStep::Fade { alpha, seconds },
+        StepData::Sound(path) => CutsceneStep::Sound(path),
+        StepData::End(end_id) => CutsceneStep::SetQuestState(QuestEvent::CutsceneEnded {
+            cutscene_id,
+            end_id,
+        }),
+    }
+}
+
+fn condition_from_data(data: ConditionData) -> DialogCondition {
+    match data {
+        ConditionData::Flag(name) => DialogCondition::Equals(name, DialogValue::Flag(true)),
+        ConditionData::NotFlag(name) => DialogCondition::Not(Box::new(DialogCondition::Equals(
+            name,
+            DialogValue::Flag(true),
+        ))),
+    }
+}
+
+#[test]
+fn cutscenes_data_test() {
+    use crate::level::TRIAL_GUILT_ADMITTED_VARIABLE;
+
+    let cutscenes = cutscenes();
+    let verdict = cutscenes
+        .iter()
+        .find(|cutscene| cutscene.id == TRIAL_VERDICT_CUTSCENE)
+        .unwrap();
+    assert_eq!(verdict.steps.len(), 16);
+    assert!(matches!(
+        &verdict.steps[0].step,
+        CutsceneStep::Replica { key, replica }
+            if key == "deliberation" && replica.speaker == Some(Speaker::ChiefJudge)
+    ));
+    let guilt_admitted = DialogCondition::Equals(
+        TRIAL_GUILT_ADMITTED_VARIABLE.to_string(),
+        DialogValue::Flag(true),
+    );
+    let failed = &verdict.steps[13];
+    assert_eq!(failed.condition, Some(guilt_admitted));
+    assert!(matches!(
+        failed.step,
+        CutsceneStep::SetQuestState(QuestEvent::CutsceneEnded {
+            cutscene_id: TRIAL_VERDICT_CUTSCENE,
+            end_id: TRIAL_VERDICT_FAILED,
+        })
+    ));
+}
+
+#[test]
+fn cutscenes_frame_seconds_test() {
+    let data: CutsceneData = serde_json::from_str(
+        r#"{ "id": 7, "steps": [
+            { "animation": { "actor": "judges", "first": 0, "last": 3, "frame_seconds": 0.0 } }
+        ] }"#,
+    )
+    .unwrap();
+    let cutscene = cutscene_from_data(data);
+    assert!(matches!(
+        cutscene.steps[0].step,
+        CutsceneStep::Animation { frame_seconds, .. } if frame_seconds == MIN_FRAME_SECONDS
+    ));
+}
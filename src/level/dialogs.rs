@@ -1,12 +1,17 @@
 use crate::core::states::GameState;
 use bevy::input::ButtonInput;
-use bevy::prelude::{Commands, Component, KeyCode, NextState, Query, Res, ResMut, Transform};
+use bevy::prelude::{Commands, Component, NextState, Query, Res, ResMut, Transform};
 use std::collections::HashMap;
 
-use crate::dialog::{Branching, Dialog, DialogEffect, DialogId, DialogStick, Replica, Variant};
+use crate::dialog::{
+    end_names, Branching, Dialog, DialogAction, DialogCondition, DialogEffect, DialogId,
+    DialogStick, DialogValue, Replica, SkillCheck, Speaker, Variant,
+};
+use crate::input::InputAction;
 use crate::interaction::interactors::{
     detect_active_interaction, ActiveInteractor, PassiveInteractor,
 };
+use crate::rpg::Characteristic;
 
 pub trait HasDialogId {
     fn dialog_id(&self) -> usize;
@@ -14,13 +19,13 @@ pub trait HasDialogId {
 
 pub fn dialog_starts<T: HasDialogId + Component>(
     mut commands: Commands,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    actions: Res<ButtonInput<InputAction>>,
     active: Query<(&ActiveInteractor, &Transform)>,
     interactors: Query<(&PassiveInteractor, &Transform, &T)>,
-    mut dialog_id_query: Query<(&mut DialogId)>,
+    mut dialog_id_query: Query<&mut DialogId>,
     mut next_game_state: ResMut<NextState<GameState>>,
 ) {
-    if !(keyboard.pressed(KeyCode::KeyE) && keyboard.just_pressed(KeyCode::KeyE)) {
+    if !(actions.pressed(InputAction::Interact) && actions.just_pressed(InputAction::Interact)) {
         return;
     }
     for (interactor, transform, has_dialog) in interactors.iter() {
@@ -68,6 +73,10 @@ pub fn sleeping_formidable_dog_dialog() -> Dialog {
         root_id,
         sticks,
     )
+    .with_end_names(end_names![
+        END_DIALOG_FORMIDABLE_DOG_JOINED,
+        END_DIALOG_FORMIDABLE_DOG_CONTINUED_SLEEP
+    ])
 }
 
 fn sleeping_formidable_dog() -> (usize, HashMap<usize, DialogStick>) {
@@ -94,7 +103,6 @@ fn sleeping_formidable_dog() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     go_with_me_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![Variant::create_with_effect(
             "Ыыыу, подозреваю, что делё опять в доме.".to_string(),
             joined_stick.id,
@@ -110,7 +118,6 @@ fn sleeping_formidable_dog() -> (usize, HashMap<usize, DialogStick>) {
 
     after_wake_up_stick.branching = Some(
         Branching {
-            id: 0,
             variants: vec![
                 Variant::create_with_effect(
                     "Пойдем сё мной. Мне дали повестьку в судь.".to_string(),
@@ -131,7 +138,6 @@ fn sleeping_formidable_dog() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     main_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![Variant::create_with_effect(
             "Подъемь!".to_string(),
             after_wake_up_stick.id,
@@ -188,6 +194,7 @@ pub fn courier_dialog() -> Dialog {
         root_id,
         sticks,
     )
+    .with_end_names(end_names![END_DIALOG_NECK_TWISTED, END_DIALOG_AGENDA_TAKEN])
 }
 
 fn courier_dialog_body() -> (usize, HashMap<usize, DialogStick>) {
@@ -231,7 +238,6 @@ fn courier_dialog_body() -> (usize, HashMap<usize, DialogStick>) {
         "Что вы... Грозный, ввваааааа! Эу...".to_string(),
     ));
     why_question_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "[Промолчать]".to_string(),
@@ -269,7 +275,6 @@ fn courier_dialog_body() -> (usize, HashMap<usize, DialogStick>) {
         .replicas
         .push(Replica::from_text("Да что вы...! Эуу....!".to_string()));
     main_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create(
                 "Ыыыу, ето ошибка! Мне не должно ничего приходить!".to_string(),
@@ -332,6 +337,7 @@ fn courier_dialog_body() -> (usize, HashMap<usize, DialogStick>) {
 pub const DREVNIRA_DIALOG: usize = 3;
 
 pub const END_DIALOG_DREVNIRA_BEATEN: usize = 1;
+pub const END_DIALOG_DREVNIRA_FEATHER_GIVEN: usize = 2;
 
 pub fn old_woman_drevnira_dialog() -> Dialog {
     let (root_id, sticks) = old_woman_drevnira();
@@ -343,6 +349,10 @@ pub fn old_woman_drevnira_dialog() -> Dialog {
         root_id,
         sticks,
     )
+    .with_end_names(end_names![
+        END_DIALOG_DREVNIRA_BEATEN,
+        END_DIALOG_DREVNIRA_FEATHER_GIVEN
+    ])
 }
 
 fn old_woman_drevnira() -> (usize, HashMap<usize, DialogStick>) {
@@ -378,8 +388,14 @@ fn old_woman_drevnira() -> (usize, HashMap<usize, DialogStick>) {
             .to_string(),
     )]);
 
+    let mut feather_given = DialogStick::from(6);
+    feather_given.replicas.extend(vec![Replica::from_text(
+        "[Старушка выхватила перо, прижала его к груди и, не оборачиваясь, \
+            засеменила прочь от окна.] Пьерро... Пьерро..."
+            .to_string(),
+    )]);
+
     i_have.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "[Долбануть бабку головой об окно...]".to_string(),
@@ -391,6 +407,15 @@ fn old_woman_drevnira() -> (usize, HashMap<usize, DialogStick>) {
                 skip.id,
                 DialogEffect::EndDialog(None),
             ),
+            Variant::create_with_effect(
+                "[Отдать перо]".to_string(),
+                feather_given.id,
+                DialogEffect::EndDialog(Some(END_DIALOG_DREVNIRA_FEATHER_GIVEN)),
+            )
+            .with_condition(DialogCondition::Equals(
+                "quest.StrangeOldWoman".to_string(),
+                DialogValue::Text("FeatherFound".to_string()),
+            )),
         ],
     });
 
@@ -403,7 +428,6 @@ fn old_woman_drevnira() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     go_away.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "ЫЫЫЫУУУУ!!!! [Долбануть бабку головой об окно...]".to_string(),
@@ -419,7 +443,6 @@ fn old_woman_drevnira() -> (usize, HashMap<usize, DialogStick>) {
     });
 
     again_talking.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "У меня есть для тебя перо!".to_string(),
@@ -440,7 +463,6 @@ fn old_woman_drevnira() -> (usize, HashMap<usize, DialogStick>) {
     });
 
     main_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "Эй, ти!".to_string(),
@@ -463,6 +485,7 @@ fn old_woman_drevnira() -> (usize, HashMap<usize, DialogStick>) {
     pool.insert(beaten.id, beaten);
     pool.insert(i_have.id, i_have);
     pool.insert(go_away.id, go_away);
+    pool.insert(feather_given.id, feather_given);
 
     (root_id, pool)
 }
@@ -495,6 +518,10 @@ pub fn blond_first_dialog() -> Dialog {
         root_id,
         sticks,
     )
+    .with_end_names(end_names![
+        END_DIALOG_BLOND_FIRST_ACCEPTED,
+        END_DIALOG_BLOND_FIRST_DENIED
+    ])
 }
 
 fn blond_first() -> (usize, HashMap<usize, DialogStick>) {
@@ -542,7 +569,6 @@ fn blond_first() -> (usize, HashMap<usize, DialogStick>) {
         .extend(vec![Replica::from_text("Ну сударъ... Эх.".to_string())]);
 
     main_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create("Ктё тебя изьбиль и сколько их былё?".to_string(), who.id),
             Variant::create("Где тебя изьбили?".to_string(), wheree.id),
@@ -602,6 +628,12 @@ pub const GOPNIKS_DIALOG: usize = 5;
 
 pub const END_DIALOG_GOPNIKS_DIALOG_FIGHT: usize = 1;
 pub const END_DIALOG_GOPNIKS_DIALOG_ASK_BLOND: usize = 2;
+pub const END_DIALOG_GOPNIKS_DIALOG_INTIMIDATED: usize = 3;
+
+pub const GOPNIKS_INTIMIDATE_CHECK: &str = "gopniks_intimidate";
+
+// Gopniks told that blond still has two packs of dumplings
+pub const BLOND_HIDES_DUMPLINGS_VARIABLE: &str = "blond_hides_dumplings";
 
 pub fn gopniks_dialog() -> Dialog {
     let (root_id, sticks) = gopniks();
@@ -613,6 +645,11 @@ pub fn gopniks_dialog() -> Dialog {
         root_id,
         sticks,
     )
+    .with_end_names(end_names![
+        END_DIALOG_GOPNIKS_DIALOG_FIGHT,
+        END_DIALOG_GOPNIKS_DIALOG_ASK_BLOND,
+        END_DIALOG_GOPNIKS_DIALOG_INTIMIDATED
+    ])
 }
 
 fn gopniks() -> (usize, HashMap<usize, DialogStick>) {
@@ -622,29 +659,32 @@ fn gopniks() -> (usize, HashMap<usize, DialogStick>) {
             Replica::from_text("[Перед тобой четверо... индивидумов. Один из них сидит на коточках, у него хмурое лицо и сморщеный лоб.\n\
             Слева от него стоит громадина в красных лосинах. Поодаль ты видишь двух близнецов с глупым выраженим на лицах.\n\
             Тот, что сидит на корточках, судя по всему, главарь, зло смотрит на тебя из подлобья.]".to_string()),
-            Replica::from_text("Херли тебе надо, а?".to_string()),
+            Replica::from_text("Херли тебе надо, а?".to_string()).with_speaker(Speaker::GopnikLeader),
             Replica::from_text("[Гигантсвий гопник в красных лосинах заржал и добавил:] \
-            Гы! Люлей давно не навешивали, фраер? Гы-гы-гы-гы!".to_string()),
+            Гы! Люлей давно не навешивали, фраер? Гы-гы-гы-гы!".to_string()).with_speaker(Speaker::GopnikGiant),
         ]
     );
 
     let mut fight = DialogStick::from(1);
 
-    fight.replicas.extend(vec![Replica::from_text(
-        "[Главарь:] ПА-ЦА-НЫЫЫЫ!".to_string(),
-    )]);
+    fight.replicas.extend(vec![
+        Replica::from_text("ПА-ЦА-НЫЫЫЫ!".to_string()).with_speaker(Speaker::GopnikLeader)
+    ]);
 
     let mut wtf = DialogStick::from(2);
     wtf.replicas.extend(vec![Replica::from_text(
-        "[Главарь:] Чё? Какие еще пельмени?".to_string(),
-    )]);
+        "Чё? Какие еще пельмени?".to_string(),
+    )
+    .with_speaker(Speaker::GopnikLeader)]);
 
     let mut aaa_blond = DialogStick::from(3);
     aaa_blond.replicas.extend(vec![
-        Replica::from_text("Ааааа, того блондина!? Хахахахахахаха!".to_string()),
+        Replica::from_text("Ааааа, того блондина!? Хахахахахахаха!".to_string())
+            .with_speaker(Speaker::GopnikLeader),
         Replica::from_text(
             "Да этот уморыш проиграл их нам в нарды! Мы не забирали их у него".to_string(),
-        ),
+        )
+        .with_speaker(Speaker::GopnikLeader),
     ]);
 
     let mut he_attack_us = DialogStick::from(4);
@@ -653,7 +693,8 @@ fn gopniks() -> (usize, HashMap<usize, DialogStick>) {
             Replica::from_text("Конечно избили, ведь он после проигрыша взбеился и начал виздать как баба!\n\
             Нарды нам раскидал, а затем и вовсе взял палку и набросился! Ну и приподали мы ему урок.\
             \nЧем докажем? А спроси у него самого о оставшихся у него его двух пачек пельмений, которые мы не забрали!\n\
-            Мы оставили у себя только то, что честно выиграли. А его добро не брали.".to_string()),
+            Мы оставили у себя только то, что честно выиграли. А его добро не брали.".to_string())
+                .with_speaker(Speaker::GopnikLeader),
         ]
     );
 
@@ -662,14 +703,27 @@ fn gopniks() -> (usize, HashMap<usize, DialogStick>) {
         "Вот увидишь, мы не чешим.".to_string(),
     )]);
 
+    let mut intimidated = DialogStick::from(6);
+    intimidated.replicas.extend(vec![
+        Replica::from_text("[Главарь привстал с корточек и нервно сглотнул.]".to_string()),
+        Replica::from_text(
+            "Э, э, ты чё, попутал? Ладно, ладно! Забирай свои пельмени, только не кипишуй."
+                .to_string(),
+        )
+        .with_speaker(Speaker::GopnikLeader),
+    ]);
+
     he_attack_us.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "Ляднё, я проверю.".to_string(),
                 go.id,
                 DialogEffect::EndDialog(Some(END_DIALOG_GOPNIKS_DIALOG_ASK_BLOND)),
-            ),
+            )
+            .with_action(DialogAction::SetVariable(
+                BLOND_HIDES_DUMPLINGS_VARIABLE.to_string(),
+                DialogValue::Flag(true),
+            )),
             Variant::create_with_effect(
                 "Я не верю, ето чущь!!!".to_string(),
                 fight.id,
@@ -679,7 +733,6 @@ fn gopniks() -> (usize, HashMap<usize, DialogStick>) {
     });
 
     aaa_blond.branching = Some(Branching {
-        id: 0,
         variants: vec![Variant::create_with_effect(
             "Чем ви докажете? Вы его избили!".to_string(),
             he_attack_us.id,
@@ -688,7 +741,6 @@ fn gopniks() -> (usize, HashMap<usize, DialogStick>) {
     });
 
     wtf.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "Котёрые вы забрали у тёго блондиня!".to_string(),
@@ -704,7 +756,6 @@ fn gopniks() -> (usize, HashMap<usize, DialogStick>) {
     });
 
     main_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "Отдавайте пельмени!".to_string(),
@@ -716,6 +767,25 @@ fn gopniks() -> (usize, HashMap<usize, DialogStick>) {
                 fight.id,
                 DialogEffect::EndDialog(Some(END_DIALOG_GOPNIKS_DIALOG_FIGHT)),
             ),
+            Variant::create_with_effect(
+                "Отдавайте пельмени по-хорошему, пока я вам щеи не свернуль!".to_string(),
+                intimidated.id,
+                DialogEffect::EndDialog(Some(END_DIALOG_GOPNIKS_DIALOG_INTIMIDATED)),
+            )
+            .with_action(DialogAction::GiveConsumable("dumplings".to_string()))
+            .with_action(DialogAction::GiveConsumable("dumplings".to_string()))
+            .with_check(
+                SkillCheck::of(
+                    GOPNIKS_INTIMIDATE_CHECK,
+                    Characteristic::Strength,
+                    1,
+                    fight.id,
+                )
+                .with_dice()
+                .with_failure_effect(DialogEffect::EndDialog(Some(
+                    END_DIALOG_GOPNIKS_DIALOG_FIGHT,
+                ))),
+            ),
         ],
     });
 
@@ -727,6 +797,7 @@ fn gopniks() -> (usize, HashMap<usize, DialogStick>) {
     pool.insert(aaa_blond.id, aaa_blond);
     pool.insert(he_attack_us.id, he_attack_us);
     pool.insert(go.id, go);
+    pool.insert(intimidated.id, intimidated);
 
     (root_id, pool)
 }
@@ -735,9 +806,8 @@ fn gopniks() -> (usize, HashMap<usize, DialogStick>) {
 //        *
 //        |
 //        ^
-//        |
-//        *
-//       END
+//       | |
+//       * * -> END
 
 pub const BLOND_GIVE_DUMPLINGS_DIALOG: usize = 6;
 
@@ -753,13 +823,17 @@ pub fn blond_give_dumplings_dialog() -> Dialog {
         root_id,
         sticks,
     )
+    .with_end_names(end_names![END_DIALOG_BLOND_GIVE_DUMPLINGS_COMPLETED])
 }
 
 fn blond_give_dumplings() -> (usize, HashMap<usize, DialogStick>) {
     let mut main_stick = DialogStick::from(0);
-    main_stick.replicas.extend(vec![Replica::from_text(
-        "Здравствуйте, сударъ. Ну как ваши успехи? Вы принесли мне мои пельмени?".to_string(),
-    )]);
+    main_stick.replicas.extend(vec![
+        Replica::from_text(
+            "Здравствуйте, сударъ. Ну как ваши успехи? Вы принесли мне мои пельмени?".to_string(),
+        ),
+        Replica::from_text("[Пачек пельменей в рюкзаке: {dumplings}]".to_string()),
+    ]);
 
     let mut final_word = DialogStick::from(1);
 
@@ -770,19 +844,33 @@ fn blond_give_dumplings() -> (usize, HashMap<usize, DialogStick>) {
         ]
     );
 
+    let mut not_yet = DialogStick::from(2);
+    not_yet.replicas.extend(vec![Replica::from_text(
+        "Ну так принесите их, сударъ!".to_string(),
+    )]);
+
     main_stick.branching = Some(Branching {
-        id: 0,
-        variants: vec![Variant::create_with_effect(
-            "Дя, вот они.".to_string(),
-            final_word.id,
-            DialogEffect::EndDialog(Some(END_DIALOG_BLOND_GIVE_DUMPLINGS_COMPLETED)),
-        )],
+        variants: vec![
+            Variant::create_with_effect(
+                "Дя, вот они.".to_string(),
+                final_word.id,
+                DialogEffect::EndDialog(Some(END_DIALOG_BLOND_GIVE_DUMPLINGS_COMPLETED)),
+            )
+            // Blond wants both packs back
+            .with_condition(DialogCondition::AtLeast("dumplings".to_string(), 2)),
+            Variant::create_with_effect(
+                "Покя нет.".to_string(),
+                not_yet.id,
+                DialogEffect::EndDialog(None),
+            ),
+        ],
     });
 
     let mut pool = HashMap::new();
     let root_id = main_stick.id;
     pool.insert(main_stick.id, main_stick);
     pool.insert(final_word.id, final_word);
+    pool.insert(not_yet.id, not_yet);
 
     (root_id, pool)
 }
@@ -810,13 +898,17 @@ pub const END_DIALOG_BLOND_TAKE_DUMPLINGS_NECK_TWISTED: usize = 2;
 pub fn blond_take_dumplings_dialog() -> Dialog {
     let (root_id, sticks) = blond_take_dumplings();
     Dialog::from(
-        DialogId(BLOND_GIVE_DUMPLINGS_DIALOG),
+        DialogId(BLOND_TAKE_DUMPLINGS_DIALOG),
         "Dialog 1".to_string(),
         "background/dialog_bg.png".to_string(),
         "npc/dialog/blonde.png".to_string(),
         root_id,
         sticks,
     )
+    .with_end_names(end_names![
+        END_DIALOG_BLOND_TAKE_DUMPLINGS_JUST_COMPLETED,
+        END_DIALOG_BLOND_TAKE_DUMPLINGS_NECK_TWISTED
+    ])
 }
 
 fn blond_take_dumplings() -> (usize, HashMap<usize, DialogStick>) {
@@ -854,13 +946,13 @@ fn blond_take_dumplings() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     truth.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "[Свернуть шею] ЫЫЫЫЫУУУУ!!!! Не указивай мне!!!".to_string(),
                 neck_twisted.id,
                 DialogEffect::EndDialog(Some(END_DIALOG_BLOND_TAKE_DUMPLINGS_NECK_TWISTED)),
-            ),
+            )
+            .with_action(DialogAction::GiveMoney(25)),
             Variant::create_with_effect(
                 "[Уйти] Сям заибряй свои пельмени!!!".to_string(),
                 i_go.id,
@@ -870,13 +962,16 @@ fn blond_take_dumplings() -> (usize, HashMap<usize, DialogStick>) {
     });
 
     no_it_is_not_me.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "Говори правдю, не тё щею сверню! Ты прятесь за пазухой другие две пачки пельмений, дя?".to_string(),
                 truth.id,
                 DialogEffect::ReplaceDialog,
-            ),
+            )
+            .with_condition(DialogCondition::Equals(
+                BLOND_HIDES_DUMPLINGS_VARIABLE.to_string(),
+                DialogValue::Flag(true),
+            )),
             Variant::create_with_effect(
                 "[Уйти] Ай, cям забиряй свои пельмени.".to_string(),
                 i_go.id,
@@ -887,7 +982,6 @@ fn blond_take_dumplings() -> (usize, HashMap<usize, DialogStick>) {
     );
 
     main_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "Ты обманюль меня! Это ты напал на гопников сам, после того как проиграль в нарди свои пельмени!".to_string(),
@@ -941,6 +1035,11 @@ pub fn guardian_first_dialog() -> Dialog {
         root_id,
         sticks,
     )
+    .with_end_names(end_names![
+        END_DIALOG_GUARDIAN_FIRST_JUST_COMPLETED,
+        END_DIALOG_GUARDIAN_FIRST_BEATEN,
+        END_DIALOG_GUARDIAN_FIRST_DREVNIRA_STOP_ACCEPTED
+    ])
 }
 
 fn guardian_first() -> (usize, HashMap<usize, DialogStick>) {
@@ -1027,8 +1126,13 @@ fn guardian_first() -> (usize, HashMap<usize, DialogStick>) {
         "Эээээ... Ладно, а можешь ты не скажешь никому, а мы тебя пропустим? Проходи.".to_string(),
     )]);
 
+    let mut complain_failed = DialogStick::from(11);
+
+    complain_failed.replicas.extend(vec![Replica::from_text(
+        "Ха! Жалуйся кому хочешь, гражданин. Приказ есть приказ.".to_string(),
+    )]);
+
     but_i_need.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create(
                 "Кто ета старушка?".to_string(),
@@ -1048,10 +1152,12 @@ fn guardian_first() -> (usize, HashMap<usize, DialogStick>) {
                 DialogEffect::EndDialog(Some(END_DIALOG_GUARDIAN_FIRST_DREVNIRA_STOP_ACCEPTED)),
             ),
             Variant::create_with_effect(
-                "[Языкастость] А может я просто расскажу как вы превышаете полномочия, да еще и вовлекаете гражданьских лиц?".to_string(),
+                "А может я просто расскажу как вы превышаете полномочия, да еще и вовлекаете гражданьских лиц?".to_string(),
                 i_will_complain.id,
                 DialogEffect::EndDialog(Some(END_DIALOG_GUARDIAN_FIRST_JUST_COMPLETED)),
-            ),
+            )
+            .with_check(guardian_complain_check(complain_failed.id))
+            .with_condition(guardian_complain_not_failed()),
             Variant::create_with_effect(
                 "[Атаковать] Я не собираюсь иметь делё с ней, лучше вам щеи сверню!".to_string(),
                 fight.id,
@@ -1062,7 +1168,6 @@ fn guardian_first() -> (usize, HashMap<usize, DialogStick>) {
     );
 
     main_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create("Ето же здание суда?".to_string(), is_it_court.id),
             Variant::create("Ыыыу, почему нельзя пройти!?".to_string(), why_i_cant.id),
@@ -1093,10 +1198,32 @@ fn guardian_first() -> (usize, HashMap<usize, DialogStick>) {
     pool.insert(where_is_she.id, where_is_she);
     pool.insert(i_will_do.id, i_will_do);
     pool.insert(i_will_complain.id, i_will_complain);
+    pool.insert(complain_failed.id, complain_failed);
 
     (root_id, pool)
 }
 
+// Guardians can be persuaded only once, failed check is not shown again,
+// so difficulty is the starting charisma and the chance is even
+fn guardian_complain_check(failure_stick_id: usize) -> SkillCheck {
+    SkillCheck::of(
+        GUARDIAN_COMPLAIN_CHECK,
+        Characteristic::Charisma,
+        1,
+        failure_stick_id,
+    )
+    .with_dice()
+}
+
+fn guardian_complain_not_failed() -> DialogCondition {
+    DialogCondition::Not(Box::new(DialogCondition::Equals(
+        format!("check.{GUARDIAN_COMPLAIN_CHECK}"),
+        DialogValue::Flag(false),
+    )))
+}
+
+pub const GUARDIAN_COMPLAIN_CHECK: &str = "guardian_complain";
+
 //      START
 //        *
 //        |
@@ -1120,6 +1247,10 @@ pub fn guardian_second_dialog() -> Dialog {
         root_id,
         sticks,
     )
+    .with_end_names(end_names![
+        END_DIALOG_GUARDIAN_SECOND_COMPLETED,
+        END_DIALOG_GUARDIAN_SECOND_BEATEN
+    ])
 }
 
 fn guardian_second() -> (usize, HashMap<usize, DialogStick>) {
@@ -1146,8 +1277,13 @@ fn guardian_second() -> (usize, HashMap<usize, DialogStick>) {
         .replicas
         .extend(vec![Replica::from_text("Ряяяяя!!! Говнюк!!!".to_string())]);
 
+    let mut complain_failed = DialogStick::from(4);
+
+    complain_failed.replicas.extend(vec![Replica::from_text(
+        "Ха! Жалуйся кому хочешь. Сначала разберись со старухой.".to_string(),
+    )]);
+
     main_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "Еще неть.".to_string(),
@@ -1155,10 +1291,12 @@ fn guardian_second() -> (usize, HashMap<usize, DialogStick>) {
                 DialogEffect::EndDialog(None),
             ),
             Variant::create_with_effect(
-                "[Языкастость] А может я просто расскажу как вы превышаете полномочия, да еще и вовлекаете гражданьских лиц?".to_string(),
+                "А может я просто расскажу как вы превышаете полномочия, да еще и вовлекаете гражданьских лиц?".to_string(),
                 i_will_complain.id,
                 DialogEffect::EndDialog(Some(END_DIALOG_GUARDIAN_SECOND_COMPLETED)),
-            ),
+            )
+            .with_check(guardian_complain_check(complain_failed.id))
+            .with_condition(guardian_complain_not_failed()),
             Variant::create_with_effect(
                 "[Атаковать] Я передумал. Пожалуй, я простё сверню вам щеи!".to_string(),
                 fight.id,
@@ -1174,6 +1312,7 @@ fn guardian_second() -> (usize, HashMap<usize, DialogStick>) {
     pool.insert(not_yet.id, not_yet);
     pool.insert(i_will_complain.id, i_will_complain);
     pool.insert(fight.id, fight);
+    pool.insert(complain_failed.id, complain_failed);
 
     (root_id, pool)
 }
@@ -1203,6 +1342,10 @@ pub fn guardian_third_dialog() -> Dialog {
         root_id,
         sticks,
     )
+    .with_end_names(end_names![
+        END_DIALOG_GUARDIAN_THIRD_COMPLETED,
+        END_DIALOG_GUARDIAN_THIRD_BEATEN
+    ])
 }
 
 fn guardian_third() -> (usize, HashMap<usize, DialogStick>) {
@@ -1232,7 +1375,6 @@ fn guardian_third() -> (usize, HashMap<usize, DialogStick>) {
         .extend(vec![Replica::from_text("Ах ты, говнюк!".to_string())]);
 
     main_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "Ыу.".to_string(),
@@ -1287,6 +1429,10 @@ pub fn hall_guardian_first_dialog() -> Dialog {
         root_id,
         sticks,
     )
+    .with_end_names(end_names![
+        HALL_GUARDIAN_FIRST_DIALOG_COMPLETED,
+        HALL_GUARDIAN_FIRST_DIALOG_BEATEN
+    ])
 }
 
 fn hall_guardian_first() -> (usize, HashMap<usize, DialogStick>) {
@@ -1308,7 +1454,6 @@ fn hall_guardian_first() -> (usize, HashMap<usize, DialogStick>) {
         .extend(vec![Replica::from_text("Ах ты, говнюк!".to_string())]);
 
     main_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "Но мене надо войти!".to_string(),
@@ -1353,6 +1498,7 @@ pub fn hall_guardian_second_dialog() -> Dialog {
         root_id,
         sticks,
     )
+    .with_end_names(end_names![HALL_GUARDIAN_SECOND_COMPLETED])
 }
 
 fn hall_guardian_second() -> (usize, HashMap<usize, DialogStick>) {
@@ -1366,7 +1512,6 @@ fn hall_guardian_second() -> (usize, HashMap<usize, DialogStick>) {
         .extend(vec![Replica::from_text("...".to_string())]);
 
     main_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![Variant::create_with_effect(
             "Чё?".to_string(),
             what.id,
@@ -1403,6 +1548,7 @@ pub fn table_1_dialog() -> Dialog {
         root_id,
         sticks,
     )
+    .with_end_names(end_names![TABLE_1_COMPLETED])
 }
 
 fn table_1() -> (usize, HashMap<usize, DialogStick>) {
@@ -1428,7 +1574,6 @@ fn table_1() -> (usize, HashMap<usize, DialogStick>) {
         .extend(vec![Replica::from_text("...".to_string())]);
 
     main_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "Где столик номер 2?".to_string(),
@@ -1479,6 +1624,7 @@ pub fn table_2_dialog() -> Dialog {
         root_id,
         sticks,
     )
+    .with_end_names(end_names![TABLE_2_COMPLETED])
 }
 
 fn table_2() -> (usize, HashMap<usize, DialogStick>) {
@@ -1499,7 +1645,6 @@ fn table_2() -> (usize, HashMap<usize, DialogStick>) {
         .extend(vec![Replica::from_text("Хм.".to_string())]);
 
     main_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "Ну где столик номер 3?".to_string(),
@@ -1545,6 +1690,7 @@ pub fn table_3_dialog() -> Dialog {
         root_id,
         sticks,
     )
+    .with_end_names(end_names![TABLE_3_COMPLETED])
 }
 
 fn table_3() -> (usize, HashMap<usize, DialogStick>) {
@@ -1567,7 +1713,6 @@ fn table_3() -> (usize, HashMap<usize, DialogStick>) {
     );
 
     main_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "Когда меня пустять в заль судя?".to_string(),
@@ -1636,6 +1781,10 @@ pub fn crazy_man_dialog() -> Dialog {
         root_id,
         sticks,
     )
+    .with_end_names(end_names![
+        CRAZY_MAN_DIALOG_COMPLETED,
+        CRAZY_MAN_DIALOG_BEATEN
+    ])
 }
 
 fn crazy_man() -> (usize, HashMap<usize, DialogStick>) {
@@ -1671,7 +1820,6 @@ fn crazy_man() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     wrong_answer.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "Ай, нафиг твои загадки.".to_string(),
@@ -1716,7 +1864,6 @@ fn crazy_man() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     right_answer_3.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "Мдя. Я ухожу.".to_string(),
@@ -1732,7 +1879,6 @@ fn crazy_man() -> (usize, HashMap<usize, DialogStick>) {
     });
 
     right_answer_2.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "33 ПЕЛЬМЕНЯ И Я".to_string(),
@@ -1768,7 +1914,6 @@ fn crazy_man() -> (usize, HashMap<usize, DialogStick>) {
     });
 
     right_answer_1.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "ПЕЛЬМЕНИ".to_string(),
@@ -1799,7 +1944,6 @@ fn crazy_man() -> (usize, HashMap<usize, DialogStick>) {
     });
 
     guess_start.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "Не зняю.".to_string(),
@@ -1842,7 +1986,6 @@ fn crazy_man() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     just_dont_want.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "[Уйти] Да мне пофигь.".to_string(),
@@ -1858,7 +2001,6 @@ fn crazy_man() -> (usize, HashMap<usize, DialogStick>) {
     });
 
     dont_want.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "Ляяядно, давай свои зягадьки.".to_string(),
@@ -1874,7 +2016,6 @@ fn crazy_man() -> (usize, HashMap<usize, DialogStick>) {
     });
 
     main_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "Ну дявай.".to_string(),
@@ -1989,6 +2130,9 @@ pub const JUDGES_FIRST_DIALOG: usize = 17;
 pub const JUDGES_FIRST_DIALOG_COMPLETED: usize = 1;
 pub const JUDGES_FIRST_DIALOG_BEATEN: usize = 2;
 
+// Answers where Formidable Face admits taking the house, the verdict depends on it
+pub const TRIAL_GUILT_ADMITTED_VARIABLE: &str = "trial.guilt_admitted";
+
 pub fn judges_first_dialog() -> Dialog {
     let (root_id, sticks) = judges_first();
     Dialog::from(
@@ -1999,6 +2143,10 @@ pub fn judges_first_dialog() -> Dialog {
         root_id,
         sticks,
     )
+    .with_end_names(end_names![
+        JUDGES_FIRST_DIALOG_COMPLETED,
+        JUDGES_FIRST_DIALOG_BEATEN
+    ])
 }
 
 pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
@@ -2013,14 +2161,17 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
         Replica::from_text(
             "ТИШИНА В ЗАЛЕ, ТИШИНА! РАССАЖИВАЕМСЯ НА МЕСТА, ЗАСЕДАНИЕ НАЧНЕТСЯ ЧЕРЕЗ РАЗ..."
                 .to_string(),
-        ),
-        Replica::from_text("Пацан: Ххххммммм, два!".to_string()),
-        Replica::from_text("Жирный [молчание]...... А, ж, ой. Четыре!".to_string()),
+        )
+        .with_speaker(Speaker::ChiefJudge),
+        Replica::from_text("Ххххммммм, два!".to_string()).with_speaker(Speaker::Kid),
+        Replica::from_text("[молчание]...... А, ж, ой. Четыре!".to_string())
+            .with_speaker(Speaker::Fatty),
         Replica::from_text(
             "ЗАСЕДАНИЕ НАЧИНАЕТСЯ! [СТУЧИТ МОЛОТКОМ]. \
             ОТКРЫВАЕМ СЛУШАНИЕ ПО ДЕЛО № 5 \"О Доме т.н. Грозн. Л-чк.\""
                 .to_string(),
-        ),
+        )
+        .with_speaker(Speaker::ChiefJudge),
     ]);
 
     let mut fight = DialogStick::from(1);
@@ -2029,15 +2180,20 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
         "ОХРАНА, ВЫШВЫРНЕТЕ ЭТОГО БУЯНА!!\n[Одновременно с криком судьи, Пацан выпивает формулу и \
             превращается в так называемого Рыжего Шипастого Пацана]"
             .to_string(),
-    )]);
+    )
+    .with_speaker(Speaker::ChiefJudge)
+    .with_portrait("npc/red_chad.png")]);
 
     let mut fight_with_simple_boy = DialogStick::from(2);
 
     fight_with_simple_boy.replicas.extend(
         vec![
-            Replica::from_text("[Обычный мальчик заорал] АААААА, на помощь!!".to_string()),
+            Replica::from_text("[Обычный мальчик заорал] АААААА, на помощь!!".to_string())
+                .with_speaker(Speaker::OrdinaryBoy),
             Replica::from_text("ОХРАНА, ВЫШВЫРНЕТЕ ЭТОГО БУЯНА!!\n[Одновременно с криком судьи, Пацан выпивает формулу и \
-            превращается в так называемого Рыжего Шипастого Пацана]".to_string()),
+            превращается в так называемого Рыжего Шипастого Пацана]".to_string())
+                .with_speaker(Speaker::ChiefJudge)
+                .with_portrait("npc/red_chad.png"),
         ]
     );
 
@@ -2045,8 +2201,10 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
     pass_1.replicas.extend(vec![
         Replica::from_text(
             "Обвинитель: уважаемый Обычный Мальчик. Обвиняемый: Грозный Личик.".to_string(),
-        ),
-        Replica::from_text("Начинаю зачитывать материалы настоящего дела...".to_string()),
+        )
+        .with_speaker(Speaker::ChiefJudge),
+        Replica::from_text("Начинаю зачитывать материалы настоящего дела...".to_string())
+            .with_speaker(Speaker::ChiefJudge),
     ]);
 
     let mut pass_2 = DialogStick::from(4);
@@ -2055,13 +2213,15 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
             "Уважаемый господин Обычный Мальчик утверждает, что физическое \
             лицо по имени Грозное Лицо.. эм, Личик незаконно завладел его недвижимостью."
                 .to_string(),
-        ),
+        )
+        .with_speaker(Speaker::ChiefJudge),
         Replica::from_text(
             "Он требует вернуть его дом, \
             по праву ему принадлежащий и получить от Грозного Личика денежную компенсацию"
                 .to_string(),
-        ),
-        Replica::from_text("Пацан: Ххммммм, да, денежную!".to_string()),
+        )
+        .with_speaker(Speaker::ChiefJudge),
+        Replica::from_text("Ххммммм, да, денежную!".to_string()).with_speaker(Speaker::Kid),
     ]);
 
     let mut get_out_here = DialogStick::from(5);
@@ -2077,7 +2237,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     get_out_here.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "[Напасть] Щас в ухо пёрну!".to_string(),
@@ -2145,7 +2304,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     ending.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "[Напасть] Никтё никуда не пойдеть!!!!".to_string(),
@@ -2171,7 +2329,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     long_time_ago.branching = Some(Branching {
-        id: 0,
         variants: vec![Variant::create_with_effect(
             "...".to_string(),
             ending.id,
@@ -2185,7 +2342,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     i_stole.branching = Some(Branching {
-        id: 0,
         variants: vec![Variant::create_with_effect(
             "...".to_string(),
             ending.id,
@@ -2200,7 +2356,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     i_built.branching = Some(Branching {
-        id: 0,
         variants: vec![Variant::create_with_effect(
             "...".to_string(),
             ending.id,
@@ -2214,7 +2369,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     years_ago.branching = Some(Branching {
-        id: 0,
         variants: vec![Variant::create_with_effect(
             "...".to_string(),
             ending.id,
@@ -2224,7 +2378,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
 
     question5.branching = Some(
         Branching {
-            id: 0,
             variants: vec![
                 Variant::create_with_effect(
                     "[Напасть] Мне надоели твои вопроси!".to_string(),
@@ -2240,7 +2393,11 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
                     "Хи-хи! Я его не покупаль, а очевиднё забраль у Обичного Мальчика, выгнав его на помойку!".to_string(),
                     i_stole.id,
                     DialogEffect::ReplaceDialog,
-                ),
+                )
+                .with_action(DialogAction::SetVariable(
+                    TRIAL_GUILT_ADMITTED_VARIABLE.to_string(),
+                    DialogValue::Flag(true),
+                )),
                 Variant::create_with_effect(
                     "Я его не покупаль! Я его строиль сям! Ето все знают!".to_string(),
                     i_built.id,
@@ -2261,7 +2418,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     look_at_the_boy.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "[Напасть на Обычного Мальчика]".to_string(),
@@ -2278,7 +2434,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
 
     question4.branching = Some(
         Branching {
-            id: 0,
             variants: vec![
                 Variant::create_with_effect(
                     "[Напасть на Обычного Мальчика] Ах ты говнюк!".to_string(),
@@ -2315,7 +2470,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
     );
 
     question3.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "[Напасть] Фиг тебе!".to_string(),
@@ -2353,7 +2507,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     wrong.branching = Some(Branching {
-        id: 0,
         variants: vec![Variant::create_with_effect(
             "...".to_string(),
             question3.id,
@@ -2367,7 +2520,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     right.branching = Some(Branching {
-        id: 0,
         variants: vec![Variant::create_with_effect(
             "...".to_string(),
             question3.id,
@@ -2384,7 +2536,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
         )]);
 
     this_stupid_question.branching = Some(Branching {
-        id: 0,
         variants: vec![Variant::create_with_effect(
             "...".to_string(),
             question3.id,
@@ -2393,7 +2544,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
     });
 
     question2.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "[Напасть] Ыыыыы!!!! Это тупо!".to_string(),
@@ -2422,7 +2572,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     dumplings.branching = Some(Branching {
-        id: 0,
         variants: vec![Variant::create_with_effect(
             "Хм.".to_string(),
             question2.id,
@@ -2436,7 +2585,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     wtf_this_question.branching = Some(Branching {
-        id: 0,
         variants: vec![Variant::create_with_effect(
             "Воть именно.".to_string(),
             question2.id,
@@ -2450,7 +2598,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     slept.branching = Some(Branching {
-        id: 0,
         variants: vec![Variant::create_with_effect(
             "...".to_string(),
             question2.id,
@@ -2464,7 +2611,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
     )]);
 
     forged.branching = Some(Branching {
-        id: 0,
         variants: vec![Variant::create_with_effect(
             "Ыу.".to_string(),
             question2.id,
@@ -2473,7 +2619,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
     });
 
     question1.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "[Напасть] Готовилься избивать сюдей.".to_string(),
@@ -2499,12 +2644,15 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
                 "Ну очевидьно потделываль документы на дом!".to_string(),
                 forged.id,
                 DialogEffect::ReplaceDialog,
-            ),
+            )
+            .with_action(DialogAction::SetVariable(
+                TRIAL_GUILT_ADMITTED_VARIABLE.to_string(),
+                DialogValue::Flag(true),
+            )),
         ],
     });
 
     pass_2.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "[Напасть] ЫЫЫЫУУУУ, это мой дём!!!".to_string(),
@@ -2525,7 +2673,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
     });
 
     pass_1.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "[Напасть] Пора заканчивать эту клёунадю.".to_string(),
@@ -2546,7 +2693,6 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
     });
 
     main_stick.branching = Some(Branching {
-        id: 0,
         variants: vec![
             Variant::create_with_effect(
                 "[Напасть] ЫЫЫУУУУУ, Пацань!!!!".to_string(),
@@ -2601,7 +2747,116 @@ pub fn judges_first() -> (usize, HashMap<usize, DialogStick>) {
 
 pub const JUDGES_SECOND_DIALOG: usize = 18;
 
+pub const JUDGES_SECOND_DIALOG_COMPLETED: usize = 1;
+
+pub fn judges_second_dialog() -> Dialog {
+    let (root_id, sticks) = judges_second();
+    Dialog::from(
+        DialogId(JUDGES_SECOND_DIALOG),
+        "Dialog 1".to_string(),
+        "background/dialog_bg.png".to_string(),
+        "npc/dialog_courier.png".to_string(),
+        root_id,
+        sticks,
+    )
+    .with_end_names(end_names![JUDGES_SECOND_DIALOG_COMPLETED])
+}
+
+pub fn judges_second() -> (usize, HashMap<usize, DialogStick>) {
+    let mut main_stick = DialogStick::from(0);
+    main_stick.replicas.extend(vec![
+        Replica::from_text(
+            "Грозный Личик, суд вас оправдал. Дом остаётся за вами, ступайте домой!".to_string(),
+        )
+        .with_speaker(Speaker::ChiefJudge),
+        Replica::from_text("Ххххммм... Пока что.".to_string()).with_speaker(Speaker::Kid),
+    ]);
+
+    let mut closed = DialogStick::from(1);
+    closed.replicas.extend(vec![
+        Replica::from_text("ЗАСЕДАНИЕ ОКОНЧЕНО!".to_string()).with_speaker(Speaker::ChiefJudge)
+    ]);
+
+    main_stick.branching = Some(Branching {
+        variants: vec![Variant::create_with_effect(
+            "Уря! Пойдю домой!".to_string(),
+            closed.id,
+            DialogEffect::EndDialog(Some(JUDGES_SECOND_DIALOG_COMPLETED)),
+        )],
+    });
+
+    let mut pool = HashMap::new();
+    let root_id = main_stick.id;
+    pool.insert(main_stick.id, main_stick);
+    pool.insert(closed.id, closed);
+
+    (root_id, pool)
+}
+
 pub const JUDGES_THIRD_DIALOG: usize = 19;
 
 pub const JUDGES_THIRD_DIALOG_COMPLETED: usize = 1;
 pub const JUDGES_THIRD_DIALOG_BEATEN: usize = 2;
+
+pub fn judges_third_dialog() -> Dialog {
+    let (root_id, sticks) = judges_third();
+    Dialog::from(
+        DialogId(JUDGES_THIRD_DIALOG),
+        "Dialog 1".to_string(),
+        "background/dialog_bg.png".to_string(),
+        "npc/dialog_courier.png".to_string(),
+        root_id,
+        sticks,
+    )
+    .with_end_names(end_names![
+        JUDGES_THIRD_DIALOG_COMPLETED,
+        JUDGES_THIRD_DIALOG_BEATEN
+    ])
+}
+
+pub fn judges_third() -> (usize, HashMap<usize, DialogStick>) {
+    let mut main_stick = DialogStick::from(0);
+    main_stick.replicas.extend(vec![
+        Replica::from_text(
+            "Грозный Личик, вам надлежит освободить дом господина Обычного Мальчика \
+            и выплатить ему компенсацию!"
+                .to_string(),
+        )
+        .with_speaker(Speaker::ChiefJudge),
+        Replica::from_text("Хе-хе. Да, компенсацию!".to_string()).with_speaker(Speaker::Kid),
+    ]);
+
+    let mut fight = DialogStick::from(1);
+    fight.replicas.extend(vec![Replica::from_text(
+        "ОХРАНА, ВЫШВЫРНЕТЕ ЭТОГО БУЯНА!!".to_string(),
+    )
+    .with_speaker(Speaker::ChiefJudge)]);
+
+    let mut closed = DialogStick::from(2);
+    closed.replicas.extend(vec![
+        Replica::from_text("ЗАСЕДАНИЕ ОКОНЧЕНО!".to_string()).with_speaker(Speaker::ChiefJudge)
+    ]);
+
+    main_stick.branching = Some(Branching {
+        variants: vec![
+            Variant::create_with_effect(
+                "[Напасть] Никогдя!!!".to_string(),
+                fight.id,
+                DialogEffect::EndDialog(Some(JUDGES_THIRD_DIALOG_BEATEN)),
+            ),
+            Variant::create_with_effect(
+                "Ыыыыуу... Ладня.".to_string(),
+                closed.id,
+                DialogEffect::EndDialog(Some(JUDGES_THIRD_DIALOG_COMPLETED)),
+            ),
+        ],
+    });
+
+    let mut pool = HashMap::new();
+    let root_id = main_stick.id;
+    pool.insert(main_stick.id, main_stick);
+    pool.insert(fight.id, fight);
+    pool.insert(closed.id, closed);
+
+    (root_id, pool)
+}
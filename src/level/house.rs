@@ -4,6 +4,7 @@ use bevy::prelude::in_state;
 use bevy::prelude::AppExtStates;
 use bevy::prelude::Component;
 use bevy::prelude::DetectChanges;
+use bevy::prelude::EventWriter;
 use bevy::prelude::NextState;
 use bevy::prelude::OnEnter;
 use bevy::prelude::OnExit;
@@ -17,7 +18,6 @@ use bevy::{
     ecs::schedule::IntoSystemConfigs,
     ecs::system::Query,
     ecs::system::ResMut,
-    input::keyboard::KeyCode,
     input::ButtonInput,
     prelude::AssetServer,
     prelude::BuildChildren,
@@ -32,30 +32,30 @@ use bevy::{
 };
 use bevy_rapier2d::prelude::{Collider, RigidBody};
 
-use super::objects::wooden_chest_states_draws;
-use super::objects::{interact_with_container_handle, spawn_container, LevelArm};
-use super::sprites::WoodenChestSprites;
+use super::objects::{spawn_container, LevelArm, RestPoint, HOUSE_WOODEN_CHEST_ID};
 use super::COURIER_DIALOG;
-use super::END_DIALOG_NECK_TWISTED;
 use super::SLEEPING_FORMIDABLE_DOG_DIALOG;
-use super::{
-    dialog_starts, HasDialogId, END_DIALOG_AGENDA_TAKEN, END_DIALOG_FORMIDABLE_DOG_JOINED,
-};
-use crate::animation::entities::MoveDirection;
+use super::{dialog_starts, HasDialogId, END_DIALOG_FORMIDABLE_DOG_JOINED};
 use crate::core::entities::BodyYOffset;
 use crate::core::z_index::DEFAULT_OBJECT_Z;
 use crate::dialog::SelectedVariantsSource;
+use crate::input::InputAction;
 use crate::interaction::interactors::{
     change_switcher_state, detect_active_interaction, ActiveInteractor,
 };
 use crate::level::house::FormidableDogState::Wakefulness;
+use crate::level::registry::LevelRegistry;
 use crate::level::states::Level;
+use crate::level::transitions::{level_exit_conditions, LevelExit, LevelExitCondition, SpawnPoint};
+use crate::movement::entities::MoveDirection;
 use crate::npc::{spawn_fixed_npc, spawn_formidable_dog, IdleAnimation};
 use crate::party::{PartyMember, PartyStateStorage};
-use crate::player::entities::{FormidableDog, PlayerPosition};
-use crate::rpg::{Character, CharacterStorage, ConsumableItem};
+use crate::player::entities::FormidableDog;
+use crate::player::followers::Follower;
+use crate::rpg::{Character, CharacterStorage, ConsumableItem, Loot, QuestItem};
 use crate::world_state::EscapeFromHouse;
 use crate::world_state::EscapeFromHouse::{CallDog, Escape, GoSleep};
+use crate::world_state::QuestEvent;
 use crate::{
     core::{
         collisions::recalculate_z,
@@ -75,7 +75,7 @@ struct HouseLevel;
 struct Courier;
 
 #[derive(Component)]
-struct Door;
+struct Bed;
 
 impl HasDialogId for Courier {
     fn dialog_id(&self) -> usize {
@@ -110,7 +110,6 @@ impl<S: States> Plugin for HousePlugin<S> {
             .add_systems(OnExit(self.state.clone()), unload)
             .add_systems(Update, courier_spawns.run_if(in_state(self.state.clone())))
             .add_systems(OnExit(self.state.clone()), despawn_courier)
-            .add_systems(Update, interact_with_container_handle)
             .add_systems(
                 Update,
                 sleeping_formidable_dog_spawns.run_if(in_state(self.state.clone())),
@@ -125,14 +124,13 @@ impl<S: States> Plugin for HousePlugin<S> {
                 Update,
                 dialog_starts::<SleepingFormidableDog>.run_if(in_state(CallDog)),
             )
-            .add_systems(Update, escape_from_house_handle.run_if(in_state(Escape)))
+            .add_systems(Update, go_sleep_handle.run_if(in_state(GoSleep)))
             .add_systems(
                 Update,
                 (
                     recalculate_z,
                     escape_from_house_variants_handles,
-                    wooden_chest_states_draws::<ConsumableItem>
-                        .after(interact_with_container_handle),
+                    level_exit_conditions::<EscapeFromHouse>,
                     draw_level_arm_states.after(change_switcher_state),
                 )
                     .run_if(in_state(self.state.clone())),
@@ -144,18 +142,11 @@ fn load(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
+    level_registry: Res<LevelRegistry>,
 ) {
-    let y_max = LevelYMax::create(192.0);
+    let y_max = LevelYMax::create(level_registry.y_max(&Level::House));
     commands.spawn((y_max, HouseLevel));
 
-    let wooden_chest_sprites = WoodenChestSprites {
-        closed: asset_server.load("chest/wooden.png"),
-        full: asset_server.load("chest/wooden_full.png"),
-        empty: asset_server.load("chest/wooden_empty.png"),
-    };
-
-    commands.insert_resource(wooden_chest_sprites);
-
     spawn_floor(&mut commands, &asset_server);
     spawn_walls(&mut commands, &asset_server);
     spawn_door(&mut commands, &asset_server);
@@ -167,14 +158,13 @@ fn load(
     spawn_wooden_chest(&mut commands, &asset_server, y_max);
 }
 
-fn escape_from_house_handle(
-    keyboard: Res<ButtonInput<KeyCode>>,
-    mut level_state: ResMut<NextState<Level>>,
-    mut next_player_position_query: Query<&mut PlayerPosition>,
-    interactors_query: Query<(&PassiveInteractor, &Transform), With<Door>>,
+fn go_sleep_handle(
+    actions: Res<ButtonInput<InputAction>>,
+    mut quest_events: EventWriter<QuestEvent>,
+    interactors_query: Query<(&PassiveInteractor, &Transform), With<Bed>>,
     active_interactor_query: Query<(&ActiveInteractor, &Transform)>,
 ) {
-    if !(keyboard.pressed(KeyCode::KeyE) && keyboard.just_pressed(KeyCode::KeyE)) {
+    if !(actions.pressed(InputAction::Interact) && actions.just_pressed(InputAction::Interact)) {
         return;
     }
 
@@ -182,10 +172,7 @@ fn escape_from_house_handle(
         let is_interacting =
             detect_active_interaction(&active_interactor_query, (interactor, transform));
         if is_interacting {
-            let mut position = next_player_position_query.single_mut();
-            position.x = 20.0;
-            position.y = -400.0;
-            level_state.set(Level::CourtHouseFront);
+            quest_events.send(QuestEvent::WentToBed);
         }
     }
 }
@@ -194,26 +181,10 @@ fn escape_from_house_variants_handles(
     mut character_storage: ResMut<CharacterStorage>,
     mut party_state: ResMut<PartyStateStorage>,
     mut dialog_variant_source: ResMut<SelectedVariantsSource>,
-    mut escape_from_house_state: ResMut<NextState<EscapeFromHouse>>,
     mut formidable_dog_state: ResMut<NextState<FormidableDogState>>,
 ) {
     // todo: it calculates on each frame.
     // make it when dialog_variant_source updates only.
-    let selected = dialog_variant_source.consume(&COURIER_DIALOG);
-    match selected {
-        None => {}
-        Some(ids) => {
-            for id in ids {
-                if id == END_DIALOG_NECK_TWISTED {
-                    escape_from_house_state.set(GoSleep)
-                }
-
-                if id == END_DIALOG_AGENDA_TAKEN {
-                    escape_from_house_state.set(CallDog)
-                }
-            }
-        }
-    }
     let selected = dialog_variant_source.consume(&SLEEPING_FORMIDABLE_DOG_DIALOG);
     match selected {
         None => {}
@@ -225,7 +196,6 @@ fn escape_from_house_variants_handles(
                     formidable_dog_state.set(Wakefulness);
                 }
             }
-            escape_from_house_state.set(Escape);
         }
     }
 }
@@ -299,10 +269,14 @@ fn spawn_wooden_chest(commands: &mut Commands, asset_server: &Res<AssetServer>,
         105.0,
         chest_z,
         -2.0,
+        HOUSE_WOODEN_CHEST_ID,
         vec![
-            ConsumableItem::default_dumplings(),
-            ConsumableItem::default_venison(),
+            Loot::Consumable(ConsumableItem::default_dumplings()),
+            Loot::Consumable(ConsumableItem::default_venison()),
+            Loot::Money(15),
+            Loot::Quest(QuestItem::ChestKey),
         ],
+        None,
     );
 }
 
@@ -432,12 +406,21 @@ fn spawn_bed(commands: &mut Commands, asset_server: &Res<AssetServer>, y_max: Le
                 },
                 ..Default::default()
             },
+            Bed,
             HouseLevel,
         ))
         .with_children(|children| {
             children
                 .spawn(Collider::cuboid(33.0, 40.0))
                 .insert(TransformBundle::from(Transform::from_xyz(0.0, -8.0, bed_z)));
+        })
+        .insert(PassiveInteractor {
+            area: InteractionArea::create(33.0, 44.0, 0.0, -12.0),
+            side: InteractionSide::Bottom,
+        })
+        .insert(RestPoint)
+        .insert(Description {
+            text: "Кровать. Можно отдохнуть и набраться сил".to_string(),
         });
 }
 
@@ -514,13 +497,25 @@ fn spawn_door(commands: &mut Commands, asset_server: &Res<AssetServer>) {
                 },
                 ..Default::default()
             },
-            Door,
             HouseLevel,
         ))
         .insert(PassiveInteractor {
             area: InteractionArea::from_sizes(30.0, 32.0),
             side: InteractionSide::Bottom,
-        });
+        })
+        .insert(LevelExit::create(Level::CourtHouseFront, "house"))
+        .insert(LevelExitCondition { state: Escape });
+
+    commands.spawn((
+        SpawnPoint::create("start"),
+        TransformBundle::from(Transform::from_xyz(60.0, -100.0, DEFAULT_OBJECT_Z)),
+        HouseLevel,
+    ));
+    commands.spawn((
+        SpawnPoint::create("door"),
+        TransformBundle::from(Transform::from_xyz(120.0, 160.0, DEFAULT_OBJECT_Z)),
+        HouseLevel,
+    ));
 }
 
 fn spawn_floor(commands: &mut Commands, asset_server: &Res<AssetServer>) {
@@ -617,7 +612,7 @@ fn courier_spawns(
                 &mut layouts,
                 (Courier, HouseLevel),
                 "npc/clerk.png".to_string(),
-                MoveDirection::ForwardIdle,
+                MoveDirection::BottomIdle,
                 120.0,
                 200.0,
                 ON_WALL_OBJECT_Z + 1.5,
@@ -641,7 +636,12 @@ fn initial_spawn_formidable_dog(
         asset_server,
         commands,
         layouts,
-        FormidableDog,
+        (
+            FormidableDog,
+            Follower {
+                member_id: PartyMember::initial_formidable_dog().id,
+            },
+        ),
         x,
         y,
         z,
@@ -3,115 +3,147 @@ use crate::level::court::CourtPlugin;
 use crate::level::courthouse_front::CourtHouseFrontPlugin;
 use crate::level::courthouse_hall::CourtHouseHallPlugin;
 use crate::level::house::HousePlugin;
+use crate::level::objects::{
+    interact_with_container_handle, rest_handle, wooden_chest_states_draws,
+};
+use crate::level::registry::{AppLevelExt, LevelInfo, LevelRegistry};
+use crate::level::sprites::WoodenChestSprites;
 use crate::level::states::Level;
-use crate::sound::Soundtrack;
+use crate::level::tiled::{tiled_level_spawns, TiledMap, TiledMapLoader};
+use crate::level::transitions::{level_exits_handle, level_transitions, LevelTransition};
+use crate::sound::{MusicPlayer, Soundtrack, Track};
 use bevy::app::{App, Plugin, Update};
-use bevy::audio::{AudioBundle, PlaybackSettings};
+use bevy::asset::{AssetApp, AssetServer};
+use bevy::prelude::in_state;
 use bevy::prelude::AppExtStates;
-use bevy::prelude::Commands;
 use bevy::prelude::DetectChanges;
-use bevy::prelude::Entity;
-use bevy::prelude::FromWorld;
+use bevy::prelude::IntoSystemConfigs;
 use bevy::prelude::Res;
 use bevy::prelude::ResMut;
-use bevy::prelude::Resource;
 use bevy::prelude::State;
-use bevy_rapier2d::parry::simba::scalar::SupersetOf;
+pub use cutscenes::*;
 pub use dialogs::*;
+pub use shops::*;
 
 mod court;
 mod courthouse_front;
 mod courthouse_hall;
+mod cutscenes;
 mod dialogs;
 pub mod house;
 pub mod objects;
+pub mod registry;
+mod shops;
 pub mod sprites;
 pub(crate) mod states;
+pub mod tiled;
+pub mod transitions;
 
 pub struct LevelNavPlugin;
 
 impl Plugin for LevelNavPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((
-            HousePlugin {
-                state: Level::House,
-            },
-            CourtHouseFrontPlugin {
-                state: Level::CourtHouseFront,
-            },
-            CourtHouseHallPlugin {
-                state: Level::CourtHouseHall,
-            },
-            CourtPlugin {
-                state: Level::Court,
-            },
-        ))
-        .init_state::<Level>()
-        .init_resource::<CurrentSoundtrack>()
-        .add_systems(Update, level_sounds_handle);
+        app.init_resource::<LevelRegistry>()
+            .add_level(
+                LevelInfo {
+                    level: Level::House,
+                    name: "house",
+                    title: "House",
+                    music: "sounds/track/house.ogg",
+                    default_spawn: "start",
+                    y_max: 192.0,
+                },
+                HousePlugin {
+                    state: Level::House,
+                },
+            )
+            .add_level(
+                LevelInfo {
+                    level: Level::CourtHouseFront,
+                    name: "courthouse_front",
+                    title: "Courthouse front",
+                    music: "sounds/track/courthouse_front.ogg",
+                    default_spawn: "house",
+                    y_max: 500.0,
+                },
+                CourtHouseFrontPlugin {
+                    state: Level::CourtHouseFront,
+                },
+            )
+            .add_level(
+                LevelInfo {
+                    level: Level::CourtHouseHall,
+                    name: "courthouse_hall",
+                    title: "Courthouse hall",
+                    music: "sounds/track/courthouse.ogg",
+                    default_spawn: "entrance",
+                    y_max: 359.0,
+                },
+                CourtHouseHallPlugin {
+                    state: Level::CourtHouseHall,
+                },
+            )
+            .add_level(
+                LevelInfo {
+                    level: Level::Court,
+                    name: "court",
+                    title: "Court",
+                    music: "sounds/track/courthouse.ogg",
+                    default_spawn: "entrance",
+                    y_max: 226.0,
+                },
+                CourtPlugin {
+                    state: Level::Court,
+                },
+            )
+            .init_state::<Level>()
+            .init_resource::<WoodenChestSprites>()
+            .init_asset::<TiledMap>()
+            .register_asset_loader(TiledMapLoader)
+            .add_systems(Update, level_sounds_handle)
+            .init_resource::<LevelTransition>()
+            .add_systems(Update, (tiled_level_spawns, level_transitions))
+            .add_systems(
+                Update,
+                (
+                    rest_handle,
+                    interact_with_container_handle,
+                    level_exits_handle,
+                    wooden_chest_states_draws.after(interact_with_container_handle),
+                )
+                    .run_if(in_state(GameState::Exploration)),
+            );
     }
 }
 
 fn level_sounds_handle(
-    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    level_registry: Res<LevelRegistry>,
     soundtrack_res: Res<Soundtrack>,
-    mut current_soundtrack_res: ResMut<CurrentSoundtrack>,
+    mut music_player: ResMut<MusicPlayer>,
     game_state: Res<State<GameState>>,
     current_level_state: Res<State<Level>>,
 ) {
     if current_level_state.is_changed() || game_state.is_changed() {
-        let sound = match game_state.get() {
-            GameState::MainMenu | GameState::DevSetting => {
-                (&soundtrack_res.menu, CurrentSoundtrackType::Menu)
-            }
-            GameState::Fighting => (&soundtrack_res.into_battle, CurrentSoundtrackType::Fighting),
+        let track = match game_state.get() {
+            GameState::MainMenu | GameState::DevSetting => soundtrack_res.get(Track::Menu),
+            // Fight music is chosen by the fight itself
+            GameState::Fighting => return,
             GameState::Dialog
+            | GameState::CatScene
             | GameState::Exploration
             | GameState::InventoryAndAbilities
-            | GameState::Character => {
-                let level = current_level_state.get();
-                let sound = match level {
-                    Level::None => &soundtrack_res.menu,
-                    Level::House => &soundtrack_res.house,
-                    Level::CourtHouseFront => &soundtrack_res.courthouse_front,
-                    Level::CourtHouseHall => &soundtrack_res.courthouse,
-                    Level::Court => &soundtrack_res.courthouse,
-                };
-                (sound, CurrentSoundtrackType::Exploration(level.clone()))
+            | GameState::Character
+            | GameState::Trading
+            | GameState::Journal => {
+                // Levels with the same music keep playing it without restart
+                match level_registry.get(current_level_state.get()) {
+                    Some(info) => asset_server.load(info.music),
+                    None => soundtrack_res.get(Track::Menu),
+                }
             }
-            GameState::CatScene => (&soundtrack_res.menu, CurrentSoundtrackType::Menu),
-
-            GameState::GameOver => (&soundtrack_res.menu, CurrentSoundtrackType::Menu),
+            GameState::GameOver => soundtrack_res.get(Track::Menu),
         };
-
-        if let Some(current) = &current_soundtrack_res.1 {
-            if current == &sound.1 {
-                return;
-            }
-        }
-
-        if let Some(entity) = current_soundtrack_res.0 {
-            commands.entity(entity).despawn();
-        }
-
-        let entity = commands
-            .spawn(AudioBundle {
-                source: sound.0.clone(),
-                settings: PlaybackSettings::LOOP,
-            })
-            .id();
-
-        current_soundtrack_res.0 = Some(entity);
-        current_soundtrack_res.1 = Some(sound.1.clone());
+        music_player.play(track);
     }
 }
-
-#[derive(PartialEq, Debug, Clone)]
-enum CurrentSoundtrackType {
-    Menu,
-    Exploration(Level),
-    Fighting,
-}
-
-#[derive(Resource, Default)]
-struct CurrentSoundtrack(Option<Entity>, Option<CurrentSoundtrackType>);
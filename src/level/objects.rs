@@ -1,30 +1,44 @@
 use crate::core::entities::Description;
-use crate::core::state_machines::FiniteLinearTransition;
+use crate::core::state_machines::Transition;
+use crate::input::InputAction;
 use crate::interaction::interactors::{
-    detect_active_interaction, ActiveInteractor, Container, ContainerState, InteractionArea,
-    InteractionSide, LimitedInteractor, PassiveInteractor,
+    detect_active_interaction, ActiveInteractor, Container, ContainerAction, ContainerState,
+    InteractionArea, InteractionSide, Lock, PassiveInteractor,
 };
 use crate::level::sprites::WoodenChestSprites;
 use crate::party::PartyStateStorage;
-use crate::rpg::ConsumableItem;
-use crate::sound::ChestSounds;
-use bevy::audio::{AudioBundle, PlaybackSettings};
+use crate::rpg::{CharacterStorage, Loot};
+use crate::sound::{AudioBus, AudioCommandsExt, ButtonSounds, ChestSounds};
+use crate::world_state::QuestEvent;
 use bevy::ecs::component::Component;
+use bevy::ecs::system::EntityCommands;
 use bevy::hierarchy::BuildChildren;
 use bevy::input::ButtonInput;
 use bevy::math::Vec3;
-use bevy::prelude::{Bundle, Changed, Commands, Entity, Handle, Image, KeyCode, Query, Res, ResMut, SpriteBundle, Transform, TransformBundle, With};
+use bevy::prelude::{
+    Bundle, Changed, Commands, Entity, EventWriter, Handle, Image, Query, Res, ResMut,
+    SpriteBundle, Transform, TransformBundle, With,
+};
 use bevy_rapier2d::dynamics::RigidBody;
 use bevy_rapier2d::geometry::Collider;
 
 #[derive(Component)]
 pub struct WoodenChest;
 
+// Container ids must be unique across all levels, see Container
+pub const HOUSE_WOODEN_CHEST_ID: usize = 1;
+pub const COURTHOUSE_FRONT_CHEST_ID: usize = 2;
+
 #[derive(Component)]
 pub struct LevelArm;
 
-pub fn spawn_object(
-    commands: &mut Commands,
+/// Place where party can rest, e.g. bed. Restores health and energy of all members.
+#[derive(Component)]
+pub struct RestPoint;
+
+/// Level marker is added by the caller, e.g. `spawn_object(..).insert(CourtLevel)`.
+pub fn spawn_object<'a>(
+    commands: &'a mut Commands,
     image: Handle<Image>,
     x: f32,
     y: f32,
@@ -32,9 +46,9 @@ pub fn spawn_object(
     half_x: f32,
     half_y: f32,
     collider_shift_y: f32,
-) {
-    commands
-        .spawn(RigidBody::Fixed)
+) -> EntityCommands<'a> {
+    let mut object = commands.spawn(RigidBody::Fixed);
+    object
         .insert(SpriteBundle {
             texture: image,
             transform: Transform {
@@ -52,6 +66,7 @@ pub fn spawn_object(
                     z,
                 )));
         });
+    object
 }
 
 pub fn spawn_container(
@@ -62,7 +77,9 @@ pub fn spawn_container(
     y: f32,
     z: f32,
     collider_shift_y: f32,
-    items: Vec<ConsumableItem>,
+    id: usize,
+    items: Vec<Loot>,
+    lock: Option<Lock>,
 ) {
     commands
         .spawn(RigidBody::Fixed)
@@ -90,43 +107,27 @@ pub fn spawn_container(
             area: InteractionArea::from_sizes(16.0, 11.0),
             side: InteractionSide::Bottom,
         })
-        .insert(LimitedInteractor)
         .insert(WoodenChest)
-        .insert(Container::<ConsumableItem> {
-            state: ContainerState::initial_state(),
-            items,
-        })
+        .insert(Container::create(id, items, lock))
         .insert(Description {
             text: "Closed chest".to_string(),
         });
 }
 
-pub fn wooden_chest_states_draws<T: Bundle + Clone>(
+pub fn wooden_chest_states_draws(
     mut commands: Commands,
-    keyboard: Res<ButtonInput<KeyCode>>,
     sprites: Res<WoodenChestSprites>,
-    chests: Query<(Entity, &Container<T>), Changed<Container<T>>>,
-    audio_res: Res<ChestSounds>,
+    chests: Query<(Entity, &Container), (With<WoodenChest>, Changed<Container>)>,
 ) {
-    if !(keyboard.pressed(KeyCode::KeyE) && keyboard.just_pressed(KeyCode::KeyE)) {
-        return;
-    }
     for (entity, container) in chests.iter() {
         let new_sprite = match container.state {
-            ContainerState::Closed => sprites.closed.clone(),
-            ContainerState::Full => {
-                commands.spawn(AudioBundle {
-                    source: audio_res.opened.clone(),
-                    settings: PlaybackSettings::ONCE,
-                });
-                sprites.full.clone()
-            }
-            ContainerState::Empty => {
-                commands.spawn(AudioBundle {
-                    source: audio_res.items_picked.clone(),
-                    settings: PlaybackSettings::ONCE,
-                });
-                sprites.empty.clone()
+            ContainerState::Locked | ContainerState::Closed => sprites.closed.clone(),
+            ContainerState::Opened => {
+                if container.items.is_empty() {
+                    sprites.empty.clone()
+                } else {
+                    sprites.full.clone()
+                }
             }
         };
         commands.entity(entity).insert(new_sprite);
@@ -136,29 +137,76 @@ pub fn wooden_chest_states_draws<T: Bundle + Clone>(
 pub fn interact_with_container_handle(
     mut commands: Commands,
     mut party_state_storage: ResMut<PartyStateStorage>,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    character_storage: Res<CharacterStorage>,
+    actions: Res<ButtonInput<InputAction>>,
     active: Query<(&ActiveInteractor, &Transform)>,
-    mut interactors: Query<
-        (
-            Entity,
-            &PassiveInteractor,
-            &Transform,
-            &mut Container<ConsumableItem>,
-        ),
-        With<LimitedInteractor>,
-    >,
+    mut interactors: Query<(&PassiveInteractor, &Transform, &mut Container)>,
+    chest_sounds: Res<ChestSounds>,
+    button_sounds: Res<ButtonSounds>,
+    mut quest_events: EventWriter<QuestEvent>,
 ) {
-    if !(keyboard.pressed(KeyCode::KeyE) && keyboard.just_pressed(KeyCode::KeyE)) {
+    if !(actions.pressed(InputAction::Interact) && actions.just_pressed(InputAction::Interact)) {
         return;
     }
-    for (entity, interactor, transform, mut container) in interactors.iter_mut() {
+    for (interactor, transform, mut container) in interactors.iter_mut() {
         let is_interacting = detect_active_interaction(&active, (interactor, transform));
-        if is_interacting {
-            container.state = container.state.transit();
-            if container.state.is_finished() {
-                party_state_storage.add_consumables(container.items.clone());
-                commands.entity(entity).remove::<LimitedInteractor>();
+        if !is_interacting {
+            continue;
+        }
+        let sound = match container.state {
+            ContainerState::Locked => {
+                let leader = character_storage.get_class_by_id(0);
+                let can_unlock = container
+                    .lock
+                    .as_ref()
+                    .map(|lock| lock.can_unlock(&party_state_storage.get_quest_items(), leader))
+                    .unwrap_or(true);
+                if can_unlock {
+                    container.state = container
+                        .state
+                        .transit(&ContainerAction::Unlock)
+                        .transit(&ContainerAction::Open);
+                    chest_sounds.opened.clone()
+                } else {
+                    button_sounds.negative_click.clone()
+                }
+            }
+            ContainerState::Closed => {
+                container.state = container.state.transit(&ContainerAction::Open);
+                chest_sounds.opened.clone()
+            }
+            ContainerState::Opened => {
+                if container.items.is_empty() {
+                    container.state = container.state.transit(&ContainerAction::Close);
+                    chest_sounds.opened.clone()
+                } else {
+                    let items = std::mem::take(&mut container.items);
+                    for item in &items {
+                        if let Loot::Quest(quest_item) = item {
+                            quest_events.send(QuestEvent::ItemPicked(quest_item.clone()));
+                        }
+                    }
+                    party_state_storage.add_loot(items);
+                    chest_sounds.items_picked.clone()
+                }
             }
+        };
+        commands.play_sound(AudioBus::Sfx, sound);
+    }
+}
+
+pub fn rest_handle(
+    mut party_state_storage: ResMut<PartyStateStorage>,
+    actions: Res<ButtonInput<InputAction>>,
+    active: Query<(&ActiveInteractor, &Transform)>,
+    rest_points: Query<(&PassiveInteractor, &Transform), With<RestPoint>>,
+) {
+    if !(actions.pressed(InputAction::Interact) && actions.just_pressed(InputAction::Interact)) {
+        return;
+    }
+    for (interactor, transform) in rest_points.iter() {
+        if detect_active_interaction(&active, (interactor, transform)) {
+            party_state_storage.restore_party();
         }
     }
 }
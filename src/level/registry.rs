@@ -0,0 +1,87 @@
+use bevy::app::{App, Plugin};
+use bevy::prelude::Resource;
+
+use crate::level::states::Level;
+
+/// Everything other systems need to know about a level.
+#[derive(Clone, Debug)]
+pub struct LevelInfo {
+    pub level: Level,
+    /// Used in level maps, e.g. as door target.
+    pub name: &'static str,
+    pub title: &'static str,
+    pub music: &'static str,
+    pub default_spawn: &'static str,
+    pub y_max: f32,
+}
+
+#[derive(Resource, Default)]
+pub struct LevelRegistry {
+    levels: Vec<LevelInfo>,
+}
+
+impl LevelRegistry {
+    pub fn register(&mut self, info: LevelInfo) {
+        self.levels
+            .retain(|registered| registered.level != info.level);
+        self.levels.push(info);
+    }
+
+    pub fn get(&self, level: &Level) -> Option<&LevelInfo> {
+        self.levels.iter().find(|info| info.level == *level)
+    }
+
+    pub fn get_by_name(&self, name: &str) -> Option<&LevelInfo> {
+        self.levels.iter().find(|info| info.name == name)
+    }
+
+    pub fn get_all(&self) -> &[LevelInfo] {
+        &self.levels
+    }
+
+    pub fn y_max(&self, level: &Level) -> f32 {
+        self.get(level)
+            .map(|info| info.y_max)
+            .expect("Level is not registered")
+    }
+}
+
+pub trait AppLevelExt {
+    /// Adds level plugin and registers the level, so it can be found by other systems.
+    fn add_level(&mut self, info: LevelInfo, plugin: impl Plugin) -> &mut Self;
+}
+
+impl AppLevelExt for App {
+    fn add_level(&mut self, info: LevelInfo, plugin: impl Plugin) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_insert_with(LevelRegistry::default)
+            .register(info);
+        self.add_plugins(plugin)
+    }
+}
+
+#[test]
+fn level_registry_test() {
+    let info = LevelInfo {
+        level: Level::House,
+        name: "house",
+        title: "House",
+        music: "sounds/track/house.ogg",
+        default_spawn: "start",
+        y_max: 192.0,
+    };
+    let mut registry = LevelRegistry::default();
+    registry.register(info.clone());
+    registry.register(LevelInfo {
+        y_max: 200.0,
+        ..info
+    });
+
+    assert_eq!(registry.get_all().len(), 1);
+    assert_eq!(registry.y_max(&Level::House), 200.0);
+    assert_eq!(
+        registry.get_by_name("house").map(|info| info.level),
+        Some(Level::House)
+    );
+    assert!(registry.get(&Level::Court).is_none());
+}
@@ -0,0 +1,102 @@
+use crate::core::states::GameState;
+use bevy::input::ButtonInput;
+use bevy::log::warn;
+use bevy::prelude::{Commands, Component, NextState, Query, Res, ResMut, Transform};
+
+use crate::input::InputAction;
+use crate::interaction::interactors::{
+    detect_active_interaction, ActiveInteractor, PassiveInteractor,
+};
+use crate::rpg::ConsumableItem;
+use crate::trade::{Goods, Shop, ShopId};
+use serde::Deserialize;
+
+pub trait HasShopId {
+    fn shop_id(&self) -> usize;
+}
+
+pub fn trade_starts<T: HasShopId + Component>(
+    mut commands: Commands,
+    actions: Res<ButtonInput<InputAction>>,
+    active: Query<(&ActiveInteractor, &Transform)>,
+    interactors: Query<(&PassiveInteractor, &Transform, &T)>,
+    mut shop_id_query: Query<&mut ShopId>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+) {
+    if !(actions.pressed(InputAction::Interact) && actions.just_pressed(InputAction::Interact)) {
+        return;
+    }
+    for (interactor, transform, has_shop) in interactors.iter() {
+        let is_interacting = detect_active_interaction(&active, (interactor, transform));
+        if is_interacting {
+            match shop_id_query.get_single_mut() {
+                Ok(mut shop_id) => shop_id.0 = has_shop.shop_id(),
+                Err(_) => {
+                    commands.spawn(ShopId(has_shop.shop_id()));
+                }
+            }
+            next_game_state.set(GameState::Trading);
+        }
+    }
+}
+
+pub const PIE_SELLER_SHOP: usize = 1;
+
+#[derive(Deserialize)]
+struct ShopData {
+    id: usize,
+    name: String,
+    goods: Vec<GoodsData>,
+}
+
+/// Item is named like in maps loot, price defaults to the item base price.
+#[derive(Deserialize)]
+struct GoodsData {
+    item: String,
+    price: Option<i32>,
+}
+
+pub fn shops() -> Vec<Shop> {
+    let data: Vec<ShopData> =
+        match serde_json::from_str(include_str!("../../assets/shops/shops.json")) {
+            Ok(data) => data,
+            Err(error) => {
+                warn!("Could not parse shops: {error}");
+                vec![]
+            }
+        };
+    data.into_iter().map(shop_from_data).collect()
+}
+
+fn shop_from_data(data: ShopData) -> Shop {
+    let goods = data
+        .goods
+        .into_iter()
+        .filter_map(|goods| {
+            let Some(item) = ConsumableItem::from_name(&goods.item) else {
+                warn!("Unknown item {} in shop {}", goods.item, data.id);
+                return None;
+            };
+            let price = goods.price.unwrap_or(item.base_price());
+            Some(Goods { item, price })
+        })
+        .collect();
+    Shop {
+        id: data.id,
+        name: data.name,
+        goods,
+    }
+}
+
+#[test]
+fn shops_data_test() {
+    let shops = shops();
+    let pie_seller = shops
+        .iter()
+        .find(|shop| shop.id == PIE_SELLER_SHOP)
+        .unwrap();
+    assert_eq!(pie_seller.name, "shop.aunt_valya");
+    assert_eq!(pie_seller.goods.len(), 4);
+    assert_eq!(pie_seller.goods[0].price, 10);
+    assert_eq!(pie_seller.goods[3].price, 30);
+}
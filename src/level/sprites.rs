@@ -1,3 +1,5 @@
+use bevy::asset::AssetServer;
+use bevy::prelude::{FromWorld, World};
 use bevy::{asset::Handle, ecs::system::Resource, render::texture::Image};
 
 #[derive(Resource)]
@@ -6,3 +8,14 @@ pub struct WoodenChestSprites {
     pub full: Handle<Image>,
     pub empty: Handle<Image>,
 }
+
+impl FromWorld for WoodenChestSprites {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        WoodenChestSprites {
+            closed: asset_server.load("chest/wooden.png"),
+            full: asset_server.load("chest/wooden_full.png"),
+            empty: asset_server.load("chest/wooden_empty.png"),
+        }
+    }
+}
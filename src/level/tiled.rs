@@ -0,0 +1,632 @@
+use std::fmt::{Display, Formatter};
+use std::path::{Component as PathComponent, Path, PathBuf};
+
+use bevy::asset::io::Reader;
+use bevy::asset::{Asset, AssetLoader, AsyncReadExt, LoadContext};
+use bevy::hierarchy::{BuildChildren, ChildBuilder};
+use bevy::log::warn;
+use bevy::math::{UVec2, Vec3};
+use bevy::prelude::{
+    Assets, Commands, Component, Entity, Handle, Image, Query, Res, SpatialBundle, SpriteBundle,
+    TextureAtlas, TextureAtlasLayout, Transform, TransformBundle, Without,
+};
+use bevy::reflect::TypePath;
+use bevy::utils::HashMap;
+use bevy_rapier2d::dynamics::RigidBody;
+use bevy_rapier2d::geometry::Collider;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::core::entities::{Description, LevelYMax};
+use crate::core::z_index::{calculate_z, FLOOR_Z, ON_WALL_OBJECT_Z, WALL_Z};
+use crate::interaction::interactors::{
+    Container, InteractionArea, InteractionSide, Lock, PassiveInteractor,
+};
+use crate::level::objects::WoodenChest;
+use crate::level::registry::LevelRegistry;
+use crate::level::sprites::WoodenChestSprites;
+use crate::level::states::Level;
+use crate::level::transitions::{LevelExit, SpawnPoint};
+use crate::rpg::{ConsumableItem, EquipmentItem, Loot, QuestItem};
+
+/// Level map authored in Tiled editor and exported as JSON (`.tmj`).
+///
+/// Tile layers become sprites. Objects of object layers are spawned by their class:
+/// tile objects become sprites with optional collider (`collider_half_x`, `collider_half_y`,
+/// `collider_shift_y` properties), `collider`, `interactor`, `container`, `door` (`target` level,
+/// `spawn` point and optional `key` properties), `spawn` and `npc` rectangles become corresponding
+/// components.
+/// Map property `y_max` is used as `LevelYMax`, the registered level value is used without it.
+#[derive(Asset, TypePath, Debug)]
+pub struct TiledMap {
+    pub data: TiledMapData,
+    pub tiles: HashMap<u32, TiledTile>,
+}
+
+#[derive(Clone, Debug)]
+pub enum TiledTile {
+    Image(Handle<Image>),
+    Atlas {
+        image: Handle<Image>,
+        layout: Handle<TextureAtlasLayout>,
+        index: usize,
+    },
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TiledMapData {
+    pub width: u32,
+    pub height: u32,
+    #[serde(rename = "tilewidth")]
+    pub tile_width: u32,
+    #[serde(rename = "tileheight")]
+    pub tile_height: u32,
+    #[serde(default)]
+    pub layers: Vec<TiledLayer>,
+    #[serde(default)]
+    pub tilesets: Vec<TiledTileset>,
+    #[serde(default)]
+    pub properties: Vec<TiledProperty>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum TiledLayer {
+    #[serde(rename = "tilelayer")]
+    Tiles {
+        width: u32,
+        data: Vec<u32>,
+        #[serde(default)]
+        properties: Vec<TiledProperty>,
+    },
+    #[serde(rename = "objectgroup")]
+    Objects { objects: Vec<TiledObject> },
+    #[serde(other)]
+    Unsupported,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TiledTileset {
+    #[serde(rename = "firstgid")]
+    pub first_gid: u32,
+    pub image: Option<String>,
+    #[serde(default, rename = "tilewidth")]
+    pub tile_width: u32,
+    #[serde(default, rename = "tileheight")]
+    pub tile_height: u32,
+    #[serde(default)]
+    pub columns: u32,
+    #[serde(default, rename = "tilecount")]
+    pub tile_count: u32,
+    #[serde(default)]
+    pub tiles: Vec<TiledTilesetTile>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TiledTilesetTile {
+    pub id: u32,
+    pub image: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TiledObject {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, rename = "type", alias = "class")]
+    pub class: String,
+    pub x: f32,
+    pub y: f32,
+    #[serde(default)]
+    pub width: f32,
+    #[serde(default)]
+    pub height: f32,
+    pub gid: Option<u32>,
+    #[serde(default)]
+    pub properties: Vec<TiledProperty>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TiledProperty {
+    pub name: String,
+    pub value: Value,
+}
+
+trait PropertiesExt {
+    fn get_f32(&self, name: &str) -> Option<f32>;
+    fn get_str(&self, name: &str) -> Option<&str>;
+}
+
+impl PropertiesExt for [TiledProperty] {
+    fn get_f32(&self, name: &str) -> Option<f32> {
+        self.iter()
+            .find(|property| property.name == name)
+            .and_then(|property| property.value.as_f64())
+            .map(|value| value as f32)
+    }
+
+    fn get_str(&self, name: &str) -> Option<&str> {
+        self.iter()
+            .find(|property| property.name == name)
+            .and_then(|property| property.value.as_str())
+    }
+}
+
+// Flip flags are stored in the highest bits of gid
+const GID_MASK: u32 = 0x1FFFFFFF;
+
+#[derive(Default)]
+pub struct TiledMapLoader;
+
+#[derive(Debug)]
+pub enum TiledMapLoaderError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+}
+
+impl Display for TiledMapLoaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TiledMapLoaderError::Io(error) => write!(f, "Could not read map: {error}"),
+            TiledMapLoaderError::Json(error) => write!(f, "Could not parse map: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for TiledMapLoaderError {}
+
+impl From<std::io::Error> for TiledMapLoaderError {
+    fn from(value: std::io::Error) -> Self {
+        TiledMapLoaderError::Io(value)
+    }
+}
+
+impl From<serde_json::Error> for TiledMapLoaderError {
+    fn from(value: serde_json::Error) -> Self {
+        TiledMapLoaderError::Json(value)
+    }
+}
+
+impl AssetLoader for TiledMapLoader {
+    type Asset = TiledMap;
+    type Settings = ();
+    type Error = TiledMapLoaderError;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a Self::Settings,
+        load_context: &'a mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let data: TiledMapData = serde_json::from_slice(&bytes)?;
+
+        // Images paths are relative to the map file
+        let directory = load_context
+            .path()
+            .parent()
+            .unwrap_or(Path::new(""))
+            .to_path_buf();
+        let mut tiles = HashMap::new();
+        for (idx, tileset) in data.tilesets.iter().enumerate() {
+            match &tileset.image {
+                Some(image) => {
+                    let image = load_context.load(resolve_path(&directory, image));
+                    let layout = TextureAtlasLayout::from_grid(
+                        UVec2::new(tileset.tile_width, tileset.tile_height),
+                        tileset.columns,
+                        tileset.tile_count / tileset.columns.max(1),
+                        None,
+                        None,
+                    );
+                    let layout = load_context.add_labeled_asset(format!("layout_{idx}"), layout);
+                    for index in 0..tileset.tile_count {
+                        tiles.insert(
+                            tileset.first_gid + index,
+                            TiledTile::Atlas {
+                                image: image.clone(),
+                                layout: layout.clone(),
+                                index: index as usize,
+                            },
+                        );
+                    }
+                }
+                None => {
+                    for tile in tileset.tiles.iter() {
+                        if let Some(image) = &tile.image {
+                            let image = load_context.load(resolve_path(&directory, image));
+                            tiles.insert(tileset.first_gid + tile.id, TiledTile::Image(image));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(TiledMap { data, tiles })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tmj"]
+    }
+}
+
+// Asset paths are not normalized, so "../" would give a different handle for the same image
+fn resolve_path(directory: &Path, path: &str) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in directory.join(path).components() {
+        match component {
+            PathComponent::ParentDir => {
+                result.pop();
+            }
+            PathComponent::CurDir => {}
+            component => result.push(component),
+        }
+    }
+    result
+}
+
+/// Add it to level entity, map content is spawned as its children when map is loaded.
+#[derive(Component)]
+pub struct TiledLevel {
+    pub level: Level,
+    pub map: Handle<TiledMap>,
+}
+
+#[derive(Component)]
+pub struct TiledLevelSpawned;
+
+pub fn tiled_level_spawns(
+    mut commands: Commands,
+    maps: Res<Assets<TiledMap>>,
+    chest_sprites: Res<WoodenChestSprites>,
+    level_registry: Res<LevelRegistry>,
+    levels: Query<(Entity, &TiledLevel), Without<TiledLevelSpawned>>,
+) {
+    for (entity, level) in levels.iter() {
+        let Some(map) = maps.get(&level.map) else {
+            continue;
+        };
+        let map_width = (map.data.width * map.data.tile_width) as f32;
+        let map_height = (map.data.height * map.data.tile_height) as f32;
+        let y_max = map
+            .data
+            .properties
+            .get_f32("y_max")
+            .or_else(|| level_registry.get(&level.level).map(|info| info.y_max));
+        let y_max = LevelYMax::create(y_max.unwrap_or(map_height / 2.0));
+        let to_world = |x: f32, y: f32| (x - map_width / 2.0, map_height / 2.0 - y);
+
+        commands
+            .entity(entity)
+            .insert((TiledLevelSpawned, SpatialBundle::default()))
+            .with_children(|parent| {
+                parent.spawn(y_max);
+                for layer in map.data.layers.iter() {
+                    match layer {
+                        TiledLayer::Tiles {
+                            width,
+                            data,
+                            properties,
+                            ..
+                        } => {
+                            let z = layer_z(properties.get_str("z"), None, 0.0, y_max);
+                            for (idx, gid) in data.iter().enumerate() {
+                                let Some(tile) = map.tiles.get(&(gid & GID_MASK)) else {
+                                    continue;
+                                };
+                                let column = idx as u32 % width;
+                                let row = idx as u32 / width;
+                                let (x, y) = to_world(
+                                    ((column as f32) + 0.5) * map.data.tile_width as f32,
+                                    ((row as f32) + 0.5) * map.data.tile_height as f32,
+                                );
+                                spawn_tile(parent, tile, Vec3::new(x, y, z));
+                            }
+                        }
+                        TiledLayer::Objects { objects, .. } => {
+                            for object in objects.iter() {
+                                spawn_map_object(
+                                    parent,
+                                    map,
+                                    object,
+                                    &to_world,
+                                    y_max,
+                                    &chest_sprites,
+                                    &level_registry,
+                                );
+                            }
+                        }
+                        TiledLayer::Unsupported => {}
+                    }
+                }
+            });
+    }
+}
+
+fn spawn_map_object(
+    parent: &mut ChildBuilder,
+    map: &TiledMap,
+    object: &TiledObject,
+    to_world: &impl Fn(f32, f32) -> (f32, f32),
+    y_max: LevelYMax,
+    chest_sprites: &WoodenChestSprites,
+    level_registry: &LevelRegistry,
+) {
+    let properties = &object.properties;
+    // Tile objects are anchored at bottom left corner, others at top left corner
+    let (x, y) = match object.gid {
+        Some(_) => to_world(
+            object.x + object.width / 2.0,
+            object.y - object.height / 2.0,
+        ),
+        None => to_world(
+            object.x + object.width / 2.0,
+            object.y + object.height / 2.0,
+        ),
+    };
+    let half_x = object.width / 2.0;
+    let half_y = object.height / 2.0;
+
+    if let Some(gid) = object.gid {
+        let Some(tile) = map.tiles.get(&(gid & GID_MASK)) else {
+            return;
+        };
+        let z = layer_z(properties.get_str("z"), properties.get_f32("z_y"), y, y_max);
+        let mut entity = spawn_tile(parent, tile, Vec3::new(x, y, z));
+        let collider_half_x = properties.get_f32("collider_half_x");
+        let collider_half_y = properties.get_f32("collider_half_y");
+        if let (Some(half_x), Some(half_y)) = (collider_half_x, collider_half_y) {
+            let shift_y = properties.get_f32("collider_shift_y").unwrap_or(0.0);
+            entity.with_children(|children| {
+                children
+                    .spawn(Collider::cuboid(half_x, half_y))
+                    .insert(TransformBundle::from(Transform::from_xyz(0.0, shift_y, z)));
+            });
+        }
+        if let Some(text) = properties.get_str("description") {
+            entity
+                .insert(PassiveInteractor {
+                    area: InteractionArea::from_sizes(half_x, half_y),
+                    side: interaction_side(properties.get_str("side")),
+                })
+                .insert(Description {
+                    text: text.to_string(),
+                });
+        }
+        return;
+    }
+
+    let transform = TransformBundle::from(Transform::from_xyz(x, y, calculate_z(y, y_max.value)));
+    match object.class.as_str() {
+        "collider" => {
+            parent
+                .spawn(RigidBody::Fixed)
+                .insert(transform)
+                .insert(Collider::cuboid(half_x, half_y));
+        }
+        "interactor" => {
+            parent
+                .spawn(transform)
+                .insert(PassiveInteractor {
+                    area: InteractionArea::from_sizes(half_x, half_y),
+                    side: interaction_side(properties.get_str("side")),
+                })
+                .insert(Description {
+                    text: properties.get_str("description").unwrap_or("").to_string(),
+                });
+        }
+        "container" => {
+            let id = properties.get_f32("id").unwrap_or(0.0) as usize;
+            let items = parse_loot(properties.get_str("loot").unwrap_or(""));
+            let lock = parse_lock(properties);
+            parent
+                .spawn(RigidBody::Fixed)
+                .insert(SpriteBundle {
+                    texture: chest_sprites.closed.clone(),
+                    transform: transform.local,
+                    ..Default::default()
+                })
+                .with_children(|children| {
+                    children
+                        .spawn(Collider::cuboid(16.0, 11.0))
+                        .insert(TransformBundle::from(Transform::from_xyz(0.0, -2.0, 0.0)));
+                })
+                .insert(PassiveInteractor {
+                    area: InteractionArea::from_sizes(16.0, 11.0),
+                    side: InteractionSide::Bottom,
+                })
+                .insert(WoodenChest)
+                .insert(Container::create(id, items, lock))
+                .insert(Description {
+                    text: "Closed chest".to_string(),
+                });
+        }
+        "door" => {
+            let target = properties
+                .get_str("target")
+                .and_then(|name| level_registry.get_by_name(name));
+            let Some(target) = target.map(|info| info.level) else {
+                warn!("Door {} has unknown target level", object.name);
+                return;
+            };
+            let mut exit = LevelExit::create(target, properties.get_str("spawn").unwrap_or(""));
+            exit.key = parse_quest_item(properties.get_str("key"));
+            parent
+                .spawn(transform)
+                .insert(PassiveInteractor {
+                    area: InteractionArea::from_sizes(half_x, half_y),
+                    side: interaction_side(properties.get_str("side")),
+                })
+                .insert(exit);
+        }
+        "spawn" | "npc" => {
+            parent
+                .spawn(transform)
+                .insert(SpawnPoint::create(&object.name));
+        }
+        _ => {}
+    }
+}
+
+fn spawn_tile<'a>(
+    parent: &'a mut ChildBuilder,
+    tile: &TiledTile,
+    translation: Vec3,
+) -> bevy::ecs::system::EntityCommands<'a> {
+    let transform = Transform::from_translation(translation);
+    match tile {
+        TiledTile::Image(image) => parent.spawn((
+            RigidBody::Fixed,
+            SpriteBundle {
+                texture: image.clone(),
+                transform,
+                ..Default::default()
+            },
+        )),
+        TiledTile::Atlas {
+            image,
+            layout,
+            index,
+        } => parent.spawn((
+            RigidBody::Fixed,
+            SpriteBundle {
+                texture: image.clone(),
+                transform,
+                ..Default::default()
+            },
+            TextureAtlas {
+                layout: layout.clone(),
+                index: *index,
+            },
+        )),
+    }
+}
+
+// z is either one of the fixed layers or calculated from position, like for any other object
+fn layer_z(z: Option<&str>, z_y: Option<f32>, y: f32, y_max: LevelYMax) -> f32 {
+    match z {
+        Some("floor") => FLOOR_Z,
+        Some("wall") => WALL_Z,
+        Some("on_wall") => ON_WALL_OBJECT_Z,
+        _ => calculate_z(z_y.unwrap_or(y), y_max.value),
+    }
+}
+
+fn interaction_side(side: Option<&str>) -> InteractionSide {
+    match side {
+        Some("top") => InteractionSide::Top,
+        Some("left") => InteractionSide::Left,
+        Some("right") => InteractionSide::Right,
+        _ => InteractionSide::Bottom,
+    }
+}
+
+// Loot is written as comma separated list, e.g. "dumplings, money:15, chest_key"
+pub fn parse_loot(value: &str) -> Vec<Loot> {
+    value
+        .split(',')
+        .map(|item| item.trim())
+        .filter_map(|item| {
+            let (name, amount) = match item.split_once(':') {
+                Some((name, amount)) => (name, amount.trim().parse::<i32>().ok()),
+                None => (item, None),
+            };
+            if let Some(item) = ConsumableItem::from_name(name) {
+                return Some(Loot::Consumable(item));
+            }
+            let loot = match name {
+                "money" => Loot::Money(amount?),
+                "brass_knuckles" => Loot::Equipment(EquipmentItem::BrassKnuckles {
+                    attack: amount.unwrap_or(1),
+                }),
+                "leather_jacket" => Loot::Equipment(EquipmentItem::LeatherJacket {
+                    armor: amount.unwrap_or(1),
+                }),
+                _ => Loot::Quest(parse_quest_item(Some(name))?),
+            };
+            Some(loot)
+        })
+        .collect()
+}
+
+fn parse_quest_item(value: Option<&str>) -> Option<QuestItem> {
+    match value {
+        Some("chest_key") => Some(QuestItem::ChestKey),
+        Some("feather") => Some(QuestItem::Feather),
+        _ => None,
+    }
+}
+
+fn parse_lock(properties: &[TiledProperty]) -> Option<Lock> {
+    let key = parse_quest_item(properties.get_str("lock_key"));
+    let strength = properties
+        .get_f32("lock_strength")
+        .map(|value| value as i32);
+    let agility = properties.get_f32("lock_agility").map(|value| value as i32);
+    if key.is_none() && strength.is_none() && agility.is_none() {
+        return None;
+    }
+    Some(Lock {
+        key,
+        strength,
+        agility,
+    })
+}
+
+#[test]
+fn parse_loot_test() {
+    let loot = parse_loot("dumplings, money:15, leather_jacket:2, chest_key, unknown, money");
+
+    assert_eq!(loot.len(), 4);
+    assert!(matches!(loot[0], Loot::Consumable(_)));
+    assert!(matches!(loot[1], Loot::Money(15)));
+    assert!(matches!(
+        loot[2],
+        Loot::Equipment(EquipmentItem::LeatherJacket { armor: 2 })
+    ));
+    assert!(matches!(loot[3], Loot::Quest(QuestItem::ChestKey)));
+    assert!(parse_loot("").is_empty());
+}
+
+#[test]
+fn resolve_path_test() {
+    let directory = Path::new("court");
+
+    assert_eq!(
+        resolve_path(directory, "floor.png"),
+        PathBuf::from("court/floor.png")
+    );
+    assert_eq!(
+        resolve_path(directory, "../courthouse_hall/doors.png"),
+        PathBuf::from("courthouse_hall/doors.png")
+    );
+}
+
+#[test]
+fn tiled_map_parse_test() {
+    let json = r#"{
+        "width": 2, "height": 2, "tilewidth": 16, "tileheight": 16,
+        "properties": [{"name": "y_max", "type": "float", "value": 20.0}],
+        "tilesets": [{"firstgid": 1, "tiles": [{"id": 0, "image": "floor.png"}]}],
+        "layers": [
+            {"type": "tilelayer", "name": "floor", "width": 2, "height": 2, "data": [1, 0, 0, 1]},
+            {"type": "objectgroup", "name": "objects", "objects": [
+                {"id": 1, "name": "entrance", "type": "spawn", "x": 8, "y": 8, "width": 0, "height": 0},
+                {"id": 2, "name": "", "class": "collider", "x": 0, "y": 0, "width": 32, "height": 4}
+            ]},
+            {"type": "imagelayer", "name": "background"}
+        ]
+    }"#;
+    let map: TiledMapData = serde_json::from_str(json).unwrap();
+
+    assert_eq!(map.properties.get_f32("y_max"), Some(20.0));
+    assert_eq!(map.layers.len(), 3);
+    match &map.layers[1] {
+        TiledLayer::Objects { objects, .. } => {
+            assert_eq!(objects[0].class, "spawn");
+            assert_eq!(objects[1].class, "collider");
+        }
+        _ => panic!("Object layer expected"),
+    }
+    assert!(matches!(map.layers[2], TiledLayer::Unsupported));
+}
@@ -0,0 +1,271 @@
+use bevy::color::{Alpha, Color};
+use bevy::hierarchy::DespawnRecursiveExt;
+use bevy::input::ButtonInput;
+use bevy::log::warn;
+use bevy::prelude::BackgroundColor;
+use bevy::prelude::Commands;
+use bevy::prelude::Component;
+use bevy::prelude::Entity;
+use bevy::prelude::NextState;
+use bevy::prelude::PositionType;
+use bevy::prelude::Query;
+use bevy::prelude::Res;
+use bevy::prelude::ResMut;
+use bevy::prelude::Resource;
+use bevy::prelude::State;
+use bevy::prelude::States;
+use bevy::prelude::Transform;
+use bevy::prelude::Val;
+use bevy::prelude::With;
+use bevy::time::{Time, Timer, TimerMode};
+use sickle_ui::prelude::SetBackgroundColorExt;
+use sickle_ui::prelude::SetHeightExt;
+use sickle_ui::prelude::SetPositionTypeExt;
+use sickle_ui::prelude::SetWidthExt;
+use sickle_ui::prelude::UiBuilderExt;
+use sickle_ui::prelude::UiColumnExt;
+use sickle_ui::prelude::UiRoot;
+
+use crate::input::InputAction;
+use crate::interaction::interactors::{
+    detect_active_interaction, ActiveInteractor, PassiveInteractor,
+};
+use crate::level::states::Level;
+use crate::party::PartyStateStorage;
+use crate::player::entities::PlayerPosition;
+use crate::rpg::QuestItem;
+use crate::sound::{AudioBus, AudioCommandsExt, ButtonSounds};
+
+const FADE_SECONDS: f32 = 0.4;
+// Tiled maps are loaded asynchronously, so spawn point can appear a bit later than level state changes
+const SPAWN_WAITING_SECONDS: f32 = 2.0;
+
+/// Passage to `spawn` point of `target` level.
+/// Closed exit is not passed, `key` item is required if it is set.
+#[derive(Component, Clone, Debug)]
+pub struct LevelExit {
+    pub target: Level,
+    pub spawn: String,
+    pub key: Option<QuestItem>,
+    pub is_open: bool,
+}
+
+impl LevelExit {
+    pub fn create(target: Level, spawn: &str) -> Self {
+        LevelExit {
+            target,
+            spawn: spawn.to_string(),
+            key: None,
+            is_open: true,
+        }
+    }
+}
+
+/// Exit is open only when quest state `S` is `state`.
+/// `level_exit_conditions::<S>` must be registered for it.
+#[derive(Component)]
+pub struct LevelExitCondition<S: States> {
+    pub state: S,
+}
+
+/// Named point of the level, where player appears after level transition.
+#[derive(Component, Debug)]
+pub struct SpawnPoint {
+    pub name: String,
+}
+
+impl SpawnPoint {
+    pub fn create(name: &str) -> Self {
+        SpawnPoint {
+            name: name.to_string(),
+        }
+    }
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct LevelTransition {
+    phase: TransitionPhase,
+}
+
+impl LevelTransition {
+    pub fn start(&mut self, target: Level, spawn: String) {
+        self.phase = TransitionPhase::FadeOut {
+            target,
+            spawn,
+            timer: Timer::from_seconds(FADE_SECONDS, TimerMode::Once),
+        };
+    }
+
+    pub fn is_active(&self) -> bool {
+        !matches!(self.phase, TransitionPhase::None)
+    }
+}
+
+#[derive(Default, Debug)]
+enum TransitionPhase {
+    #[default]
+    None,
+    FadeOut {
+        target: Level,
+        spawn: String,
+        timer: Timer,
+    },
+    WaitSpawn {
+        spawn: String,
+        timer: Timer,
+    },
+    FadeIn {
+        timer: Timer,
+    },
+}
+
+#[derive(Component)]
+pub struct TransitionFade;
+
+pub fn level_exit_conditions<S: States>(
+    state: Res<State<S>>,
+    mut exits: Query<(&mut LevelExit, &LevelExitCondition<S>)>,
+) {
+    for (mut exit, condition) in exits.iter_mut() {
+        let is_open = condition.state == *state.get();
+        if exit.is_open != is_open {
+            exit.is_open = is_open;
+        }
+    }
+}
+
+pub fn level_exits_handle(
+    mut commands: Commands,
+    actions: Res<ButtonInput<InputAction>>,
+    party_state_storage: Res<PartyStateStorage>,
+    button_sounds: Res<ButtonSounds>,
+    mut transition: ResMut<LevelTransition>,
+    active: Query<(&ActiveInteractor, &Transform)>,
+    exits: Query<(&PassiveInteractor, &Transform, &LevelExit)>,
+) {
+    if !(actions.pressed(InputAction::Interact) && actions.just_pressed(InputAction::Interact)) {
+        return;
+    }
+    if transition.is_active() {
+        return;
+    }
+
+    for (interactor, transform, exit) in exits.iter() {
+        let is_interacting = detect_active_interaction(&active, (interactor, transform));
+        if !is_interacting {
+            continue;
+        }
+        let has_key = match &exit.key {
+            Some(key) => party_state_storage.get_quest_items().contains(key),
+            None => true,
+        };
+        if exit.is_open && has_key {
+            transition.start(exit.target, exit.spawn.clone());
+            spawn_fade(&mut commands);
+        } else {
+            commands.play_sound(AudioBus::Ui, button_sounds.negative_click.clone());
+        }
+        return;
+    }
+}
+
+pub fn level_transitions(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut transition: ResMut<LevelTransition>,
+    mut next_level_state: ResMut<NextState<Level>>,
+    spawn_points: Query<(&SpawnPoint, &Transform)>,
+    mut player_position: Query<&mut PlayerPosition>,
+    mut fades: Query<(Entity, &mut BackgroundColor), With<TransitionFade>>,
+) {
+    let next_phase = match &mut transition.phase {
+        TransitionPhase::None => return,
+        TransitionPhase::FadeOut {
+            target,
+            spawn,
+            timer,
+        } => {
+            timer.tick(time.delta());
+            set_fade_alpha(&mut fades, timer.fraction());
+            if !timer.finished() {
+                return;
+            }
+            next_level_state.set(*target);
+            TransitionPhase::WaitSpawn {
+                spawn: std::mem::take(spawn),
+                timer: Timer::from_seconds(SPAWN_WAITING_SECONDS, TimerMode::Once),
+            }
+        }
+        TransitionPhase::WaitSpawn { spawn, timer } => {
+            timer.tick(time.delta());
+            let point = spawn_points.iter().find(|(point, _)| point.name == *spawn);
+            match point {
+                Some((_, transform)) => {
+                    for mut position in player_position.iter_mut() {
+                        position.x = transform.translation.x;
+                        position.y = transform.translation.y;
+                    }
+                }
+                None => {
+                    if !timer.finished() {
+                        return;
+                    }
+                    warn!("Spawn point {spawn} is not found");
+                }
+            }
+            TransitionPhase::FadeIn {
+                timer: Timer::from_seconds(FADE_SECONDS, TimerMode::Once),
+            }
+        }
+        TransitionPhase::FadeIn { timer } => {
+            timer.tick(time.delta());
+            set_fade_alpha(&mut fades, 1.0 - timer.fraction());
+            if !timer.finished() {
+                return;
+            }
+            for (entity, _) in fades.iter() {
+                commands.entity(entity).despawn_recursive();
+            }
+            TransitionPhase::None
+        }
+    };
+    transition.phase = next_phase;
+}
+
+fn set_fade_alpha(
+    fades: &mut Query<(Entity, &mut BackgroundColor), With<TransitionFade>>,
+    alpha: f32,
+) {
+    for (_, mut background) in fades.iter_mut() {
+        background.0 = Color::BLACK.with_alpha(alpha);
+    }
+}
+
+fn spawn_fade(commands: &mut Commands) {
+    commands
+        .ui_builder(UiRoot)
+        .column(|_| {})
+        .insert(TransitionFade)
+        .style()
+        .position_type(PositionType::Absolute)
+        .width(Val::Percent(100.0))
+        .height(Val::Percent(100.0))
+        .background_color(Color::BLACK.with_alpha(0.0));
+}
+
+#[test]
+fn level_transition_start_test() {
+    let mut transition = LevelTransition::default();
+    assert!(!transition.is_active());
+
+    transition.start(Level::CourtHouseHall, "entrance".to_string());
+
+    assert!(transition.is_active());
+    assert!(matches!(
+        transition.phase,
+        TransitionPhase::FadeOut {
+            target: Level::CourtHouseHall,
+            ..
+        }
+    ));
+}
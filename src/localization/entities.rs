@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+use bevy::log::warn;
+use bevy::prelude::Resource;
+
+use crate::settings::Language;
+
+/// Strings of the language by id.
+pub type StringTable = HashMap<String, String>;
+
+/// Language of the original texts, its strings are shown when translation is missing.
+pub const FALLBACK_LANGUAGE: Language = Language::Russian;
+
+/// String tables of all languages, texts are taken in the current one.
+#[derive(Resource)]
+pub struct Localization {
+    language: Language,
+    tables: HashMap<Language, StringTable>,
+}
+
+impl Localization {
+    pub fn load(language: Language) -> Self {
+        let tables = Language::ALL
+            .iter()
+            .map(|language| (*language, parse_table(*language)))
+            .collect();
+        Localization { language, tables }
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    pub fn set_language(&mut self, language: Language) {
+        self.language = language;
+    }
+
+    /// String of the current or the fallback language, the key itself if there is no such string.
+    pub fn get(&self, key: &str) -> String {
+        self.find(key).unwrap_or(key).to_string()
+    }
+
+    /// Like `get`, but `default` is used when the string is missing in all tables,
+    /// e.g. original dialog text written in code.
+    pub fn get_or(&self, key: &str, default: &str) -> String {
+        self.find(key).unwrap_or(default).to_string()
+    }
+
+    /// Replaces `{name}` placeholders of the string with values of args.
+    pub fn format(&self, key: &str, args: &[(&str, String)]) -> String {
+        let mut result = self.get(key);
+        for (name, value) in args {
+            result = result.replace(&format!("{{{name}}}"), value);
+        }
+        result
+    }
+
+    fn find(&self, key: &str) -> Option<&str> {
+        [self.language, FALLBACK_LANGUAGE]
+            .iter()
+            .filter_map(|language| self.tables.get(language))
+            .find_map(|table| table.get(key))
+            .map(|value| value.as_str())
+    }
+}
+
+fn table_source(language: Language) -> &'static str {
+    match language {
+        Language::Russian => include_str!("../../assets/locales/ru.json"),
+        Language::English => include_str!("../../assets/locales/en.json"),
+    }
+}
+
+fn parse_table(language: Language) -> StringTable {
+    match serde_json::from_str(table_source(language)) {
+        Ok(table) => table,
+        Err(error) => {
+            warn!("Could not parse {} strings: {error}", language.code());
+            StringTable::new()
+        }
+    }
+}
+
+#[test]
+fn localization_fallback_test() {
+    let mut tables = HashMap::new();
+    tables.insert(
+        Language::Russian,
+        StringTable::from([
+            ("menu.new".to_string(), "Новая игра".to_string()),
+            ("menu.exit".to_string(), "Выход".to_string()),
+        ]),
+    );
+    tables.insert(
+        Language::English,
+        StringTable::from([("menu.new".to_string(), "New game".to_string())]),
+    );
+    let mut localization = Localization {
+        language: Language::English,
+        tables,
+    };
+
+    assert_eq!(localization.get("menu.new"), "New game");
+    assert_eq!(localization.get("menu.exit"), "Выход");
+    assert_eq!(localization.get("menu.unknown"), "menu.unknown");
+    assert_eq!(localization.get_or("dialog.1.0.0", "Хрр..."), "Хрр...");
+
+    localization.set_language(Language::Russian);
+    assert_eq!(localization.get("menu.new"), "Новая игра");
+}
+
+#[test]
+fn localization_format_test() {
+    let tables = HashMap::from([(
+        Language::English,
+        StringTable::from([("trade.money".to_string(), "Money: {money}".to_string())]),
+    )]);
+    let localization = Localization {
+        language: Language::English,
+        tables,
+    };
+
+    assert_eq!(
+        localization.format("trade.money", &[("money", 42.to_string())]),
+        "Money: 42"
+    );
+}
+
+#[test]
+fn localization_tables_keys_test() {
+    let russian = parse_table(Language::Russian);
+    let english = parse_table(Language::English);
+
+    assert!(!russian.is_empty());
+    // Dialogs are written in Russian in code, other strings must be translated
+    for key in russian.keys() {
+        assert!(english.contains_key(key), "No English string for {key}");
+    }
+}
+
+#[test]
+fn localization_dialogs_keys_test() {
+    let english = parse_table(Language::English);
+    let mut keys = vec![];
+
+    for dialog in crate::dialog::DialogsStorage.get_all().values() {
+        for (key, _) in dialog.texts() {
+            assert!(english.contains_key(&key), "No English string for {key}");
+            keys.push(key);
+        }
+    }
+    // Keys are positional, a removed replica leaves a stale translation and shifts the rest
+    let is_replica_key = |key: &String| {
+        key.strip_prefix("dialog.")
+            .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+    };
+    for key in english.keys().filter(|key| is_replica_key(key)) {
+        assert!(keys.contains(key), "English string {key} has no replica");
+    }
+}
+
+#[test]
+fn localization_cutscenes_keys_test() {
+    let english = parse_table(Language::English);
+
+    for cutscene in crate::cutscene::CutscenesStorage.get_all().values() {
+        for (key, _) in cutscene.texts() {
+            assert!(english.contains_key(&key), "No English string for {key}");
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use bevy::app::{App, Plugin, PreUpdate};
+use bevy::prelude::{DetectChanges, Res, ResMut};
+
+mod entities;
+
+pub use entities::*;
+
+use crate::settings::Settings;
+
+pub struct LocalizationPlugin;
+
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        let language = app
+            .world()
+            .get_resource::<Settings>()
+            .map(|settings| settings.language)
+            .unwrap_or(Settings::default().language);
+        app.insert_resource(Localization::load(language))
+            .add_systems(PreUpdate, localization_language_update);
+    }
+}
+
+fn localization_language_update(settings: Res<Settings>, mut localization: ResMut<Localization>) {
+    if !settings.is_changed() || localization.language() == settings.language {
+        return;
+    }
+    localization.set_language(settings.language);
+}
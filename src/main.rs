@@ -1,7 +1,9 @@
+// Bevy systems get their queries and resources as arguments
+#![allow(clippy::too_many_arguments, clippy::type_complexity)]
+
 use bevy::prelude::AppExtStates;
-use bevy::render::settings::{Backends, WgpuSettings};
+use bevy::render::settings::WgpuSettings;
 use bevy::render::RenderPlugin;
-use bevy::window::WindowMode;
 use bevy::{
     prelude::{App, PluginGroup, Startup},
     window::{Window, WindowPlugin},
@@ -12,12 +14,15 @@ use bevy_rapier2d::prelude::{NoUserData, RapierPhysicsPlugin};
 use player::animations::PlayerAnimations;
 
 use crate::core::states::GameState;
+use crate::cutscene::CutsceneScene;
 use crate::dev::DevSettingsPlugin;
 use crate::dialog::{DialogPlugin, DialogScene};
 use crate::fight::{FightPlugin, FightingScene};
 use crate::gui::UiPlugin;
+use crate::input::InputPlugin;
 use crate::interaction::BaseInteractionPlugin;
 use crate::level::LevelNavPlugin;
+use crate::localization::LocalizationPlugin;
 use crate::menu::MainMenuPlugin;
 use crate::npc::NpcPlugin;
 use crate::party::PartyPlugin;
@@ -25,33 +30,53 @@ use crate::player::plugins::PlayerPlugin;
 use crate::rpg::CharacterScreenPlugin;
 use crate::rpg::InventoryAndAbilityScreenPlugin;
 use crate::rpg::RpgPlugin;
+use crate::settings::{Settings, SettingsPlugin};
+use crate::trade::{TradePlugin, TradeScene};
 use crate::world_state::WorldStatePlugin;
 
 mod animation;
 mod core;
+mod cutscene;
 mod dev;
 mod dialog;
 mod fight;
 mod gui;
+mod input;
 mod interaction;
 mod level;
+mod localization;
 mod menu;
 mod movement;
 mod npc;
 mod party;
 mod player;
 mod rpg;
+mod settings;
 mod sound;
 mod startup;
+mod trade;
 mod world_state;
 
 fn main() {
-    let wgpu_settings = WgpuSettings {
-        backends: Some(Backends::VULKAN),
+    let settings = Settings::load();
+    let mut wgpu_settings = WgpuSettings::default();
+    if let Some(backends) = settings.renderer.backends() {
+        wgpu_settings.backends = Some(backends);
+    }
+    let window = Window {
+        title: "Faces".to_string(),
+        mode: settings.display_mode.window_mode(),
+        present_mode: settings.present_mode(),
+        resolution: (
+            settings.resolution.width as f32,
+            settings.resolution.height as f32,
+        )
+            .into(),
         ..Default::default()
     };
     App::new()
         .insert_resource(PlayerAnimations::default())
+        .insert_resource(settings)
         .add_plugins(RapierPhysicsPlugin::<NoUserData>::default())
         .add_plugins(
             DefaultPlugins
@@ -60,11 +85,7 @@ fn main() {
                     ..Default::default()
                 })
                 .set(WindowPlugin {
-                    primary_window: Some(Window {
-                        title: "Faces".to_string(),
-                        mode: WindowMode::BorderlessFullscreen,
-                        ..Default::default()
-                    }),
+                    primary_window: Some(window),
                     ..Default::default()
                 }),
         )
@@ -85,7 +106,16 @@ fn main() {
             WorldStatePlugin,
             CharacterScreenPlugin,
         ))
-        .add_plugins((InventoryAndAbilityScreenPlugin, sound::SoundPlugin))
+        .add_plugins((
+            InventoryAndAbilityScreenPlugin,
+            sound::SoundPlugin,
+            TradePlugin,
+            TradeScene,
+            InputPlugin,
+            SettingsPlugin,
+            LocalizationPlugin,
+            CutsceneScene,
+        ))
         .add_systems(Startup, startup::setup)
         .init_state::<GameState>()
         .run();
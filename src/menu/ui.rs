@@ -1,8 +1,8 @@
-use bevy::app::{App, AppExit, Plugin, Update};
-use bevy::audio::{AudioBundle, PlaybackSettings};
+use bevy::app::{App, AppExit, Plugin, PreUpdate, Update};
 use bevy::color::palettes::css::DIM_GREY;
 use bevy::color::Color;
 use bevy::hierarchy::DespawnRecursiveExt;
+use bevy::input::gamepad::GamepadButton;
 use bevy::input::ButtonInput;
 use bevy::prelude::in_state;
 use bevy::prelude::AppExtStates;
@@ -26,6 +26,8 @@ use bevy::prelude::States;
 use bevy::prelude::Val;
 use bevy::prelude::With;
 use bevy::prelude::{AlignItems, DetectChanges, KeyCode};
+use bevy::prelude::{Children, HierarchyQueryExt, Resource};
+use bevy::text::Text;
 use sickle_ui::prelude::SetAlignItemsExt;
 use sickle_ui::prelude::SetBackgroundColorExt;
 use sickle_ui::prelude::SetJustifyContentExt;
@@ -33,11 +35,18 @@ use sickle_ui::prelude::SetSizeExt;
 use sickle_ui::prelude::UiBuilderExt;
 use sickle_ui::prelude::UiColumnExt;
 use sickle_ui::prelude::UiRoot;
+use sickle_ui::prelude::UiRowExt;
 
 use crate::core::states::GameState;
-use crate::gui::{TextButton, TextButtonExt};
+use crate::gui::{ButtonConfig, TextButton, TextButtonExt, TextConfig};
+use crate::input::{
+    button_title, input_actions_update, is_bindable_button, is_bindable_key, key_title,
+    InputAction, InputBindings,
+};
 use crate::level::states::Level;
-use crate::sound::ButtonSounds;
+use crate::localization::Localization;
+use crate::settings::{SettingKind, Settings};
+use crate::sound::{AudioBus, AudioCommandsExt, ButtonSounds};
 
 pub struct MainMenuPlugin;
 
@@ -52,65 +61,132 @@ enum ScreenState {
     #[default]
     Main,
     Options,
+    Controls,
+    Settings,
 }
 
+#[derive(Component)]
+struct Controls;
+
+#[derive(Component)]
+struct SettingsScreen;
+
 #[derive(Component, Eq, PartialEq)]
 struct MenuItemId(usize);
 
+#[derive(Component, Clone, Copy, Eq, PartialEq)]
+enum ControlsItemId {
+    Action(InputAction),
+    Reset,
+}
+
+#[derive(Component, Clone, Copy, Eq, PartialEq)]
+enum SettingsItemId {
+    Previous(SettingKind),
+    Value(SettingKind),
+    Next(SettingKind),
+}
+
+/// Action which waits for a new key or gamepad button. After it's bound, actions are
+/// ignored until the key is released, otherwise the held key would trigger the action.
+#[derive(Resource, Default, Clone, Copy, PartialEq)]
+enum Rebinding {
+    #[default]
+    None,
+    WaitingForKey(InputAction),
+    WaitingForRelease(InputAction),
+}
+
 impl Plugin for MainMenuPlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<ScreenState>()
+            .init_resource::<Rebinding>()
             .add_systems(Update, opens_screens_handle)
             .add_systems(OnEnter(GameState::MainMenu), spawn_main)
             .add_systems(OnExit(GameState::MainMenu), despawn_main)
             .add_systems(OnEnter(ScreenState::Options), spawn_options)
             .add_systems(OnExit(ScreenState::Options), despawn_options)
+            .add_systems(OnEnter(ScreenState::Controls), spawn_controls)
+            .add_systems(OnExit(ScreenState::Controls), despawn_controls)
+            .add_systems(OnEnter(ScreenState::Settings), spawn_settings)
+            .add_systems(OnExit(ScreenState::Settings), despawn_settings)
+            .add_systems(
+                PreUpdate,
+                rebinding_input_handle
+                    .after(input_actions_update)
+                    .run_if(in_state(GameState::MainMenu)),
+            )
             .add_systems(
                 Update,
-                mouse_input_handle.run_if(in_state(GameState::MainMenu)),
+                (
+                    back_input_handle,
+                    mouse_input_handle,
+                    controls_mouse_input_handle,
+                    controls_titles_update,
+                    settings_mouse_input_handle,
+                    settings_titles_update,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::MainMenu)),
             );
     }
 }
 
 fn opens_screens_handle(
-    mut commands: Commands,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    actions: Res<ButtonInput<InputAction>>,
     game_state: Res<State<GameState>>,
     mut next_game_state: ResMut<NextState<GameState>>,
 ) {
-    if game_state.is_changed() || keyboard.is_changed() {
+    if game_state.is_changed() || actions.is_changed() {
         match game_state.get() {
             GameState::MainMenu => {}
             GameState::Exploration => {
-                if keyboard.pressed(KeyCode::KeyC) && keyboard.just_pressed(KeyCode::KeyC) {
+                if actions.pressed(InputAction::OpenCharacter)
+                    && actions.just_pressed(InputAction::OpenCharacter)
+                {
                     next_game_state.set(GameState::Character)
                 }
 
-                if keyboard.pressed(KeyCode::KeyI) && keyboard.just_pressed(KeyCode::KeyI) {
+                if actions.pressed(InputAction::OpenInventory)
+                    && actions.just_pressed(InputAction::OpenInventory)
+                {
                     next_game_state.set(GameState::InventoryAndAbilities)
                 }
+
+                if actions.pressed(InputAction::OpenJournal)
+                    && actions.just_pressed(InputAction::OpenJournal)
+                {
+                    next_game_state.set(GameState::Journal)
+                }
             }
             GameState::Fighting => {}
             GameState::Dialog => {}
-            GameState::Character | GameState::InventoryAndAbilities => {
-                if keyboard.pressed(KeyCode::Escape) && keyboard.just_pressed(KeyCode::Escape) {
+            GameState::Character
+            | GameState::InventoryAndAbilities
+            | GameState::Trading
+            | GameState::Journal => {
+                if actions.pressed(InputAction::Back) && actions.just_pressed(InputAction::Back) {
                     next_game_state.set(GameState::Exploration)
                 }
             }
             GameState::CatScene => {}
-            GameState::GameOver => {}
+            GameState::GameOver => {
+                if actions.pressed(InputAction::Back) && actions.just_pressed(InputAction::Back) {
+                    next_game_state.set(GameState::MainMenu)
+                }
+            }
             GameState::DevSetting => {}
         }
     }
 }
 
-fn spawn_main(mut commands: Commands) {
+fn spawn_main(mut commands: Commands, localization: Res<Localization>) {
     commands
         .ui_builder(UiRoot)
         .column(|parent| {
-            parent.text_button("New", NEW_MENU_ITEM_ID);
-            parent.text_button("Options", OPTIONS_MENU_ITEM_ID);
-            parent.text_button("Exit", EXIT_MENU_ITEM_ID);
+            parent.text_button(localization.get("menu.new"), NEW_MENU_ITEM_ID);
+            parent.text_button(localization.get("menu.options"), OPTIONS_MENU_ITEM_ID);
+            parent.text_button(localization.get("menu.exit"), EXIT_MENU_ITEM_ID);
         })
         .insert(MainMenu)
         .style()
@@ -130,11 +206,16 @@ fn despawn_main(
     next_state.set(ScreenState::Main);
 }
 
-fn spawn_options(mut commands: Commands) {
+fn spawn_options(mut commands: Commands, localization: Res<Localization>) {
     commands
         .ui_builder(UiRoot)
         .column(|parent| {
-            parent.text_button("Dev settings", DEV_SETTINGS_OPTION_ITEM_ID);
+            parent.text_button(localization.get("menu.settings"), SETTINGS_OPTION_ITEM_ID);
+            parent.text_button(localization.get("menu.controls"), CONTROLS_OPTION_ITEM_ID);
+            parent.text_button(
+                localization.get("menu.dev_settings"),
+                DEV_SETTINGS_OPTION_ITEM_ID,
+            );
         })
         .insert(Options)
         .style()
@@ -149,6 +230,306 @@ fn despawn_options(mut commands: Commands, query: Query<Entity, With<Options>>)
     commands.entity(entity).despawn_recursive();
 }
 
+fn spawn_controls(
+    mut commands: Commands,
+    bindings: Res<InputBindings>,
+    localization: Res<Localization>,
+) {
+    let button_config = ButtonConfig {
+        width: Val::Px(800.0),
+        height: Val::Px(44.0),
+        ..Default::default()
+    };
+    commands
+        .ui_builder(UiRoot)
+        .column(|parent| {
+            for action in InputAction::ALL {
+                parent.configure_text_button(
+                    binding_title(action, &bindings, Rebinding::None, &localization),
+                    ControlsItemId::Action(action),
+                    TextConfig::small(Color::from(DIM_GREY)),
+                    button_config.clone(),
+                );
+            }
+            parent.configure_text_button(
+                localization.get("menu.reset"),
+                ControlsItemId::Reset,
+                TextConfig::small(Color::from(DIM_GREY)),
+                button_config.clone(),
+            );
+        })
+        .insert(Controls)
+        .style()
+        .justify_content(JustifyContent::SpaceAround)
+        .size(Val::Percent(100.0))
+        .align_items(AlignItems::Center)
+        .background_color(Color::from(DIM_GREY));
+}
+
+fn despawn_controls(
+    mut commands: Commands,
+    mut rebinding: ResMut<Rebinding>,
+    query: Query<Entity, With<Controls>>,
+) {
+    let entity = query.single();
+    commands.entity(entity).despawn_recursive();
+    *rebinding = Rebinding::None;
+}
+
+fn binding_title(
+    action: InputAction,
+    bindings: &InputBindings,
+    rebinding: Rebinding,
+    localization: &Localization,
+) -> String {
+    let action_title = localization.get(action.title_key());
+    if rebinding == Rebinding::WaitingForKey(action) {
+        return localization.format("menu.rebinding", &[("action", action_title)]);
+    }
+    let binding = bindings.get(action);
+    format!(
+        "{}: {} / {}",
+        action_title,
+        key_title(binding.key),
+        button_title(binding.button)
+    )
+}
+
+fn back_input_handle(
+    actions: Res<ButtonInput<InputAction>>,
+    current_state: Res<State<ScreenState>>,
+    mut next_state: ResMut<NextState<ScreenState>>,
+) {
+    if !(actions.pressed(InputAction::Back) && actions.just_pressed(InputAction::Back)) {
+        return;
+    }
+    match current_state.get() {
+        ScreenState::Main => {}
+        ScreenState::Options => next_state.set(ScreenState::Main),
+        ScreenState::Controls | ScreenState::Settings => next_state.set(ScreenState::Options),
+    }
+}
+
+// Runs before all action handlers, so while rebinding none of them sees the pressed keys
+fn rebinding_input_handle(
+    mut rebinding: ResMut<Rebinding>,
+    mut bindings: ResMut<InputBindings>,
+    mut actions: ResMut<ButtonInput<InputAction>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+) {
+    match *rebinding {
+        Rebinding::None => return,
+        Rebinding::WaitingForKey(action) => {
+            let key = keyboard
+                .get_just_pressed()
+                .copied()
+                .find(|key| is_bindable_key(*key));
+            let button = gamepad_buttons
+                .get_just_pressed()
+                .map(|button| button.button_type)
+                .find(|button| is_bindable_button(*button));
+
+            if let Some(key) = key {
+                bindings.bind_key(action, key);
+            } else if let Some(button) = button {
+                bindings.bind_button(action, button);
+            }
+            if key.is_some() || button.is_some() {
+                bindings.save();
+                *rebinding = Rebinding::WaitingForRelease(action);
+            }
+        }
+        Rebinding::WaitingForRelease(action) => {
+            if !actions.pressed(action) {
+                *rebinding = Rebinding::None;
+            }
+        }
+    }
+    actions.reset_all();
+}
+
+fn controls_mouse_input_handle(
+    mut commands: Commands,
+    mut rebinding: ResMut<Rebinding>,
+    mut bindings: ResMut<InputBindings>,
+    mut query: Query<
+        (
+            &TextButton<ControlsItemId>,
+            &Interaction,
+            &mut BackgroundColor,
+        ),
+        Changed<Interaction>,
+    >,
+    audio_res: Res<ButtonSounds>,
+) {
+    for (button, interaction, mut background_color) in &mut query {
+        match *interaction {
+            Interaction::None => {
+                *background_color = button.config.idle;
+            }
+            Interaction::Hovered => {
+                *background_color = button.config.hover;
+            }
+            Interaction::Pressed => {
+                commands.play_sound(AudioBus::Ui, audio_res.click.clone());
+                match button.payload {
+                    ControlsItemId::Action(action) => *rebinding = Rebinding::WaitingForKey(action),
+                    ControlsItemId::Reset => {
+                        *bindings = InputBindings::default();
+                        bindings.save();
+                        *rebinding = Rebinding::None;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn controls_titles_update(
+    rebinding: Res<Rebinding>,
+    bindings: Res<InputBindings>,
+    localization: Res<Localization>,
+    buttons_query: Query<(Entity, &TextButton<ControlsItemId>)>,
+    children_query: Query<&Children>,
+    mut text_query: Query<&mut Text>,
+) {
+    if !(rebinding.is_changed() || bindings.is_changed() || localization.is_changed()) {
+        return;
+    }
+    for (entity, button) in buttons_query.iter() {
+        let ControlsItemId::Action(action) = button.payload else {
+            continue;
+        };
+        let title = binding_title(action, &bindings, *rebinding, &localization);
+        button_title_update(entity, title, &children_query, &mut text_query);
+    }
+}
+
+fn button_title_update(
+    button: Entity,
+    title: String,
+    children_query: &Query<&Children>,
+    text_query: &mut Query<&mut Text>,
+) {
+    for child in children_query.iter_descendants(button) {
+        if let Ok(mut text) = text_query.get_mut(child) {
+            text.sections[0].value = title.clone();
+        }
+    }
+}
+
+fn spawn_settings(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    localization: Res<Localization>,
+) {
+    let arrow_config = ButtonConfig {
+        width: Val::Px(60.0),
+        height: Val::Px(44.0),
+        ..Default::default()
+    };
+    let value_config = ButtonConfig {
+        width: Val::Px(700.0),
+        height: Val::Px(44.0),
+        ..Default::default()
+    };
+    commands
+        .ui_builder(UiRoot)
+        .column(|parent| {
+            for kind in SettingKind::ALL {
+                parent.row(|parent| {
+                    parent.configure_text_button(
+                        "<",
+                        SettingsItemId::Previous(kind),
+                        TextConfig::small(Color::from(DIM_GREY)),
+                        arrow_config.clone(),
+                    );
+                    parent.configure_text_button(
+                        settings.title(kind, &localization),
+                        SettingsItemId::Value(kind),
+                        TextConfig::small(Color::from(DIM_GREY)),
+                        value_config.clone(),
+                    );
+                    parent.configure_text_button(
+                        ">",
+                        SettingsItemId::Next(kind),
+                        TextConfig::small(Color::from(DIM_GREY)),
+                        arrow_config.clone(),
+                    );
+                });
+            }
+        })
+        .insert(SettingsScreen)
+        .style()
+        .justify_content(JustifyContent::SpaceAround)
+        .size(Val::Percent(100.0))
+        .align_items(AlignItems::Center)
+        .background_color(Color::from(DIM_GREY));
+}
+
+fn despawn_settings(mut commands: Commands, query: Query<Entity, With<SettingsScreen>>) {
+    let entity = query.single();
+    commands.entity(entity).despawn_recursive();
+}
+
+fn settings_mouse_input_handle(
+    mut commands: Commands,
+    mut settings: ResMut<Settings>,
+    mut query: Query<
+        (
+            &TextButton<SettingsItemId>,
+            &Interaction,
+            &mut BackgroundColor,
+        ),
+        Changed<Interaction>,
+    >,
+    audio_res: Res<ButtonSounds>,
+) {
+    for (button, interaction, mut background_color) in &mut query {
+        match *interaction {
+            Interaction::None => {
+                *background_color = button.config.idle;
+            }
+            Interaction::Hovered => {
+                *background_color = button.config.hover;
+            }
+            Interaction::Pressed => {
+                commands.play_sound(AudioBus::Ui, audio_res.click.clone());
+                match button.payload {
+                    SettingsItemId::Previous(kind) => settings.change(kind, -1),
+                    SettingsItemId::Value(kind) | SettingsItemId::Next(kind) => {
+                        settings.change(kind, 1)
+                    }
+                }
+                settings.save();
+            }
+        }
+    }
+}
+
+fn settings_titles_update(
+    settings: Res<Settings>,
+    localization: Res<Localization>,
+    buttons_query: Query<(Entity, &TextButton<SettingsItemId>)>,
+    children_query: Query<&Children>,
+    mut text_query: Query<&mut Text>,
+) {
+    if !(settings.is_changed() || localization.is_changed()) {
+        return;
+    }
+    for (entity, button) in buttons_query.iter() {
+        if let SettingsItemId::Value(kind) = button.payload {
+            button_title_update(
+                entity,
+                settings.title(kind, &localization),
+                &children_query,
+                &mut text_query,
+            );
+        }
+    }
+}
+
 fn mouse_input_handle(
     mut commands: Commands,
     mut next_game_state: ResMut<NextState<GameState>>,
@@ -170,45 +551,45 @@ fn mouse_input_handle(
             Interaction::Hovered => {
                 *background_color = button.config.hover;
             }
-            Interaction::Pressed => {
-                let default = AudioBundle {
-                    source: audio_res.click.clone(),
-                    settings: PlaybackSettings::ONCE,
-                };
-                match current_screen_state.get() {
-                    ScreenState::Main => {
-                        if button.payload == NEW_MENU_ITEM_ID {
-                            commands.spawn(AudioBundle {
-                                source: audio_res.final_click.clone(),
-                                settings: PlaybackSettings::ONCE,
-                            });
-                            next_level_state.set(Level::House);
-                            next_game_state.set(GameState::Exploration);
-                            return;
-                        }
-
-                        if button.payload == OPTIONS_MENU_ITEM_ID {
-                            commands.spawn(default);
-                            next_state.set(ScreenState::Options);
-                            return;
-                        }
-
-                        if button.payload == EXIT_MENU_ITEM_ID {
-                            commands.spawn(default);
-                            exit.send(AppExit::Success);
-                            return;
-                        }
+            Interaction::Pressed => match current_screen_state.get() {
+                ScreenState::Main => {
+                    if button.payload == NEW_MENU_ITEM_ID {
+                        commands.play_sound(AudioBus::Ui, audio_res.final_click.clone());
+                        next_level_state.set(Level::House);
+                        next_game_state.set(GameState::Exploration);
+                        return;
                     }
-                    ScreenState::Options => {
-                        commands.spawn(default);
-                        if button.payload == DEV_SETTINGS_OPTION_ITEM_ID {
-                            next_game_state.set(GameState::DevSetting);
-                            return;
-                        }
+
+                    if button.payload == OPTIONS_MENU_ITEM_ID {
+                        commands.play_sound(AudioBus::Ui, audio_res.click.clone());
+                        next_state.set(ScreenState::Options);
+                        return;
+                    }
+
+                    if button.payload == EXIT_MENU_ITEM_ID {
+                        commands.play_sound(AudioBus::Ui, audio_res.click.clone());
+                        exit.send(AppExit::Success);
                         return;
                     }
                 }
-            }
+                ScreenState::Options => {
+                    commands.play_sound(AudioBus::Ui, audio_res.click.clone());
+                    if button.payload == SETTINGS_OPTION_ITEM_ID {
+                        next_state.set(ScreenState::Settings);
+                        return;
+                    }
+                    if button.payload == CONTROLS_OPTION_ITEM_ID {
+                        next_state.set(ScreenState::Controls);
+                        return;
+                    }
+                    if button.payload == DEV_SETTINGS_OPTION_ITEM_ID {
+                        next_game_state.set(GameState::DevSetting);
+                        return;
+                    }
+                    return;
+                }
+                ScreenState::Controls | ScreenState::Settings => {}
+            },
         }
     }
 }
@@ -217,3 +598,5 @@ const NEW_MENU_ITEM_ID: MenuItemId = MenuItemId(2);
 const OPTIONS_MENU_ITEM_ID: MenuItemId = MenuItemId(3);
 const EXIT_MENU_ITEM_ID: MenuItemId = MenuItemId(4);
 const DEV_SETTINGS_OPTION_ITEM_ID: MenuItemId = MenuItemId(11);
+const CONTROLS_OPTION_ITEM_ID: MenuItemId = MenuItemId(12);
+const SETTINGS_OPTION_ITEM_ID: MenuItemId = MenuItemId(13);
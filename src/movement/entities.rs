@@ -1,12 +1,45 @@
 use bevy::ecs::component::Component;
+use bevy::math::Vec2;
+use rand::Rng;
 
 use crate::core::geometry::{BBox, Point2D};
 
+// sin(22.5°), vector is split into 8 equal sectors
+const DIAGONAL_MIN_PART: f32 = 0.38;
+
 #[derive(Component)]
 pub struct MoveAgent {
+    /// Agent with lower priority gives way to agent with higher one.
     pub priority: i32,
     pub half_size: i32,
     pub route: Vec<Point2D>,
+    /// Route is built to this point, it's cleared when the agent arrives.
+    pub destination: Option<Point2D>,
+}
+
+impl MoveAgent {
+    pub fn create(priority: i32, half_size: i32) -> Self {
+        MoveAgent {
+            priority,
+            half_size,
+            route: vec![],
+            destination: None,
+        }
+    }
+
+    pub fn go_to(&mut self, point: Point2D) {
+        self.destination = Some(point);
+        self.route.clear();
+    }
+
+    pub fn stop(&mut self) {
+        self.destination = None;
+        self.route.clear();
+    }
+
+    pub fn is_moving(&self) -> bool {
+        self.destination.is_some()
+    }
 }
 
 #[derive(Component)]
@@ -20,18 +53,26 @@ impl Blocks {
     }
 }
 
-#[derive(Component)]
-pub struct Target {
-    pub half_size: i32,
-}
-
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct MoveArea {
     pub half_width: i32,
     pub half_height: i32,
     pub center_position: Point2D,
 }
 
+impl MoveArea {
+    pub fn random_point(&self) -> Point2D {
+        let mut rng = rand::thread_rng();
+        let Point2D { x, y } = self.center_position;
+        Point2D::new(
+            rng.gen_range(x - self.half_width..=x + self.half_width),
+            rng.gen_range(y - self.half_height..=y + self.half_height),
+        )
+    }
+}
+
+/// Direction of player and npc, moving or standing. Used as animation sheet key too.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum MoveDirection {
     Top,
     LeftTop,
@@ -50,3 +91,119 @@ pub enum MoveDirection {
     RightIdle,
     RightTopIdle,
 }
+
+impl MoveDirection {
+    /// Direction by signs of velocity axes, None if there is no movement.
+    pub fn from_signs(x_sign: i32, y_sign: i32) -> Option<Self> {
+        let direction = match (x_sign.signum(), y_sign.signum()) {
+            (0, 1) => MoveDirection::Top,
+            (-1, 1) => MoveDirection::LeftTop,
+            (-1, 0) => MoveDirection::Left,
+            (-1, -1) => MoveDirection::LeftBottom,
+            (0, -1) => MoveDirection::Bottom,
+            (1, -1) => MoveDirection::RightBottom,
+            (1, 0) => MoveDirection::Right,
+            (1, 1) => MoveDirection::RightTop,
+            _ => return None,
+        };
+        Some(direction)
+    }
+
+    /// Direction of the vector, axis with a small part of it is ignored,
+    /// so almost straight movement is not a diagonal one.
+    pub fn from_vector(vector: Vec2) -> Option<Self> {
+        let min_part = vector.length() * DIAGONAL_MIN_PART;
+        let sign = |value: f32| {
+            if value == 0.0 || value.abs() < min_part {
+                0
+            } else {
+                value.signum() as i32
+            }
+        };
+        Self::from_signs(sign(vector.x), sign(vector.y))
+    }
+
+    /// Unit vector of the direction, so diagonal movement is not faster than straight one.
+    pub fn to_vector(self) -> Vec2 {
+        let (x, y) = match self.to_moving() {
+            MoveDirection::Top => (0.0, 1.0),
+            MoveDirection::LeftTop => (-1.0, 1.0),
+            MoveDirection::Left => (-1.0, 0.0),
+            MoveDirection::LeftBottom => (-1.0, -1.0),
+            MoveDirection::Bottom => (0.0, -1.0),
+            MoveDirection::RightBottom => (1.0, -1.0),
+            MoveDirection::Right => (1.0, 0.0),
+            _ => (1.0, 1.0),
+        };
+        Vec2::new(x, y).normalize()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_idle(&self) -> bool {
+        *self == self.to_idle()
+    }
+
+    pub fn to_idle(self) -> Self {
+        match self {
+            MoveDirection::Top => MoveDirection::TopIdle,
+            MoveDirection::LeftTop => MoveDirection::LeftTopIdle,
+            MoveDirection::Left => MoveDirection::LeftIdle,
+            MoveDirection::LeftBottom => MoveDirection::LeftBottomIdle,
+            MoveDirection::Bottom => MoveDirection::BottomIdle,
+            MoveDirection::RightBottom => MoveDirection::RightBottomIdle,
+            MoveDirection::Right => MoveDirection::RightIdle,
+            MoveDirection::RightTop => MoveDirection::RightTopIdle,
+            idle => idle,
+        }
+    }
+
+    pub fn to_moving(self) -> Self {
+        match self {
+            MoveDirection::TopIdle => MoveDirection::Top,
+            MoveDirection::LeftTopIdle => MoveDirection::LeftTop,
+            MoveDirection::LeftIdle => MoveDirection::Left,
+            MoveDirection::LeftBottomIdle => MoveDirection::LeftBottom,
+            MoveDirection::BottomIdle => MoveDirection::Bottom,
+            MoveDirection::RightBottomIdle => MoveDirection::RightBottom,
+            MoveDirection::RightIdle => MoveDirection::Right,
+            MoveDirection::RightTopIdle => MoveDirection::RightTop,
+            moving => moving,
+        }
+    }
+}
+
+#[test]
+fn move_area_random_point_test() {
+    let area = MoveArea {
+        half_width: 10,
+        half_height: 5,
+        center_position: Point2D::new(-20, 40),
+    };
+    for _ in 0..100 {
+        let point = area.random_point();
+        assert!((-30..=-10).contains(&point.x));
+        assert!((35..=45).contains(&point.y));
+    }
+}
+
+#[test]
+fn move_direction_from_vector_test() {
+    let direction = |x, y| MoveDirection::from_vector(Vec2::new(x, y));
+
+    assert_eq!(direction(0.0, 0.0), None);
+    assert_eq!(direction(10.0, 1.0), Some(MoveDirection::Right));
+    assert_eq!(direction(-5.0, 5.0), Some(MoveDirection::LeftTop));
+    assert_eq!(direction(2.0, -10.0), Some(MoveDirection::Bottom));
+    assert_eq!(direction(-6.0, -10.0), Some(MoveDirection::LeftBottom));
+}
+
+#[test]
+fn move_direction_to_vector_test() {
+    let diagonal = MoveDirection::RightBottomIdle.to_vector();
+
+    assert_eq!(MoveDirection::Top.to_vector(), Vec2::new(0.0, 1.0));
+    assert!((diagonal.length() - 1.0).abs() < 1e-6);
+    assert!(diagonal.x > 0.0 && diagonal.y < 0.0);
+    assert!(MoveDirection::LeftTopIdle.is_idle());
+    assert!(!MoveDirection::LeftTop.is_idle());
+}
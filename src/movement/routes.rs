@@ -1,101 +1,304 @@
-use crate::core::geometry::{nearest_to_point, round_segments_intersection, BBox, Point2D};
-
-use super::entities::{Blocks, MoveAgent, Target};
+use crate::core::geometry::{round_segments_intersection, BBox, Point2D};
+
+use super::entities::{Blocks, MoveAgent};
+use crate::level::states::Level;
+use bevy::ecs::entity::Entity;
+use bevy::hierarchy::Parent;
+use bevy::prelude::{Added, DetectChanges, RemovedComponents, Res, ResMut, Resource, State};
+use bevy::transform::components::GlobalTransform;
 use bevy::{ecs::system::Query, transform::components::Transform, utils::HashSet};
-use std::collections::{HashMap, VecDeque};
+use bevy_rapier2d::dynamics::RigidBody;
+use bevy_rapier2d::geometry::Collider;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+// Route search is stopped after this count of visited cells,
+// the agent goes to the closest to target cell found so far.
+const MAX_ROUTE_CELLS: usize = 4096;
+
+/// Static obstacles of the level, they are collected once instead of on every route request.
+#[derive(Resource)]
+pub struct RouteObstacles {
+    blocks: Blocks,
+    // Blocks inflated by agent half size, used to smooth routes
+    inflated: HashMap<i32, Blocks>,
+    is_outdated: bool,
+}
+
+impl Default for RouteObstacles {
+    fn default() -> Self {
+        RouteObstacles {
+            blocks: Blocks::from(vec![]),
+            inflated: HashMap::new(),
+            is_outdated: true,
+        }
+    }
+}
+
+impl RouteObstacles {
+    pub fn from(blocks: Blocks) -> Self {
+        RouteObstacles {
+            blocks,
+            inflated: HashMap::new(),
+            is_outdated: false,
+        }
+    }
+
+    fn inflated(&mut self, half_size: i32) -> &Blocks {
+        let blocks = &self.blocks;
+        self.inflated
+            .entry(half_size)
+            .or_insert_with(|| inflate_blocks(blocks, half_size as f32))
+    }
+}
+
+// Level objects are spawned some time after the level is entered, e.g. from tiled maps,
+// so obstacles are also collected again when colliders appear or vanish
+pub fn route_obstacles_outdates(
+    level: Res<State<Level>>,
+    added_colliders: Query<(), Added<Collider>>,
+    added_blocks: Query<(), Added<Blocks>>,
+    mut removed_colliders: RemovedComponents<Collider>,
+    mut removed_blocks: RemovedComponents<Blocks>,
+    mut obstacles: ResMut<RouteObstacles>,
+) {
+    let added = !added_colliders.is_empty() || !added_blocks.is_empty();
+    let removed = removed_colliders.read().count() + removed_blocks.read().count() > 0;
+    if level.is_changed() || added || removed {
+        obstacles.is_outdated = true;
+    }
+}
 
 pub fn route_build(
-    target: Query<(&Target, &Transform)>,
     blocks: Query<&Blocks>,
+    colliders: Query<(Entity, &Collider, &GlobalTransform, Option<&Parent>)>,
+    bodies: Query<&RigidBody>,
+    mut obstacles: ResMut<RouteObstacles>,
     mut agents: Query<(&mut MoveAgent, &Transform)>,
 ) {
-    // let blocks = blocks.get_single().expect("Expected one blocks");
-
-    for (target, target_transform) in target.iter() {
-        for (mut agent, transform) in agents.iter_mut() {
-            // let x = transform.translation.x;
-            // let y = transform.translation.y;
-            // let square = BBox::from_square(x, y, agent.half_size as f32);
-
-            let target_x = target_transform.translation.x;
-            let target_y = target_transform.translation.y;
-            // let target_square = BBox::from_square(target_x, target_y, target.half_size as f32);
-            // todo: use rebuild_route instead of this when it complete
-            agent.route = vec![Point2D {
-                x: target_x as i32,
-                y: target_y as i32,
-            }]
-            // build_direct_route(&square, &target_square);
-            // rebuild_route(&square, &target_square, &blocks);
+    let needs_route = agents
+        .iter()
+        .any(|(agent, _)| agent.is_moving() && agent.route.is_empty());
+    if !needs_route {
+        return;
+    }
+
+    if obstacles.is_outdated {
+        *obstacles = RouteObstacles::from(collect_blocks(&blocks, &colliders, &bodies));
+    }
+
+    for (mut agent, transform) in agents.iter_mut() {
+        let Some(destination) = agent.destination else {
+            continue;
+        };
+        if !agent.route.is_empty() {
+            continue;
+        }
+
+        let half_size = agent.half_size as f32;
+        let x = transform.translation.x;
+        let y = transform.translation.y;
+        let square = BBox::from_square(x, y, half_size);
+        let target_square =
+            BBox::from_square(destination.x as f32, destination.y as f32, half_size);
+        agent.route = rebuild_route(&square, &target_square, &mut obstacles);
+        // Already there
+        if agent.route.is_empty() {
+            agent.destination = None;
         }
     }
 }
 
-fn rebuild_route(start: &BBox, target: &BBox, blocks: &Blocks) -> Vec<Point2D> {
-    let mut points_to_route: HashMap<Point2D, Vec<Point2D>> = HashMap::new();
-    let mut points: VecDeque<Point2D> = VecDeque::new();
-    let mut handle_points: HashSet<Point2D> = HashSet::new();
+fn collect_blocks(
+    blocks: &Query<&Blocks>,
+    colliders: &Query<(Entity, &Collider, &GlobalTransform, Option<&Parent>)>,
+    bodies: &Query<&RigidBody>,
+) -> Blocks {
+    let mut all_blocks = Blocks::from(vec![]);
+    for blocks in blocks.iter() {
+        all_blocks.blocks.extend(blocks.blocks.iter());
+    }
+    for (entity, collider, transform, parent) in colliders.iter() {
+        let body = bodies
+            .get(entity)
+            .ok()
+            .or_else(|| parent.and_then(|parent| bodies.get(parent.get()).ok()));
+        // Only static colliders are obstacles, npcs and player are moving around
+        if body != Some(&RigidBody::Fixed) {
+            continue;
+        }
+        if let Some(cuboid) = collider.as_cuboid() {
+            let half_size = cuboid.half_extents();
+            let center = transform.translation();
+            all_blocks.blocks.push(BBox::from_rect(
+                center.x,
+                center.y,
+                half_size.x,
+                half_size.y,
+            ));
+        }
+    }
+    all_blocks
+}
 
-    points.push_back(start.round_center());
-    handle_points.insert(start.round_center());
+#[derive(PartialEq, Eq)]
+struct RouteCell {
+    cost: i32,
+    point: Point2D,
+}
 
-    let mut current_point = start.round_center();
+// Reversed for min-heap: the cheapest cell goes first
+impl Ord for RouteCell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .cmp(&self.cost)
+            .then_with(|| (self.point.x, self.point.y).cmp(&(other.point.x, other.point.y)))
+    }
+}
+
+impl PartialOrd for RouteCell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
-    loop {
-        for point in &points {
-            println!("!!! point = {:?} ", point);
+/// A* over grid with agent size cells. Returns waypoints without start point,
+/// the last one is a cell that touches target.
+fn rebuild_route(start: &BBox, target: &BBox, obstacles: &mut RouteObstacles) -> Vec<Point2D> {
+    let half_size = start.half_w();
+    let blocks = &obstacles.blocks;
+    let start_point = start.round_center();
+    let target_point = target.round_center();
+
+    let mut open: BinaryHeap<RouteCell> = BinaryHeap::new();
+    let mut came_from: HashMap<Point2D, Point2D> = HashMap::new();
+    let mut costs: HashMap<Point2D, i32> = HashMap::new();
+    let mut closed: HashSet<Point2D> = HashSet::new();
+
+    open.push(RouteCell {
+        cost: route_cost(&start_point, &target_point),
+        point: start_point,
+    });
+    costs.insert(start_point, 0);
+
+    let mut closest = start_point;
+    let mut closest_distance = start_point.distance_to(&target_point);
+
+    while let Some(RouteCell { point, .. }) = open.pop() {
+        if !closed.insert(point) {
+            continue;
         }
-        println!("--------------------------------------------------------");
 
-        if points.is_empty() {
+        let square = BBox::from_square(point.x as f32, point.y as f32, half_size);
+        if square.round_intersection_with(target) > 0 {
+            closest = point;
             break;
         }
 
-        current_point = points
-            .pop_back()
-            .expect("Unexpected error: points is empty");
+        let distance = point.distance_to(&target_point);
+        if distance < closest_distance {
+            closest = point;
+            closest_distance = distance;
+        }
 
-        if there_is_direct_route(&current_point, &target.round_center(), blocks) {
+        if closed.len() >= MAX_ROUTE_CELLS {
             break;
         }
 
-        handle_points.remove(&current_point);
+        let cost = costs[&point];
+        for near in extract_available_neighborhood(&square, blocks) {
+            if closed.contains(&near) {
+                continue;
+            }
+            let near_cost = cost + route_cost(&point, &near);
+            if costs.get(&near).is_some_and(|known| *known <= near_cost) {
+                continue;
+            }
+            costs.insert(near, near_cost);
+            came_from.insert(near, point);
+            open.push(RouteCell {
+                cost: near_cost + route_cost(&near, &target_point),
+                point: near,
+            });
+        }
+    }
 
-        let near_points = extract_available_neighborhood(&start, blocks);
-        let mut curent_route = points_to_route
-            .entry(current_point)
-            .or_insert(vec![current_point])
-            .clone();
+    let mut path = vec![closest];
+    let mut current = closest;
+    while let Some(previous) = came_from.get(&current) {
+        path.push(*previous);
+        current = *previous;
+    }
+    path.reverse();
 
-        for point in near_points {
-            let point_route = points_to_route.entry(point).or_insert(vec![]);
-            if point_route.is_empty() || point_route.len() < curent_route.len() + 1 {
-                curent_route.push(point);
-                points_to_route.insert(point, curent_route.clone());
-            }
+    // Cells can touch blocks, so the route along the block side
+    // mustn't be considered as intersection
+    smooth_route(&path, obstacles.inflated(half_size as i32 - 1))
+}
 
-            if !handle_points.contains(&point) {
-                points.push_back(point);
-                handle_points.insert(point);
+fn route_cost(start: &Point2D, target: &Point2D) -> i32 {
+    (start.distance_to(target) * 10.0) as i32
+}
+
+/// Removes waypoints which can be skipped by straight line.
+/// Blocks are expected to be inflated with agent size.
+fn smooth_route(path: &[Point2D], blocks: &Blocks) -> Vec<Point2D> {
+    let mut result = vec![];
+    let mut anchor = 0;
+    while anchor + 1 < path.len() {
+        let mut next = anchor + 1;
+        for candidate in (anchor + 2..path.len()).rev() {
+            if there_is_direct_route(&path[anchor], &path[candidate], blocks) {
+                next = candidate;
+                break;
             }
         }
+        result.push(path[next]);
+        anchor = next;
     }
+    result
+}
 
-    return points_to_route.remove(&current_point).unwrap_or_default();
+fn inflate_blocks(blocks: &Blocks, half_size: f32) -> Blocks {
+    let inflated = blocks
+        .blocks
+        .iter()
+        .map(|block| BBox {
+            left: block.left - half_size,
+            top: block.top + half_size,
+            right: block.right + half_size,
+            bottom: block.bottom - half_size,
+        })
+        .collect();
+    Blocks::from(inflated)
 }
 
 fn extract_available_neighborhood(square: &BBox, blocks: &Blocks) -> Vec<Point2D> {
-    // todo: optimize do not using copy
     let mut result = Vec::with_capacity(8);
+    let Point2D { x, y } = square.round_center();
     let candidates = extract_neighborhood(square);
     for point in candidates {
         // See NOTE_SQUARE
-        let rect = BBox::from_square(point.x as f32, point.y as f32, square.half_w());
-        if can_be_occupied(&rect, &blocks) {
-            result.push(point);
+        if !cell_is_free(&point, square.half_w(), blocks) {
+            continue;
+        }
+        // Diagonal move mustn't cut block corners
+        let is_diagonal = point.x != x && point.y != y;
+        if is_diagonal
+            && !(cell_is_free(&Point2D::new(point.x, y), square.half_w(), blocks)
+                && cell_is_free(&Point2D::new(x, point.y), square.half_w(), blocks))
+        {
+            continue;
         }
+        result.push(point);
     }
-    return result;
+    result
+}
+
+fn cell_is_free(point: &Point2D, half_size: f32, blocks: &Blocks) -> bool {
+    let rect = BBox::from_square(point.x as f32, point.y as f32, half_size);
+    can_be_occupied(&rect, blocks)
 }
 
 fn extract_neighborhood(square: &BBox) -> Vec<Point2D> {
@@ -104,7 +307,7 @@ fn extract_neighborhood(square: &BBox) -> Vec<Point2D> {
     // bbox considered to be square
     // todo: make it more typesafe
     let size = 2 * square.half_w() as i32;
-    return vec![
+    vec![
         Point2D::new(x - size, y + size),
         Point2D::new(x - size, y),
         Point2D::new(x - size, y - size),
@@ -113,68 +316,56 @@ fn extract_neighborhood(square: &BBox) -> Vec<Point2D> {
         Point2D::new(x + size, y),
         Point2D::new(x + size, y + size),
         Point2D::new(x, y + size),
-    ];
+    ]
 }
 
 fn can_be_occupied(rect: &BBox, blocks: &Blocks) -> bool {
-    let result = true;
     for block in &blocks.blocks {
         if block.round_intersection_with(rect) > 0 {
             return false;
         }
     }
-    return result;
+    true
 }
 
 fn there_is_direct_route(start: &Point2D, target: &Point2D, blocks: &Blocks) -> bool {
     for block in &blocks.blocks {
-        if segment_is_intercect_rect(start, target, &block) {
-            return true;
+        if segment_is_intercect_rect(start, target, block) {
+            return false;
         }
     }
-    return false;
+    true
 }
 
 fn segment_is_intercect_rect(start: &Point2D, target: &Point2D, rect: &BBox) -> bool {
-    let side_start = Point2D::new(rect.left as i32, rect.bottom as i32);
-    let side_end = Point2D::new(rect.left as i32, rect.top as i32);
-    let left = round_segments_intersection(start, target, &side_start, &side_end).is_none();
-
-    let side_start = Point2D::new(rect.left as i32, rect.top as i32);
-    let side_end = Point2D::new(rect.right as i32, rect.top as i32);
-    let top = round_segments_intersection(start, target, &side_start, &side_end).is_none();
-
-    let side_start = Point2D::new(rect.right as i32, rect.top as i32);
-    let side_end = Point2D::new(rect.right as i32, rect.bottom as i32);
-    let right = round_segments_intersection(start, target, &side_start, &side_end).is_none();
-
-    let side_start = Point2D::new(rect.right as i32, rect.bottom as i32);
-    let side_end = Point2D::new(rect.left as i32, rect.bottom as i32);
-    let bottom = round_segments_intersection(start, target, &side_start, &side_end).is_none();
-
-    return left && top && right && bottom;
-}
+    if point_is_inside_rect(start, rect) || point_is_inside_rect(target, rect) {
+        return true;
+    }
 
-fn build_direct_route(start: &BBox, target: &BBox) -> Vec<Point2D> {
-    let mut current = *start;
-    let mut route: Vec<Point2D> = vec![];
+    let left_bottom = Point2D::new(rect.left as i32, rect.bottom as i32);
+    let left_top = Point2D::new(rect.left as i32, rect.top as i32);
+    let right_top = Point2D::new(rect.right as i32, rect.top as i32);
+    let right_bottom = Point2D::new(rect.right as i32, rect.bottom as i32);
 
-    loop {
-        if current.round_intersection_with(target) > 0 {
-            break;
+    let sides = [
+        (left_bottom, left_top),
+        (left_top, right_top),
+        (right_top, right_bottom),
+        (right_bottom, left_bottom),
+    ];
+    for (side_start, side_end) in sides {
+        if round_segments_intersection(start, target, &side_start, &side_end).is_some() {
+            return true;
         }
-
-        // todo: use neihtboors restricted with blocks `extract_available_neighborhood``
-        let near = extract_neighborhood(&current);
-        let point = nearest_to_point(&near, &target.round_center());
-        route.push(point);
-        // See NOTE_SQUARE
-        current = BBox::from_square(point.x as f32, point.y as f32, current.half_w());
     }
-
-    return route;
+    false
 }
 
+fn point_is_inside_rect(point: &Point2D, rect: &BBox) -> bool {
+    let x = point.x as f32;
+    let y = point.y as f32;
+    rect.left < x && x < rect.right && rect.bottom < y && y < rect.top
+}
 // |*| - target
 // |^| - current start
 
@@ -191,8 +382,8 @@ fn build_route_target_top_left_1() {
 
     let blocks = Blocks::from(vec![]);
 
-    let result = rebuild_route(&start, &target, &blocks);
-    let expected = vec![Point2D::new(1, 0), Point2D::new(-1, 2), Point2D::new(-3, 4)];
+    let result = rebuild_route(&start, &target, &mut RouteObstacles::from(blocks));
+    let expected = vec![Point2D::new(-3, 2)];
 
     assert_eq!(result, expected);
 }
@@ -209,8 +400,8 @@ fn build_route_target_top_right_1() {
 
     let blocks = Blocks::from(vec![]);
 
-    let result = rebuild_route(&start, &target, &blocks);
-    let expected = vec![Point2D::new(2, 2), Point2D::new(4, 2)];
+    let result = rebuild_route(&start, &target, &mut RouteObstacles::from(blocks));
+    let expected = vec![Point2D::new(4, 2)];
 
     assert_eq!(result, expected);
 }
@@ -227,14 +418,8 @@ fn build_route_target_top_right_different_sizes_1() {
 
     let blocks = Blocks::from(vec![]);
 
-    let result = rebuild_route(&start, &target, &blocks);
-    let expected = vec![
-        Point2D::new(2, 2),
-        Point2D::new(4, 4),
-        Point2D::new(6, 6),
-        Point2D::new(8, 6),
-        Point2D::new(10, 6),
-    ];
+    let result = rebuild_route(&start, &target, &mut RouteObstacles::from(blocks));
+    let expected = vec![Point2D::new(10, 6)];
     assert_eq!(result, expected);
 }
 
@@ -251,8 +436,8 @@ fn build_route_target_horizontal_1() {
 
     let blocks = Blocks::from(vec![]);
 
-    let result = rebuild_route(&start, &target, &blocks);
-    let expected = vec![Point2D::new(3, 1), Point2D::new(5, 1)];
+    let result = rebuild_route(&start, &target, &mut RouteObstacles::from(blocks));
+    let expected = vec![Point2D::new(5, 1)];
 
     assert_eq!(result, expected);
 }
@@ -270,8 +455,8 @@ fn build_route_target_vertical_1() {
 
     let blocks = Blocks::from(vec![]);
 
-    let result = rebuild_route(&start, &target, &blocks);
-    let expected = vec![Point2D::new(0, 2), Point2D::new(0, 4)];
+    let result = rebuild_route(&start, &target, &mut RouteObstacles::from(blocks));
+    let expected = vec![Point2D::new(0, 4)];
 
     assert_eq!(result, expected);
 }
@@ -289,15 +474,10 @@ fn build_route_with_simple_block_1() {
 
     let blocks = Blocks::from(vec![BBox::from_rect(7.0, 6.0, 2.0, 8.0)]);
 
-    let result = rebuild_route(&start, &target, &blocks);
+    let result = rebuild_route(&start, &target, &mut RouteObstacles::from(blocks));
     let expected = vec![
-        Point2D::new(2, 0),
-        Point2D::new(4, 0),
-        Point2D::new(6, 0),
-        Point2D::new(8, 0),
-        Point2D::new(10, 2),
-        Point2D::new(10, 4),
-        Point2D::new(12, 6),
+        Point2D::new(6, -4),
+        Point2D::new(10, -4),
         Point2D::new(12, 8),
     ];
 
@@ -319,22 +499,55 @@ fn build_route_with_complex_blocks_1() {
 
     let blocks = Blocks::from(vec![
         BBox::from_rect(1.0, 3.0, 8.0, 2.0),
-        BBox::from_rect(0.0, 4.0, 2.0, 6.0),
+        BBox::from_rect(4.0, -2.0, 1.0, 4.0),
         BBox::from_rect(8.0, 6.0, 2.0, 4.0),
     ]);
 
-    let result = rebuild_route(&start, &target, &blocks);
+    let result = rebuild_route(&start, &target, &mut RouteObstacles::from(blocks));
     let expected = vec![
-        Point2D::new(2, -2),
-        Point2D::new(2, -4),
-        Point2D::new(4, -6),
-        Point2D::new(6, -4),
-        Point2D::new(8, -2),
-        Point2D::new(10, 0),
-        Point2D::new(12, 2),
+        Point2D::new(2, -8),
+        Point2D::new(6, -8),
         Point2D::new(12, 4),
         Point2D::new(12, 6),
     ];
 
     assert_eq!(result, expected);
 }
+
+//  ******
+//  *|*| *
+//  *    *
+//  ******
+//
+//  |^|
+//
+#[test]
+fn build_route_to_enclosed_target_1() {
+    let start = BBox::from_square(0.0, 0.0, 1.0);
+    let target = BBox::from_square(0.0, 10.0, 1.0);
+
+    let blocks = Blocks::from(vec![
+        BBox::from_rect(0.0, 14.0, 6.0, 1.0),
+        BBox::from_rect(0.0, 6.0, 6.0, 1.0),
+        BBox::from_rect(-5.0, 10.0, 1.0, 3.0),
+        BBox::from_rect(5.0, 10.0, 1.0, 3.0),
+    ]);
+
+    let result = rebuild_route(&start, &target, &mut RouteObstacles::from(blocks));
+    let expected = vec![Point2D::new(0, 4)];
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn segment_is_intercect_rect_test_1() {
+    let rect = BBox::from_rect(4.0, 4.0, 2.0, 2.0);
+
+    let crossing = segment_is_intercect_rect(&Point2D::new(0, 3), &Point2D::new(8, 5), &rect);
+    let diagonal = segment_is_intercect_rect(&Point2D::new(0, 0), &Point2D::new(8, 8), &rect);
+    let outside = segment_is_intercect_rect(&Point2D::new(0, 0), &Point2D::new(8, 1), &rect);
+
+    assert!(crossing);
+    assert!(diagonal);
+    assert!(!outside);
+}
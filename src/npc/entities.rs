@@ -1,5 +1,4 @@
-use crate::animation::entities::MoveDirection;
-use crate::movement;
+use crate::movement::entities::MoveDirection;
 use bevy::asset::Handle;
 use bevy::ecs::component::Component;
 use bevy::prelude::{TextureAtlasLayout, Timer};
@@ -7,7 +6,7 @@ use bevy::prelude::{TextureAtlasLayout, Timer};
 #[derive(Component)]
 pub struct Npc {
     pub speed: f32,
-    pub move_direction: movement::entities::MoveDirection,
+    pub move_direction: MoveDirection,
 }
 
 #[derive(Component)]
@@ -15,6 +14,7 @@ pub struct MoveAnimation {
     pub timer: Timer,
     pub direction: MoveDirection,
     // todo: remove it, find more conventional method to get particular sheets
+    #[allow(dead_code)]
     pub sheet_handle: Handle<TextureAtlasLayout>,
 }
 
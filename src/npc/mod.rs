@@ -1,7 +1,11 @@
 use bevy::app::{Plugin, Update};
 use bevy::asset::{AssetServer, Assets};
+use bevy::ecs::system::EntityCommands;
 use bevy::hierarchy::BuildChildren;
 use bevy::math::UVec2;
+use bevy::math::Vec2;
+use bevy::math::Vec3;
+use bevy::prelude::Entity;
 use bevy::prelude::Query;
 use bevy::prelude::Res;
 use bevy::prelude::ResMut;
@@ -12,6 +16,8 @@ use bevy::prelude::Time;
 use bevy::prelude::Timer;
 use bevy::prelude::Transform;
 use bevy::prelude::TransformBundle;
+use bevy::prelude::With;
+use bevy::prelude::{in_state, OnExit};
 use bevy::prelude::{Bundle, Commands, IntoSystemConfigs};
 use bevy_rapier2d::dynamics::GravityScale;
 use bevy_rapier2d::dynamics::LockedAxes;
@@ -21,25 +27,37 @@ use bevy_rapier2d::geometry::Collider;
 
 pub use animations::NpcAnimations;
 
-use crate::animation::entities::MoveDirection;
 use crate::core::entities::BodyYOffset;
+use crate::core::states::GameState;
 use crate::core::z_index::DEFAULT_OBJECT_Z;
 use crate::interaction::interactors::{InteractionArea, InteractionSide, PassiveInteractor};
-use crate::movement;
 use crate::movement::entities::MoveAgent;
-use crate::movement::routes::route_build;
+use crate::movement::entities::MoveDirection;
+use crate::movement::routes::{route_build, route_obstacles_outdates, RouteObstacles};
 pub use crate::npc::entities::{IdleAnimation, MoveAnimation, Npc};
+use crate::npc::schedules::npc_behaviours;
+pub use crate::npc::schedules::{npc_behaviour_triggers, NpcBehaviour, NpcBehaviourTrigger};
 
 mod animations;
 mod entities;
+mod schedules;
+
+const NPC_WALK_SPEED: f32 = 60.0;
 
 pub struct NpcPlugin;
 
 impl Plugin for NpcPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.add_systems(Update, route_build)
+        app.init_resource::<RouteObstacles>()
+            .add_systems(Update, route_obstacles_outdates.before(route_build))
+            .add_systems(
+                Update,
+                (npc_behaviours, route_build, move_agent_moves)
+                    .chain()
+                    .run_if(in_state(GameState::Exploration)),
+            )
+            .add_systems(OnExit(GameState::Exploration), move_agents_stops)
             .add_systems(Update, idle_animation)
-            .add_systems(Update, move_agent_moves.after(route_build))
             .add_systems(Update, npc_animation.after(move_agent_moves))
             .add_systems(Update, npc_basic_animation);
     }
@@ -47,8 +65,8 @@ impl Plugin for NpcPlugin {
 
 pub fn spawn_fixed_npc(
     asset_server: &Res<AssetServer>,
-    mut commands: &mut Commands,
-    mut layouts: &mut ResMut<Assets<TextureAtlasLayout>>,
+    commands: &mut Commands,
+    layouts: &mut ResMut<Assets<TextureAtlasLayout>>,
     marker: impl Bundle,
     asset_path: String,
     move_direction: MoveDirection,
@@ -56,14 +74,77 @@ pub fn spawn_fixed_npc(
     pos_y: f32,
     pos_z: f32,
 ) {
+    spawn_npc(
+        asset_server,
+        commands,
+        layouts,
+        marker,
+        asset_path,
+        Npc {
+            speed: 0.0,
+            move_direction,
+        },
+        Vec3::new(pos_x, pos_y, pos_z),
+    );
+}
+
+/// How npc spawned by [spawn_moving_npc] walks around.
+pub struct NpcMovement {
+    pub behaviour: NpcBehaviour,
+    /// Npc with lower priority gives way to others, see [MoveAgent].
+    pub priority: i32,
+}
+
+/// Npc which moves by its behaviour, see [NpcBehaviour].
+pub fn spawn_moving_npc(
+    asset_server: &Res<AssetServer>,
+    commands: &mut Commands,
+    layouts: &mut ResMut<Assets<TextureAtlasLayout>>,
+    marker: impl Bundle,
+    asset_path: String,
+    movement: NpcMovement,
+    pos_x: f32,
+    pos_y: f32,
+    pos_z: f32,
+) {
+    let mut npc = spawn_npc(
+        asset_server,
+        commands,
+        layouts,
+        marker,
+        asset_path,
+        Npc {
+            speed: NPC_WALK_SPEED,
+            move_direction: MoveDirection::BottomIdle,
+        },
+        Vec3::new(pos_x, pos_y, pos_z),
+    );
+    // Fixed body of the npc is replaced, so physics can move it
+    npc.insert(RigidBody::Dynamic)
+        .insert(GravityScale(0.0))
+        .insert(MoveAgent::create(movement.priority, 8))
+        .insert(movement.behaviour);
+}
+
+// Sprite, animations and collider shared by all human npc
+fn spawn_npc<'a>(
+    asset_server: &Res<AssetServer>,
+    commands: &'a mut Commands,
+    layouts: &mut ResMut<Assets<TextureAtlasLayout>>,
+    marker: impl Bundle,
+    asset_path: String,
+    npc: Npc,
+    position: Vec3,
+) -> EntityCommands<'a> {
+    let move_direction = npc.move_direction;
     let moves_handle = asset_server.load(asset_path);
     let move_layout = TextureAtlasLayout::from_grid(UVec2::new(32, 46), 6, 8, None, None);
 
     let move_layout_handle = layouts.add(move_layout);
 
     let animations = NpcAnimations::default();
-    commands
-        .spawn(RigidBody::Fixed)
+    let mut entity = commands.spawn(RigidBody::Fixed);
+    entity
         .insert((
             SpriteBundle {
                 texture: moves_handle,
@@ -86,13 +167,8 @@ pub fn spawn_fixed_npc(
         .insert(animations)
         .insert(Velocity::zero())
         .insert(LockedAxes::ROTATION_LOCKED)
-        .insert(TransformBundle::from(Transform::from_xyz(
-            pos_x, pos_y, pos_z,
-        )))
-        .insert(Npc {
-            speed: 0.0,
-            move_direction: movement::entities::MoveDirection::TopIdle,
-        })
+        .insert(TransformBundle::from(Transform::from_translation(position)))
+        .insert(npc)
         .insert(BodyYOffset::create(20.0))
         .with_children(|children| {
             children
@@ -107,6 +183,7 @@ pub fn spawn_fixed_npc(
             area: InteractionArea::from_sizes(8.0, 20.0),
             side: InteractionSide::Bottom,
         });
+    entity
 }
 
 pub fn spawn_formidable_dog(
@@ -120,7 +197,7 @@ pub fn spawn_formidable_dog(
     speed: f32,
     gravity_scale: f32,
 ) {
-    let start_move_direction = MoveDirection::ForwardIdle;
+    let start_move_direction = MoveDirection::BottomIdle;
 
     let moves_handle = asset_server.load("npc/formidable_dog.png");
     let move_layout = TextureAtlasLayout::from_grid(UVec2::new(24, 24), 4, 8, None, None);
@@ -158,7 +235,7 @@ pub fn spawn_formidable_dog(
         )))
         .insert(Npc {
             speed,
-            move_direction: movement::entities::MoveDirection::TopIdle,
+            move_direction: start_move_direction,
         })
         .insert(BodyYOffset::create(8.0))
         .with_children(|children| {
@@ -173,50 +250,20 @@ pub fn spawn_formidable_dog(
         .insert(PassiveInteractor {
             area: InteractionArea::from_sizes(8.0, 20.0),
             side: InteractionSide::Bottom,
-        })
-        .insert(MoveAgent {
-            priority: 1,
-            half_size: 8,
-            route: vec![],
         });
 }
 
 pub fn npc_animation(
-    mut player_query: Query<(
-        &mut MoveAnimation,
-        &mut TextureAtlas,
-        &Npc,
-        &NpcAnimations,
-        &Velocity,
-    )>,
+    mut npc_query: Query<(&mut MoveAnimation, &mut TextureAtlas, &Npc, &NpcAnimations)>,
 ) {
-    for (mut move_animation, mut sprite, npc, npc_animation, rb_vels) in player_query.iter_mut() {
-        if rb_vels.linvel.x == 0.0 && rb_vels.linvel.y == 0.0 && move_animation.direction.is_idle()
-        {
-            return;
+    for (mut move_animation, mut sprite, npc, npc_animation) in npc_query.iter_mut() {
+        if move_animation.direction == npc.move_direction {
+            continue;
         }
-        let new_animation = match npc.move_direction {
-            movement::entities::MoveDirection::Top => MoveDirection::BackwardMove,
-            movement::entities::MoveDirection::LeftTop => MoveDirection::BackwardMove,
-            movement::entities::MoveDirection::Left => MoveDirection::LeftMove,
-            movement::entities::MoveDirection::LeftBottom => MoveDirection::ForwardMove,
-            movement::entities::MoveDirection::Bottom => MoveDirection::ForwardMove,
-            movement::entities::MoveDirection::RightBottom => MoveDirection::ForwardMove,
-            movement::entities::MoveDirection::Right => MoveDirection::RightMove,
-            movement::entities::MoveDirection::RightTop => MoveDirection::BackwardMove,
-            movement::entities::MoveDirection::TopIdle => MoveDirection::BackwardIdle,
-            movement::entities::MoveDirection::LeftTopIdle => MoveDirection::BackwardIdle,
-            movement::entities::MoveDirection::LeftIdle => MoveDirection::LeftIdle,
-            movement::entities::MoveDirection::LeftBottomIdle => MoveDirection::ForwardIdle,
-            movement::entities::MoveDirection::BottomIdle => MoveDirection::ForwardIdle,
-            movement::entities::MoveDirection::RightBottomIdle => MoveDirection::ForwardIdle,
-            movement::entities::MoveDirection::RightIdle => MoveDirection::RightIdle,
-            movement::entities::MoveDirection::RightTopIdle => MoveDirection::BackwardIdle,
-        };
-        let prev_animation = move_animation.direction != new_animation;
-        move_animation.direction = new_animation;
-        if prev_animation {
-            let sprite_part = npc_animation.moves[&move_animation.direction];
+        let prev_sprite_part = npc_animation.moves[&move_animation.direction];
+        move_animation.direction = npc.move_direction;
+        let sprite_part = npc_animation.moves[&move_animation.direction];
+        if prev_sprite_part != sprite_part {
             sprite.index = sprite_part.0 as usize;
             move_animation.timer =
                 Timer::from_seconds(sprite_part.2, bevy::time::TimerMode::Repeating);
@@ -224,11 +271,25 @@ pub fn npc_animation(
     }
 }
 
-pub fn move_agent_moves(mut agents: Query<(&mut MoveAgent, &mut Npc, &mut Velocity, &Transform)>) {
-    for (mut agent, mut npc, mut velocity, transform) in agents.iter_mut() {
+pub fn move_agent_moves(
+    mut agents: Query<(Entity, &mut MoveAgent, &mut Npc, &mut Velocity, &Transform)>,
+) {
+    let others: Vec<(Entity, i32, i32, Vec2)> = agents
+        .iter()
+        .map(|(entity, agent, _, _, transform)| {
+            (
+                entity,
+                agent.priority,
+                agent.half_size,
+                transform.translation.truncate(),
+            )
+        })
+        .collect();
+
+    for (entity, mut agent, mut npc, mut velocity, transform) in agents.iter_mut() {
         let route = &agent.route;
         if route.is_empty() {
-            return;
+            continue;
         }
         let target = route.first().expect("Route musn't be empty");
         let translation = transform.translation;
@@ -236,37 +297,35 @@ pub fn move_agent_moves(mut agents: Query<(&mut MoveAgent, &mut Npc, &mut Veloci
         let y = translation.y;
         let target_x = target.x as f32;
         let target_y = target.y as f32;
-        let eps = 24.0;
+        let eps = agent.half_size as f32;
+
+        let position = translation.truncate();
+        let route_direction = Vec2::new(target_x - x, target_y - y);
+        let must_give_way = others
+            .iter()
+            .any(|(other, priority, half_size, other_position)| {
+                let is_more_important =
+                    (*priority, entity.index()) < (agent.priority, other.index());
+                let distance = (2 * (agent.half_size + half_size)) as f32;
+                let shift = *other_position - position;
+                *other != entity
+                    && !is_more_important
+                    && shift.length() < distance
+                    && shift.dot(route_direction) > 0.0
+            });
+        if must_give_way {
+            npc.move_direction = npc.move_direction.to_idle();
+            velocity.linvel = Vec2::ZERO;
+            continue;
+        }
+
         if (x - target_x).abs() < eps && (y - target_y).abs() < eps {
-            let route = &mut agent.route;
-            route.pop();
-            npc.move_direction = match npc.move_direction {
-                movement::entities::MoveDirection::Top => {
-                    movement::entities::MoveDirection::TopIdle
-                }
-                movement::entities::MoveDirection::LeftTop => {
-                    movement::entities::MoveDirection::TopIdle
-                }
-                movement::entities::MoveDirection::Left => {
-                    movement::entities::MoveDirection::LeftIdle
-                }
-                movement::entities::MoveDirection::LeftBottom => {
-                    movement::entities::MoveDirection::LeftIdle
-                }
-                movement::entities::MoveDirection::Bottom => {
-                    movement::entities::MoveDirection::BottomIdle
-                }
-                movement::entities::MoveDirection::RightBottom => {
-                    movement::entities::MoveDirection::BottomIdle
-                }
-                movement::entities::MoveDirection::Right => {
-                    movement::entities::MoveDirection::RightIdle
-                }
-                movement::entities::MoveDirection::RightTop => {
-                    movement::entities::MoveDirection::RightIdle
-                }
-                _ => movement::entities::MoveDirection::TopIdle,
-            };
+            agent.route.remove(0);
+            if !agent.route.is_empty() {
+                continue;
+            }
+            agent.destination = None;
+            npc.move_direction = npc.move_direction.to_idle();
             velocity.linvel.x = 0.0;
             velocity.linvel.y = 0.0;
         } else {
@@ -284,36 +343,21 @@ pub fn move_agent_moves(mut agents: Query<(&mut MoveAgent, &mut Npc, &mut Veloci
             if (target_y - y).abs() < eps {
                 y_sign = 0;
             }
-            velocity.linvel.x = npc.speed * x_sign as f32;
-            velocity.linvel.y = npc.speed * y_sign as f32;
-            if x_sign == 0 && y_sign > 1 {
-                npc.move_direction = movement::entities::MoveDirection::Top;
-            }
-            if x_sign == -1 && y_sign > 1 {
-                npc.move_direction = movement::entities::MoveDirection::LeftTop;
-            }
-            if x_sign == -1 && y_sign == 0 {
-                npc.move_direction = movement::entities::MoveDirection::Left;
-            }
-            if x_sign == -1 && y_sign == -1 {
-                npc.move_direction = movement::entities::MoveDirection::LeftBottom;
-            }
-            if x_sign == 0 && y_sign == -1 {
-                npc.move_direction = movement::entities::MoveDirection::Bottom;
-            }
-            if x_sign == 1 && y_sign == -1 {
-                npc.move_direction = movement::entities::MoveDirection::RightBottom;
-            }
-            if x_sign == 1 && y_sign == 0 {
-                npc.move_direction = movement::entities::MoveDirection::Right;
-            }
-            if x_sign == 1 && y_sign == 1 {
-                npc.move_direction = movement::entities::MoveDirection::RightTop;
+            if let Some(direction) = MoveDirection::from_signs(x_sign, y_sign) {
+                npc.move_direction = direction;
+                velocity.linvel = direction.to_vector() * npc.speed;
             }
         }
     }
 }
 
+pub fn move_agents_stops(mut agents: Query<(&mut Npc, &mut Velocity), With<MoveAgent>>) {
+    for (mut npc, mut velocity) in agents.iter_mut() {
+        npc.move_direction = npc.move_direction.to_idle();
+        velocity.linvel = Vec2::ZERO;
+    }
+}
+
 pub fn npc_basic_animation(
     time: Res<Time>,
     mut animation_query: Query<(&mut MoveAnimation, &mut TextureAtlas, &NpcAnimations)>,
@@ -0,0 +1,134 @@
+use bevy::prelude::{
+    Commands, Component, Entity, Query, Res, State, States, Time, Timer, TimerMode,
+};
+
+use crate::core::geometry::Point2D;
+use crate::movement::entities::{MoveAgent, MoveArea, MoveDirection};
+use crate::npc::entities::Npc;
+
+/// What moving npc does when it's not on the way.
+#[derive(Component, Clone)]
+pub enum NpcBehaviour {
+    Idle,
+    /// Goes by waypoints in a loop, stops for a pause on each one.
+    Patrol {
+        waypoints: Vec<Point2D>,
+        next: usize,
+        pause: Timer,
+    },
+    /// Goes to random points of the area, stops for a pause on each one.
+    Wander {
+        area: MoveArea,
+        pause: Timer,
+    },
+    /// Goes to the point once, then stands there looking in the direction.
+    GoToThenIdle {
+        point: Point2D,
+        direction: MoveDirection,
+        started: bool,
+    },
+}
+
+impl NpcBehaviour {
+    pub fn patrol(waypoints: Vec<Point2D>, pause_seconds: f32) -> Self {
+        NpcBehaviour::Patrol {
+            waypoints,
+            next: 0,
+            pause: Timer::from_seconds(pause_seconds, TimerMode::Repeating),
+        }
+    }
+
+    pub fn wander(area: MoveArea, pause_seconds: f32) -> Self {
+        NpcBehaviour::Wander {
+            area,
+            pause: Timer::from_seconds(pause_seconds, TimerMode::Repeating),
+        }
+    }
+
+    pub fn go_to(point: Point2D, direction: MoveDirection) -> Self {
+        NpcBehaviour::GoToThenIdle {
+            point,
+            direction,
+            started: false,
+        }
+    }
+}
+
+/// Replaces npc behaviour when the state is reached or passed, e.g. quest one,
+/// so npc spawned after that behaves the same.
+#[derive(Component)]
+pub struct NpcBehaviourTrigger<S: States> {
+    pub state: S,
+    pub behaviour: NpcBehaviour,
+}
+
+pub fn npc_behaviours(
+    time: Res<Time>,
+    mut npc_query: Query<(&mut NpcBehaviour, &mut MoveAgent, &mut Npc)>,
+) {
+    for (mut behaviour, mut agent, mut npc) in npc_query.iter_mut() {
+        if agent.is_moving() {
+            continue;
+        }
+
+        let mut is_finished = false;
+        match behaviour.as_mut() {
+            NpcBehaviour::Idle => {}
+            NpcBehaviour::Patrol {
+                waypoints,
+                next,
+                pause,
+            } => {
+                if waypoints.is_empty() {
+                    continue;
+                }
+                pause.tick(time.delta());
+                if pause.just_finished() {
+                    agent.go_to(waypoints[*next]);
+                    *next = (*next + 1) % waypoints.len();
+                }
+            }
+            NpcBehaviour::Wander { area, pause } => {
+                pause.tick(time.delta());
+                if pause.just_finished() {
+                    agent.go_to(area.random_point());
+                }
+            }
+            NpcBehaviour::GoToThenIdle {
+                point,
+                direction,
+                started,
+            } => {
+                if *started {
+                    npc.move_direction = *direction;
+                    is_finished = true;
+                } else {
+                    agent.go_to(*point);
+                    *started = true;
+                }
+            }
+        }
+        if is_finished {
+            *behaviour = NpcBehaviour::Idle;
+        }
+    }
+}
+
+pub fn npc_behaviour_triggers<S: States + Ord>(
+    mut commands: Commands,
+    state: Res<State<S>>,
+    mut query: Query<(Entity, &NpcBehaviourTrigger<S>, Option<&mut MoveAgent>)>,
+) {
+    for (entity, trigger, agent) in query.iter_mut() {
+        if *state.get() >= trigger.state {
+            // New behaviour starts right now, not after the current way
+            if let Some(mut agent) = agent {
+                agent.stop();
+            }
+            commands
+                .entity(entity)
+                .insert(trigger.behaviour.clone())
+                .remove::<NpcBehaviourTrigger<S>>();
+        }
+    }
+}
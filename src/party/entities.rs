@@ -13,10 +13,14 @@ pub struct PartyMember {
 }
 
 impl PartyMember {
+    pub fn is_downed(&self) -> bool {
+        self.target.is_defeated()
+    }
+
     pub fn initial_formidable_face() -> Self {
         PartyMember {
             id: 0,
-            name: "Грозный Личик".to_string(),
+            name: "character.formidable_face".to_string(),
             base_attack: 15,
             asset_path: "".to_string(),
             target: TargetProps {
@@ -44,7 +48,7 @@ impl PartyMember {
     pub fn initial_formidable_dog() -> Self {
         PartyMember {
             id: 1,
-            name: "Грозный Пёс".to_string(),
+            name: "character.formidable_dog".to_string(),
             base_attack: 15,
             asset_path: "".to_string(),
             target: TargetProps {
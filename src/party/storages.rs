@@ -1,13 +1,19 @@
 use bevy::prelude::Resource;
+use bevy::utils::HashSet;
 
 use crate::party::entities::PartyMember;
 use crate::rpg::TargetProps;
-use crate::rpg::{Ability, ConsumableItem, DirectionalAttack};
+use crate::rpg::{Ability, ConsumableItem, DirectionalAttack, EquipmentItem, Loot, QuestItem};
 
 #[derive(Resource)]
 pub struct PartyStateStorage {
     members: Vec<PartyMember>,
     consumable: Vec<ConsumableItem>,
+    equipment: Vec<EquipmentItem>,
+    quest_items: Vec<QuestItem>,
+    money: i32,
+    // Dialog variants chosen during the game: dialog id, stick id and variant position
+    seen_variants: HashSet<(usize, usize, usize)>,
 }
 
 impl Default for PartyStateStorage {
@@ -15,6 +21,10 @@ impl Default for PartyStateStorage {
         PartyStateStorage {
             members: vec![PartyMember::initial_formidable_face()],
             consumable: vec![ConsumableItem::default_dumplings()],
+            equipment: vec![],
+            quest_items: vec![],
+            money: 30,
+            seen_variants: HashSet::new(),
         }
     }
 }
@@ -44,6 +54,15 @@ impl PartyStateStorage {
         self.members[id].abilities = abilities;
     }
 
+    pub fn restore_party(&mut self) {
+        for member in self.members.iter_mut() {
+            let health = &mut member.target.health;
+            health.current = health.max;
+            let energy = &mut member.target.energy;
+            energy.current = energy.max;
+        }
+    }
+
     pub fn get_consumables(&self) -> Vec<ConsumableItem> {
         self.consumable.clone()
     }
@@ -61,4 +80,58 @@ impl PartyStateStorage {
     pub fn remove_consumable_by_id(&mut self, id: usize) {
         self.consumable.remove(id);
     }
+
+    pub fn get_equipment(&self) -> Vec<EquipmentItem> {
+        self.equipment.clone()
+    }
+
+    pub fn add_equipment(&mut self, item: EquipmentItem) {
+        self.equipment.push(item);
+    }
+
+    pub fn get_quest_items(&self) -> Vec<QuestItem> {
+        self.quest_items.clone()
+    }
+
+    pub fn add_quest_item(&mut self, item: QuestItem) {
+        self.quest_items.push(item);
+    }
+
+    pub fn add_loot(&mut self, loot: Vec<Loot>) {
+        for item in loot {
+            match item {
+                Loot::Consumable(value) => self.add_consumable(value),
+                Loot::Equipment(value) => self.add_equipment(value),
+                Loot::Money(value) => self.add_money(value),
+                Loot::Quest(value) => self.add_quest_item(value),
+            }
+        }
+    }
+
+    pub fn get_money(&self) -> i32 {
+        self.money
+    }
+
+    pub fn add_money(&mut self, amount: i32) {
+        self.money += amount;
+    }
+
+    // returns false and keeps money untouched if there is not enough
+    pub fn take_money(&mut self, amount: i32) -> bool {
+        if self.money < amount {
+            return false;
+        }
+        self.money -= amount;
+        true
+    }
+
+    pub fn mark_variant_seen(&mut self, dialog_id: usize, stick_id: usize, variant_pos: usize) {
+        self.seen_variants
+            .insert((dialog_id, stick_id, variant_pos));
+    }
+
+    pub fn is_variant_seen(&self, dialog_id: usize, stick_id: usize, variant_pos: usize) -> bool {
+        self.seen_variants
+            .contains(&(dialog_id, stick_id, variant_pos))
+    }
 }
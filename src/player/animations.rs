@@ -2,30 +2,18 @@ use std::collections::HashMap;
 
 use bevy::prelude::Resource;
 
-use crate::animation::entities::MoveDirection;
+use crate::animation::entities::{four_sides_sheet, SheetRow};
+use crate::movement::entities::MoveDirection;
 
 #[derive(Resource)]
 pub struct PlayerAnimations {
-    pub moves: HashMap<MoveDirection, (u32, u32, f32)>,
+    pub moves: HashMap<MoveDirection, SheetRow>,
 }
 
 impl PlayerAnimations {
     pub fn default() -> PlayerAnimations {
         PlayerAnimations {
-            moves: Self::moves(),
+            moves: four_sides_sheet(6, 0.4, 0.15),
         }
     }
-
-    fn moves() -> HashMap<MoveDirection, (u32, u32, f32)> {
-        let mut moves = HashMap::with_capacity(8);
-        moves.insert(MoveDirection::ForwardIdle, (0, 5, 0.4));
-        moves.insert(MoveDirection::LeftIdle, (6, 11, 0.4));
-        moves.insert(MoveDirection::BackwardIdle, (12, 17, 0.4));
-        moves.insert(MoveDirection::RightIdle, (18, 23, 0.4));
-        moves.insert(MoveDirection::ForwardMove, (24, 29, 0.15));
-        moves.insert(MoveDirection::LeftMove, (30, 35, 0.15));
-        moves.insert(MoveDirection::BackwardMove, (36, 41, 0.15));
-        moves.insert(MoveDirection::RightMove, (42, 47, 0.15));
-        moves
-    }
 }
@@ -3,7 +3,7 @@ use bevy::prelude::Component;
 use bevy::sprite::TextureAtlasLayout;
 use bevy::time::Timer;
 
-use crate::animation::entities::MoveDirection;
+use crate::movement::entities::MoveDirection;
 
 #[derive(Component, Debug)]
 pub struct Player {
@@ -18,6 +18,7 @@ pub struct MoveAnimation {
     pub timer: Timer,
     pub direction: MoveDirection,
     // todo: remove it, find more conventional method to get particular sheets
+    #[allow(dead_code)]
     pub sheet_handle: Handle<TextureAtlasLayout>,
 }
 
@@ -0,0 +1,177 @@
+use std::collections::VecDeque;
+
+use bevy::math::Vec2;
+use bevy::prelude::{Changed, Component, Query, Res, ResMut, Resource, Transform, With, Without};
+use bevy_rapier2d::dynamics::Velocity;
+
+use crate::movement::entities::MoveDirection;
+use crate::npc::Npc;
+use crate::party::PartyStateStorage;
+use crate::player::entities::{Player, PlayerPosition};
+
+// Distance between breadcrumbs of player path
+const BREADCRUMB_STEP: f32 = 4.0;
+// Distance along the path between player and first follower and between followers
+const FOLLOWER_SPACING: f32 = 24.0;
+const FOLLOWER_STOP_DISTANCE: f32 = 4.0;
+// Follower which lags behind moves faster to catch up
+const FOLLOWER_CATCH_UP_FACTOR: f32 = 1.5;
+const MAX_BREADCRUMBS: usize = 256;
+
+/// Party member which follows the player in the world.
+#[derive(Component)]
+pub struct Follower {
+    pub member_id: usize,
+}
+
+/// Recorded path of the player, the newest point goes first.
+/// Followers go by this path, so they avoid the same blocks as the player.
+#[derive(Resource, Default)]
+pub struct PlayerTrail {
+    points: VecDeque<Vec2>,
+}
+
+impl PlayerTrail {
+    pub fn record(&mut self, point: Vec2) {
+        let is_far_enough = self
+            .points
+            .front()
+            .is_none_or(|last| last.distance(point) >= BREADCRUMB_STEP);
+        if !is_far_enough {
+            return;
+        }
+        self.points.push_front(point);
+        self.points.truncate(MAX_BREADCRUMBS);
+    }
+
+    /// Starts the path from scratch, as if the player came from the `behind` direction.
+    pub fn reset(&mut self, point: Vec2, behind: Vec2) {
+        self.points.clear();
+        let direction = behind.normalize_or_zero();
+        let mut distance = 0.0;
+        while self.points.len() < MAX_BREADCRUMBS {
+            self.points.push_back(point + direction * distance);
+            distance += BREADCRUMB_STEP;
+        }
+    }
+
+    /// Point of the path at `distance` from the player, or the oldest known point.
+    pub fn point_behind(&self, distance: f32) -> Option<Vec2> {
+        let mut passed = 0.0;
+        let mut previous = *self.points.front()?;
+        for point in self.points.iter().skip(1) {
+            let segment = previous.distance(*point);
+            if segment > 0.0 && passed + segment >= distance {
+                return Some(previous.lerp(*point, (distance - passed) / segment));
+            }
+            passed += segment;
+            previous = *point;
+        }
+        Some(previous)
+    }
+}
+
+pub fn player_trail_records(
+    mut trail: ResMut<PlayerTrail>,
+    player_query: Query<&Transform, With<Player>>,
+) {
+    for transform in player_query.iter() {
+        trail.record(transform.translation.truncate());
+    }
+}
+
+pub fn followers_places(
+    mut trail: ResMut<PlayerTrail>,
+    party_state: Res<PartyStateStorage>,
+    mut followers_query: Query<(&Follower, &mut Transform, &mut Velocity), Without<Player>>,
+    next_player_position_query: Query<&PlayerPosition, Changed<PlayerPosition>>,
+) {
+    // Followers stand in a row behind the player, so they never appear inside each other
+    for position in next_player_position_query.iter() {
+        trail.reset(Vec2::new(position.x, position.y), Vec2::new(1.0, -1.0));
+        for (follower, mut transform, mut velocity) in followers_query.iter_mut() {
+            let Some(order) = follower_order(&party_state, follower) else {
+                continue;
+            };
+            if let Some(point) = trail.point_behind(FOLLOWER_SPACING * (order + 1) as f32) {
+                transform.translation.x = point.x;
+                transform.translation.y = point.y;
+            }
+            velocity.linvel = Vec2::ZERO;
+        }
+    }
+}
+
+pub fn followers_moves(
+    trail: Res<PlayerTrail>,
+    party_state: Res<PartyStateStorage>,
+    mut followers_query: Query<(&Follower, &mut Npc, &mut Velocity, &Transform), Without<Player>>,
+) {
+    for (follower, mut npc, mut velocity, transform) in followers_query.iter_mut() {
+        let target = follower_order(&party_state, follower)
+            .and_then(|order| trail.point_behind(FOLLOWER_SPACING * (order + 1) as f32));
+        let Some(target) = target else {
+            continue;
+        };
+
+        let shift = target - transform.translation.truncate();
+        let distance = shift.length();
+        if distance < FOLLOWER_STOP_DISTANCE {
+            velocity.linvel = Vec2::ZERO;
+            npc.move_direction = npc.move_direction.to_idle();
+            continue;
+        }
+
+        let mut speed = npc.speed;
+        if distance > FOLLOWER_SPACING {
+            speed *= FOLLOWER_CATCH_UP_FACTOR;
+        }
+        velocity.linvel = shift / distance * speed;
+
+        if let Some(direction) = MoveDirection::from_vector(shift) {
+            npc.move_direction = direction;
+        }
+    }
+}
+
+pub fn followers_stops(mut followers_query: Query<(&mut Npc, &mut Velocity), With<Follower>>) {
+    for (mut npc, mut velocity) in followers_query.iter_mut() {
+        velocity.linvel = Vec2::ZERO;
+        npc.move_direction = npc.move_direction.to_idle();
+    }
+}
+
+// Position in the line of followers, members not in the party don't follow
+fn follower_order(party_state: &PartyStateStorage, follower: &Follower) -> Option<usize> {
+    party_state
+        .get_party_members()
+        .iter()
+        .filter(|member| member.id != 0)
+        .position(|member| member.id == follower.member_id)
+}
+
+#[test]
+fn player_trail_point_behind_test() {
+    let mut trail = PlayerTrail::default();
+    assert_eq!(trail.point_behind(10.0), None);
+
+    trail.record(Vec2::new(0.0, 0.0));
+    trail.record(Vec2::new(1.0, 0.0));
+    trail.record(Vec2::new(8.0, 0.0));
+    trail.record(Vec2::new(8.0, 8.0));
+
+    assert_eq!(trail.point_behind(0.0), Some(Vec2::new(8.0, 8.0)));
+    assert_eq!(trail.point_behind(4.0), Some(Vec2::new(8.0, 4.0)));
+    assert_eq!(trail.point_behind(12.0), Some(Vec2::new(4.0, 0.0)));
+    assert_eq!(trail.point_behind(100.0), Some(Vec2::new(0.0, 0.0)));
+}
+
+#[test]
+fn player_trail_reset_test() {
+    let mut trail = PlayerTrail::default();
+    trail.record(Vec2::new(50.0, 50.0));
+    trail.reset(Vec2::new(10.0, 10.0), Vec2::new(1.0, 0.0));
+
+    assert_eq!(trail.point_behind(0.0), Some(Vec2::new(10.0, 10.0)));
+    assert_eq!(trail.point_behind(24.0), Some(Vec2::new(34.0, 10.0)));
+}
@@ -1,3 +1,4 @@
 pub mod animations;
 pub mod entities;
+pub mod followers;
 pub mod plugins;
@@ -1,6 +1,5 @@
-use bevy::ecs::query::QuerySingleError;
-use bevy::math::UVec2;
-use bevy::prelude::{in_state, Changed, Mut, TransformBundle, Without};
+use bevy::math::{UVec2, Vec2};
+use bevy::prelude::{in_state, Changed, OnExit, TransformBundle, Without};
 use bevy::sprite::SpriteBundle;
 use bevy::{
     prelude::AssetServer,
@@ -9,7 +8,6 @@ use bevy::{
     prelude::ButtonInput,
     prelude::Commands,
     prelude::IntoSystemConfigs,
-    prelude::KeyCode,
     prelude::Plugin,
     prelude::Query,
     prelude::Res,
@@ -33,32 +31,37 @@ use super::{
 };
 use crate::core::entities::MainCamera;
 use crate::core::states::GameState;
-use crate::party::PartyMember;
-use crate::player::entities::{FormidableDog, PlayerPosition};
+use crate::input::InputAction;
+use crate::player::entities::PlayerPosition;
+use crate::player::followers::{
+    followers_moves, followers_places, followers_stops, player_trail_records, PlayerTrail,
+};
 use crate::{
-    animation::entities::MoveDirection,
     core::{entities::BodyYOffset, z_index::DEFAULT_OBJECT_Z},
     interaction::interactors::{ActiveInteractor, InteractionArea, InteractionSide},
-    movement::entities::Target,
+    movement::entities::MoveDirection,
 };
 
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.add_systems(Startup, spawn_player)
+        app.init_resource::<PlayerTrail>()
+            .add_systems(Startup, spawn_player)
             .add_systems(Startup, spawn_initial_position)
             .add_systems(
                 Update,
                 player_movement.run_if(in_state(GameState::Exploration)),
             )
+            .add_systems(Update, (change_player_position_handle, followers_places))
             .add_systems(
                 Update,
-                (
-                    change_player_position_handle,
-                    change_party_members_position_handle,
-                ),
+                (player_trail_records, followers_moves)
+                    .chain()
+                    .after(player_movement)
+                    .run_if(in_state(GameState::Exploration)),
             )
+            .add_systems(OnExit(GameState::Exploration), followers_stops)
             .add_systems(
                 Update,
                 camera_movement
@@ -101,28 +104,13 @@ fn change_player_position_handle(
     }
 }
 
-fn change_party_members_position_handle(
-    mut party_member_query: Query<&mut Transform, With<FormidableDog>>,
-    next_player_position_query: Query<&PlayerPosition, Changed<PlayerPosition>>,
-) {
-    for position in next_player_position_query.iter() {
-        match party_member_query.get_single_mut() {
-            Ok(mut transform) => {
-                transform.translation.x = position.x + 20.0;
-                transform.translation.y = position.y - 20.0;
-            }
-            Err(_) => {}
-        }
-    }
-}
-
 fn spawn_player(
     asset_server: Res<AssetServer>,
     mut commands: Commands,
     character_animations: Res<PlayerAnimations>,
     mut layouts: ResMut<Assets<TextureAtlasLayout>>,
 ) {
-    let start_move_direction = MoveDirection::ForwardIdle;
+    let start_move_direction = MoveDirection::BottomIdle;
 
     let moves_handle = asset_server.load("npc/formidable_face.png");
     let move_layout = TextureAtlasLayout::from_grid(UVec2::new(32, 46), 6, 8, None, None);
@@ -171,34 +159,26 @@ fn spawn_player(
         .insert(ActiveInteractor {
             area: InteractionArea::from_sizes(8.0, 20.0),
             side: InteractionSide::Bottom,
-        })
-        .insert(Target { half_size: 16 });
+        });
 }
 
 fn player_movement(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    actions: Res<ButtonInput<InputAction>>,
     mut player_info: Query<(&Player, &mut Velocity)>,
 ) {
     for (player, mut velocity) in player_info.iter_mut() {
-        let up = keyboard.pressed(KeyCode::KeyW);
-        let down = keyboard.pressed(KeyCode::KeyS);
-        let left = keyboard.pressed(KeyCode::KeyA);
-        let right = keyboard.pressed(KeyCode::KeyD);
+        let up = actions.pressed(InputAction::MoveUp);
+        let down = actions.pressed(InputAction::MoveDown);
+        let left = actions.pressed(InputAction::MoveLeft);
+        let right = actions.pressed(InputAction::MoveRight);
 
         let x_axis = -(left as i8) + right as i8;
         let y_axis = -(down as i8) + up as i8;
 
-        if x_axis != 0 {
-            velocity.linvel.x = player.speed * (x_axis as f32);
-        } else {
-            velocity.linvel.x = 0.0;
-        }
-
-        if y_axis != 0 {
-            velocity.linvel.y = player.speed * (y_axis as f32);
-        } else {
-            velocity.linvel.y = 0.0;
-        }
+        velocity.linvel = match MoveDirection::from_signs(x_axis as i32, y_axis as i32) {
+            Some(direction) => direction.to_vector() * player.speed,
+            None => Vec2::ZERO,
+        };
     }
 }
 
@@ -213,44 +193,19 @@ pub fn camera_movement(
 }
 
 fn player_animation(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
     character_animations: Res<PlayerAnimations>,
     mut player_query: Query<(&mut MoveAnimation, &mut TextureAtlas, &Velocity), With<Player>>,
 ) {
-    for (mut move_animation, mut sprite, rb_vels) in player_query.iter_mut() {
-        let mut restart_animation = false;
-        if rb_vels.linvel.x == 0.0 && rb_vels.linvel.y == 0.0 {
-            if keyboard_input.just_released(KeyCode::KeyA) {
-                move_animation.direction = MoveDirection::LeftIdle;
-                restart_animation = true;
-            } else if keyboard_input.just_released(KeyCode::KeyD) {
-                move_animation.direction = MoveDirection::RightIdle;
-                restart_animation = true;
-            } else if keyboard_input.just_released(KeyCode::KeyW) {
-                move_animation.direction = MoveDirection::BackwardIdle;
-                restart_animation = true;
-            } else if keyboard_input.just_released(KeyCode::KeyS) {
-                move_animation.direction = MoveDirection::ForwardIdle;
-                restart_animation = true;
-            }
-        }
-
-        if keyboard_input.just_pressed(KeyCode::KeyA) {
-            move_animation.direction = MoveDirection::LeftMove;
-            restart_animation = true;
-        } else if keyboard_input.just_pressed(KeyCode::KeyD) {
-            move_animation.direction = MoveDirection::RightMove;
-            restart_animation = true;
-        } else if keyboard_input.just_pressed(KeyCode::KeyW) {
-            move_animation.direction = MoveDirection::BackwardMove;
-            restart_animation = true;
-        } else if keyboard_input.just_pressed(KeyCode::KeyS) {
-            move_animation.direction = MoveDirection::ForwardMove;
-            restart_animation = true;
+    for (mut move_animation, mut sprite, velocity) in player_query.iter_mut() {
+        let direction = MoveDirection::from_vector(velocity.linvel)
+            .unwrap_or(move_animation.direction.to_idle());
+        if direction == move_animation.direction {
+            continue;
         }
-
-        if restart_animation {
-            let sprite_part = character_animations.moves[&move_animation.direction];
+        let prev_sprite_part = character_animations.moves[&move_animation.direction];
+        move_animation.direction = direction;
+        let sprite_part = character_animations.moves[&move_animation.direction];
+        if prev_sprite_part != sprite_part {
             sprite.index = sprite_part.0 as usize;
             move_animation.timer =
                 Timer::from_seconds(sprite_part.2, bevy::time::TimerMode::Repeating);
@@ -259,20 +214,20 @@ fn player_animation(
 }
 
 fn change_interaction_area(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    actions: Res<ButtonInput<InputAction>>,
     mut player_query: Query<&mut ActiveInteractor, With<Player>>,
 ) {
     for mut interactor in player_query.iter_mut() {
-        if keyboard_input.just_released(KeyCode::KeyA) {
+        if actions.just_released(InputAction::MoveLeft) {
             interactor.area = InteractionArea::create(8.0, 20.0, -16.0, 0.0);
             interactor.side = InteractionSide::Left;
-        } else if keyboard_input.just_released(KeyCode::KeyD) {
+        } else if actions.just_released(InputAction::MoveRight) {
             interactor.area = InteractionArea::create(8.0, 20.0, 16.0, 0.0);
             interactor.side = InteractionSide::Right;
-        } else if keyboard_input.just_released(KeyCode::KeyW) {
+        } else if actions.just_released(InputAction::MoveUp) {
             interactor.area = InteractionArea::from_sizes(8.0, 20.0);
             interactor.side = InteractionSide::Top;
-        } else if keyboard_input.just_released(KeyCode::KeyS) {
+        } else if actions.just_released(InputAction::MoveDown) {
             interactor.area = InteractionArea::from_sizes(8.0, 20.0);
             interactor.side = InteractionSide::Bottom;
         }
@@ -29,16 +29,16 @@ impl Ability {
 
     pub fn apply_cost(&self, target: &mut TargetProps) {
         match self {
-            Ability::NeckTwist { damage, cost } => {
+            Ability::NeckTwist { damage: _, cost } => {
                 target.energy.decrease(*cost);
             }
-            Ability::WoundsLicking { health, cost } => {
+            Ability::WoundsLicking { health: _, cost } => {
                 target.energy.decrease(*cost);
             }
-            Ability::SuperPunch { damage, cost } => {
+            Ability::SuperPunch { damage: _, cost } => {
                 target.energy.decrease(*cost);
             }
-            Ability::NeckGnawing { damage, cost } => {
+            Ability::NeckGnawing { damage: _, cost } => {
                 target.energy.decrease(*cost);
             }
         }
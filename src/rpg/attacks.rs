@@ -23,11 +23,11 @@ pub enum AttackResult {
 impl DirectionalAttack {
     pub fn damage(&self) -> i32 {
         match self {
-            DirectionalAttack::InevitableDamage { damage } => damage.clone(),
-            DirectionalAttack::Punch { damage } => damage.clone(),
-            DirectionalAttack::Kick { damage } => damage.clone(),
-            DirectionalAttack::Bite { damage } => damage.clone(),
-            DirectionalAttack::PawStrike { damage } => damage.clone(),
+            DirectionalAttack::InevitableDamage { damage } => *damage,
+            DirectionalAttack::Punch { damage } => *damage,
+            DirectionalAttack::Kick { damage } => *damage,
+            DirectionalAttack::Bite { damage } => *damage,
+            DirectionalAttack::PawStrike { damage } => *damage,
         }
     }
 
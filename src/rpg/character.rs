@@ -1,8 +1,10 @@
 use bevy::prelude::Component;
 
+use crate::rpg::Characteristic;
+
 #[derive(Component, PartialEq, Clone, Debug)]
 pub struct Character {
-    pub name: String, // it is always unique id
+    pub name: String, // it is always unique id and key of the localized name
     pub class: Class,
     pub level: Level,
 }
@@ -10,7 +12,7 @@ pub struct Character {
 impl Character {
     pub fn initial_formidable_face() -> Self {
         Character {
-            name: "Грозный Личик".to_string(),
+            name: "character.formidable_face".to_string(),
             class: Class::FormidableFace {
                 strength: 1,
                 agility: 1,
@@ -30,7 +32,7 @@ impl Character {
 
     pub fn initial_formidable_dog() -> Self {
         Character {
-            name: "Грозный Пёс".to_string(),
+            name: "character.formidable_dog".to_string(),
             class: Class::FormidableDog {
                 strength: 1,
                 agility: 1,
@@ -66,6 +68,41 @@ pub enum Class {
     },
 }
 
+impl Class {
+    pub fn strength(&self) -> i32 {
+        match self {
+            Class::FormidableFace { strength, .. } => *strength,
+            Class::FormidableDog { strength, .. } => *strength,
+        }
+    }
+
+    pub fn agility(&self) -> i32 {
+        match self {
+            Class::FormidableFace { agility, .. } => *agility,
+            Class::FormidableDog { agility, .. } => *agility,
+        }
+    }
+
+    pub fn charisma(&self) -> i32 {
+        match self {
+            Class::FormidableFace { charisma, .. } => *charisma,
+            Class::FormidableDog { .. } => 0,
+        }
+    }
+
+    pub fn characteristic(&self, characteristic: Characteristic) -> i32 {
+        match (self, characteristic) {
+            (_, Characteristic::Strength) => self.strength(),
+            (_, Characteristic::Agility) => self.agility(),
+            (_, Characteristic::Charisma) => self.charisma(),
+            (Class::FormidableFace { stamina, .. }, Characteristic::Stamina) => *stamina,
+            (Class::FormidableDog { stamina, .. }, Characteristic::Stamina) => *stamina,
+            (Class::FormidableFace { fortitude, .. }, Characteristic::Fortitude) => *fortitude,
+            (Class::FormidableDog { fortitude, .. }, Characteristic::Fortitude) => *fortitude,
+        }
+    }
+}
+
 #[derive(Component, PartialEq, Clone, Debug)]
 pub struct Level {
     pub current: i32,
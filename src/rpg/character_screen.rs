@@ -1,12 +1,8 @@
-use std::fmt::format;
-use std::ops::Deref;
-
 use bevy::app::{Plugin, Update};
-use bevy::audio::{AudioBundle, PlaybackSettings};
 use bevy::color::palettes::css::ANTIQUE_WHITE;
 use bevy::color::{Color, Srgba};
 use bevy::hierarchy::{Children, DespawnRecursiveExt};
-use bevy::log::{error, warn};
+use bevy::log::warn;
 use bevy::prelude::in_state;
 use bevy::prelude::Changed;
 use bevy::prelude::Commands;
@@ -20,8 +16,7 @@ use bevy::prelude::OnExit;
 use bevy::prelude::Query;
 use bevy::prelude::Val;
 use bevy::prelude::With;
-use bevy::prelude::{BackgroundColor, Res, ResMut, Style, Visibility};
-use bevy::render::render_resource::encase::private::RuntimeSizedArray;
+use bevy::prelude::{BackgroundColor, Res, ResMut, Visibility};
 use bevy::ui::{FocusPolicy, UiRect};
 use bevy::utils::hashbrown::HashMap;
 use sickle_ui::prelude::SetHeightExt;
@@ -32,13 +27,12 @@ use sickle_ui::prelude::UiBuilderExt;
 use sickle_ui::prelude::UiColumnExt;
 use sickle_ui::prelude::UiRoot;
 use sickle_ui::prelude::UiRowExt;
-use sickle_ui::prelude::{
-    PseudoState, SetBackgroundColorExt, SetFocusPolicyExt, SetSizeExt, SetVisibilityExt,
-};
-use sickle_ui::ui_commands::{ManagePseudoStateExt, UpdateTextExt};
+use sickle_ui::prelude::{SetBackgroundColorExt, SetFocusPolicyExt, SetSizeExt, SetVisibilityExt};
+use sickle_ui::ui_commands::UpdateTextExt;
 
 use crate::core::states::GameState;
 use crate::gui::{ButtonConfig, TextButton, TextButtonExt, TextConfig, TextExt};
+use crate::localization::Localization;
 use crate::party::PartyStateStorage;
 use crate::rpg::character::{Character, Class};
 use crate::rpg::characteristic_item_ui::select_item_handle;
@@ -51,7 +45,7 @@ use crate::rpg::stat_item_ui::{Stat, StatItemExt, StatValue, StatsValues};
 use crate::rpg::storages::CharacterStorage;
 use crate::rpg::title_ui::{Title, TitleAction, TitleExt};
 use crate::rpg::{Ability, DirectionalAttack, RangedProp, TargetProps};
-use crate::sound::ButtonSounds;
+use crate::sound::{AudioBus, AudioCommandsExt, ButtonSounds};
 
 pub struct CharacterScreenPlugin;
 
@@ -125,11 +119,15 @@ impl Plugin for CharacterScreenPlugin {
     }
 }
 
-fn spawn_main(mut commands: Commands, character_storage: Res<CharacterStorage>) {
+fn spawn_main(
+    mut commands: Commands,
+    character_storage: Res<CharacterStorage>,
+    localization: Res<Localization>,
+) {
     let characters_items = character_storage.get();
 
     let character = characters_items
-        .get(0)
+        .first()
         .expect("characters must not be empty");
 
     let char_values = to_screen_values(character);
@@ -167,12 +165,12 @@ fn spawn_main(mut commands: Commands, character_storage: Res<CharacterStorage>)
                                 .height(Val::Percent(10.0));
                             parent
                                 .column(|parent| {
-                                    parent.characteristic(Characteristic::Strength);
-                                    parent.characteristic(Characteristic::Agility);
-                                    parent.characteristic(Characteristic::Stamina);
-                                    parent.characteristic(Characteristic::Fortitude);
+                                    parent.characteristic(Characteristic::Strength, &localization);
+                                    parent.characteristic(Characteristic::Agility, &localization);
+                                    parent.characteristic(Characteristic::Stamina, &localization);
+                                    parent.characteristic(Characteristic::Fortitude, &localization);
                                     parent
-                                        .characteristic(Characteristic::Charisma)
+                                        .characteristic(Characteristic::Charisma, &localization)
                                         .insert(Charisma)
                                         .style()
                                         .visibility(Visibility::Visible);
@@ -215,11 +213,11 @@ fn spawn_main(mut commands: Commands, character_storage: Res<CharacterStorage>)
 
                             parent
                                 .column(|parent| {
-                                    parent.stat(Stat::BaseAttack);
-                                    parent.stat(Stat::Health);
-                                    parent.stat(Stat::Energy);
-                                    parent.stat(Stat::Armor);
-                                    parent.stat(Stat::Evasion);
+                                    parent.stat(Stat::BaseAttack, &localization);
+                                    parent.stat(Stat::Health, &localization);
+                                    parent.stat(Stat::Energy, &localization);
+                                    parent.stat(Stat::Armor, &localization);
+                                    parent.stat(Stat::Evasion, &localization);
                                 })
                                 .style()
                                 .justify_content(JustifyContent::SpaceAround)
@@ -251,7 +249,7 @@ fn spawn_main(mut commands: Commands, character_storage: Res<CharacterStorage>)
 
                     parent
                         .configure_text_button(
-                            "Подтвердить",
+                            localization.get("character.confirm"),
                             ConfirmButton,
                             TextConfig::from_color(Color::from(ANTIQUE_WHITE)),
                             ButtonConfig {
@@ -377,7 +375,7 @@ fn to_screen_values(character: &Character) -> HashMap<Characteristic, RangedProp
     char_values
 }
 
-pub fn change_characteristic_handle(
+fn change_characteristic_handle(
     mut query: Query<
         (
             &TextButton<(CharacteristicAction, Characteristic)>,
@@ -386,9 +384,9 @@ pub fn change_characteristic_handle(
         ),
         Changed<Interaction>,
     >,
-    mut characteristic_values_query: Query<(&mut CharacteristicValues)>,
-    mut stats_values_query: Query<(&mut StatsValues)>,
-    mut scores_query: Query<(&mut Scores)>,
+    mut characteristic_values_query: Query<&mut CharacteristicValues>,
+    mut stats_values_query: Query<&mut StatsValues>,
+    mut scores_query: Query<&mut Scores>,
 ) {
     for (item, interaction, mut background_color) in &mut query {
         match *interaction {
@@ -400,14 +398,12 @@ pub fn change_characteristic_handle(
                 let chars = &mut characteristic_values_query.single_mut().0;
                 let value = chars
                     .get_mut(&item.payload.1)
-                    .expect(&format!("No value with key = {:?}", &item.payload.1));
+                    .unwrap_or_else(|| panic!("No value with key = {:?}", &item.payload.1));
                 let scores = &mut scores_query.single_mut().0;
                 match item.payload.0 {
                     CharacteristicAction::Increase => {
-                        if scores.current > scores.min {
-                            if value.increase(1) {
-                                scores.decrease(1);
-                            }
+                        if scores.current > scores.min && value.increase(1) {
+                            scores.decrease(1);
                         }
                     }
                     CharacteristicAction::Decrease => {
@@ -417,7 +413,7 @@ pub fn change_characteristic_handle(
                     }
                 }
                 let stats = &mut stats_values_query.single_mut().0;
-                recalculate_stats(&chars, stats);
+                recalculate_stats(chars, stats);
             }
         }
     }
@@ -425,18 +421,14 @@ pub fn change_characteristic_handle(
 
 fn change_character_handle(
     mut query: Query<
-        (
-            &TextButton<(TitleAction)>,
-            &Interaction,
-            &mut BackgroundColor,
-        ),
+        (&TextButton<TitleAction>, &Interaction, &mut BackgroundColor),
         Changed<Interaction>,
     >,
-    mut characters_value_query: Query<(&mut Characters)>,
-    mut scores_value_query: Query<(&mut Scores)>,
-    mut characteristic_values_query: Query<(&mut CharacteristicValues)>,
-    mut stats_values_query: Query<(&mut StatsValues)>,
-    mut visibility_query: Query<(&mut Visibility), With<Charisma>>,
+    mut characters_value_query: Query<&mut Characters>,
+    mut scores_value_query: Query<&mut Scores>,
+    mut characteristic_values_query: Query<&mut CharacteristicValues>,
+    mut stats_values_query: Query<&mut StatsValues>,
+    mut visibility_query: Query<&mut Visibility, With<Charisma>>,
 ) {
     for (item, interaction, mut background_color) in &mut query {
         match *interaction {
@@ -485,21 +477,21 @@ fn confirm_handle(
     mut commands: Commands,
     mut character_storage: ResMut<CharacterStorage>,
     mut party_state_storage: ResMut<PartyStateStorage>,
-    mut scores_query: Query<(&mut Scores)>,
+    mut scores_query: Query<&mut Scores>,
     mut query: Query<
         (
-            &TextButton<(ConfirmButton)>,
+            &TextButton<ConfirmButton>,
             &Interaction,
             &mut BackgroundColor,
         ),
         Changed<Interaction>,
     >,
     audio_res: Res<ButtonSounds>,
-    mut characters_value_query: Query<(&mut Characters)>,
-    mut characteristic_values_query: Query<(&mut CharacteristicValues)>,
-    stats_values_query: Query<(&StatsValues)>,
+    mut characters_value_query: Query<&mut Characters>,
+    mut characteristic_values_query: Query<&mut CharacteristicValues>,
+    stats_values_query: Query<&StatsValues>,
 ) {
-    for (mut item, interaction, mut background_color) in &mut query {
+    for (item, interaction, mut background_color) in &mut query {
         match *interaction {
             Interaction::None => {
                 *background_color = item.config.idle;
@@ -512,17 +504,13 @@ fn confirm_handle(
                     let mut characteristics = characteristic_values_query.single_mut();
                     let class =
                         map_to_class(&characteristics, character_storage.get_class_by_id(char_id));
-                    for (_, mut props) in &mut characteristics.0 {
+                    for (_, props) in &mut characteristics.0 {
                         props.min = props.current;
                     }
 
                     let stats = stats_values_query.single();
                     let targets = map_to_target_props(stats);
-                    let base_attack = stats
-                        .0
-                        .get(&Stat::BaseAttack)
-                        .expect("No value set")
-                        .clone();
+                    let base_attack = *stats.0.get(&Stat::BaseAttack).expect("No value set");
                     party_state_storage.update_base_attack_by_id(char_id, base_attack);
                     party_state_storage.update_target_props_by_id(char_id, targets);
                     let new_level = character_storage.get_level_by_id(char_id).up_level();
@@ -538,10 +526,7 @@ fn confirm_handle(
                     let mut characters = characters_value_query.single_mut();
                     characters.items = character_storage.get();
                 } else {
-                    commands.spawn(AudioBundle {
-                        source: audio_res.negative_click.clone(),
-                        settings: PlaybackSettings::ONCE,
-                    });
+                    commands.play_sound(AudioBus::Ui, audio_res.negative_click.clone());
                 }
             }
         }
@@ -710,16 +695,16 @@ fn map_to_target_props(stats: &StatsValues) -> TargetProps {
     TargetProps {
         health: RangedProp {
             min: 0,
-            current: health.clone(),
-            max: health.clone(),
+            current: *health,
+            max: *health,
         },
         energy: RangedProp {
             min: 0,
-            current: energy.clone(),
-            max: energy.clone(),
+            current: *energy,
+            max: *energy,
         },
-        armor: stats.0.get(&Stat::Armor).expect("No value sat").clone(),
-        evasion: stats.0.get(&Stat::Evasion).expect("No value sat").clone(),
+        armor: *stats.0.get(&Stat::Armor).expect("No value sat"),
+        evasion: *stats.0.get(&Stat::Evasion).expect("No value sat"),
     }
 }
 
@@ -747,11 +732,11 @@ fn recalculate_stats(chars: &HashMap<Characteristic, RangedProp>, stats: &mut Ha
 
 fn update_characteristics_value_labels_handle(
     mut commands: Commands,
-    mut characteristic_value_query: Query<(&Children, &Characteristic), With<CharacteristicValue>>,
-    characteristic_values_query: Query<(&CharacteristicValues), Changed<CharacteristicValues>>,
+    characteristic_value_query: Query<(&Children, &Characteristic), With<CharacteristicValue>>,
+    characteristic_values_query: Query<&CharacteristicValues, Changed<CharacteristicValues>>,
 ) {
     for values in characteristic_values_query.iter() {
-        for (mut children, char) in characteristic_value_query.iter() {
+        for (children, char) in characteristic_value_query.iter() {
             for &child in children.iter() {
                 match commands.get_entity(child) {
                     None => {
@@ -761,7 +746,7 @@ fn update_characteristics_value_labels_handle(
                         let value = values
                             .0
                             .get(char)
-                            .expect(&format!("No value with key = {:?}", char));
+                            .unwrap_or_else(|| panic!("No value with key = {:?}", char));
                         entity_commands.update_text(format!("{}", value.current));
                     }
                 }
@@ -772,11 +757,11 @@ fn update_characteristics_value_labels_handle(
 
 fn update_stats_value_labels_handle(
     mut commands: Commands,
-    mut stat_value_query: Query<(&Children, &Stat), With<StatValue>>,
-    stat_values_query: Query<(&StatsValues), Changed<StatsValues>>,
+    stat_value_query: Query<(&Children, &Stat), With<StatValue>>,
+    stat_values_query: Query<&StatsValues, Changed<StatsValues>>,
 ) {
     for values in stat_values_query.iter() {
-        for (mut children, stat) in stat_value_query.iter() {
+        for (children, stat) in stat_value_query.iter() {
             for &child in children.iter() {
                 match commands.get_entity(child) {
                     None => {
@@ -786,7 +771,7 @@ fn update_stats_value_labels_handle(
                         let value = values
                             .0
                             .get(stat)
-                            .expect(&format!("No value with key = {:?}", stat));
+                            .unwrap_or_else(|| panic!("No value with key = {:?}", stat));
                         entity_commands.update_text(format!("{}", value));
                     }
                 }
@@ -797,11 +782,12 @@ fn update_stats_value_labels_handle(
 
 fn update_character_name_handle(
     mut commands: Commands,
-    mut children_query: Query<(&Children), With<Title>>,
-    characters_value_query: Query<(&Characters), Changed<Characters>>,
+    localization: Res<Localization>,
+    children_query: Query<&Children, With<Title>>,
+    characters_value_query: Query<&Characters, Changed<Characters>>,
 ) {
     for value in characters_value_query.iter() {
-        for mut children in children_query.iter() {
+        for children in children_query.iter() {
             for &child in children.iter() {
                 match commands.get_entity(child) {
                     None => {
@@ -809,7 +795,7 @@ fn update_character_name_handle(
                     }
                     Some(mut entity_commands) => {
                         let current = value.current();
-                        entity_commands.update_text(format!("{}", current.name));
+                        entity_commands.update_text(localization.get(&current.name));
                     }
                 }
             }
@@ -819,11 +805,12 @@ fn update_character_name_handle(
 
 fn update_character_level_handle(
     mut commands: Commands,
-    mut children_query: Query<(&Children), With<Level>>,
-    characters_value_query: Query<(&Characters), Changed<Characters>>,
+    localization: Res<Localization>,
+    children_query: Query<&Children, With<Level>>,
+    characters_value_query: Query<&Characters, Changed<Characters>>,
 ) {
     for value in characters_value_query.iter() {
-        for mut children in children_query.iter() {
+        for children in children_query.iter() {
             for &child in children.iter() {
                 match commands.get_entity(child) {
                     None => {
@@ -831,7 +818,10 @@ fn update_character_level_handle(
                     }
                     Some(mut entity_commands) => {
                         let current = value.current();
-                        entity_commands.update_text(format!("Level {}", current.level.current));
+                        entity_commands.update_text(localization.format(
+                            "character.level",
+                            &[("level", current.level.current.to_string())],
+                        ));
                     }
                 }
             }
@@ -841,11 +831,12 @@ fn update_character_level_handle(
 
 fn update_character_exp_handle(
     mut commands: Commands,
-    mut children_query: Query<(&Children), With<Exp>>,
-    characters_value_query: Query<(&Characters), Changed<Characters>>,
+    localization: Res<Localization>,
+    children_query: Query<&Children, With<Exp>>,
+    characters_value_query: Query<&Characters, Changed<Characters>>,
 ) {
     for value in characters_value_query.iter() {
-        for mut children in children_query.iter() {
+        for children in children_query.iter() {
             for &child in children.iter() {
                 match commands.get_entity(child) {
                     None => {
@@ -853,9 +844,12 @@ fn update_character_exp_handle(
                     }
                     Some(mut entity_commands) => {
                         let current = value.current();
-                        entity_commands.update_text(format!(
-                            "Exp. {} / {}",
-                            current.level.current_experience, current.level.experience_for_the_next,
+                        entity_commands.update_text(localization.format(
+                            "character.experience",
+                            &[
+                                ("current", current.level.current_experience.to_string()),
+                                ("next", current.level.experience_for_the_next.to_string()),
+                            ],
                         ));
                     }
                 }
@@ -866,10 +860,11 @@ fn update_character_exp_handle(
 
 fn update_scores_handle(
     mut commands: Commands,
-    mut scores_query: Query<(&Children, &Scores), Changed<Scores>>,
-    mut confirm_button_style_query: Query<(&mut BackgroundColor, &mut TextButton<(ConfirmButton)>)>,
+    localization: Res<Localization>,
+    scores_query: Query<(&Children, &Scores), Changed<Scores>>,
+    mut confirm_button_style_query: Query<(&mut BackgroundColor, &mut TextButton<ConfirmButton>)>,
 ) {
-    for (mut children, scores) in scores_query.iter() {
+    for (children, scores) in scores_query.iter() {
         let (mut background, mut button) = confirm_button_style_query.single_mut();
         if scores.0.current > scores.0.min || scores.0.max == 0 {
             button.config.hover = BackgroundColor(DISABLED_BUTTON_COLOR);
@@ -886,7 +881,10 @@ fn update_scores_handle(
                     warn!("Scores is not found")
                 }
                 Some(mut entity_commands) => {
-                    entity_commands.update_text(format!("Доступные очки: {}", scores.0.current));
+                    entity_commands.update_text(localization.format(
+                        "character.available_points",
+                        &[("points", scores.0.current.to_string())],
+                    ));
                 }
             }
         }
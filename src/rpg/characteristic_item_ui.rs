@@ -30,6 +30,7 @@ use crate::gui::TextButton;
 use crate::gui::TextButtonExt;
 use crate::gui::TextConfig;
 use crate::gui::TextExt;
+use crate::localization::Localization;
 use crate::rpg::RangedProp;
 
 #[derive(Component, Hash, Copy, Clone, Eq, PartialEq, Debug)]
@@ -42,16 +43,16 @@ pub enum Characteristic {
 }
 
 impl Characteristic {
-    fn name(&self) -> String {
-        let str = match self {
-            Characteristic::Strength => "Сила",
-            Characteristic::Agility => "Выкрутасность",
-            Characteristic::Stamina => "Стамина",
-            Characteristic::Fortitude => "Стойкость",
-            Characteristic::Charisma => "Языкастость",
+    pub fn name(&self, localization: &Localization) -> String {
+        let key = match self {
+            Characteristic::Strength => "characteristic.strength",
+            Characteristic::Agility => "characteristic.agility",
+            Characteristic::Stamina => "characteristic.stamina",
+            Characteristic::Fortitude => "characteristic.fortitude",
+            Characteristic::Charisma => "characteristic.charisma",
         };
 
-        str.to_string()
+        localization.get(key)
     }
 }
 
@@ -81,15 +82,23 @@ pub trait HasDescription {
 }
 
 pub trait CharacteristicItemExt<'a> {
-    fn characteristic(&mut self, typ: Characteristic) -> UiBuilder<Entity>;
+    fn characteristic(
+        &mut self,
+        typ: Characteristic,
+        localization: &Localization,
+    ) -> UiBuilder<'_, Entity>;
 }
 
 impl<'a> CharacteristicItemExt<'a> for UiBuilder<'a, Entity> {
-    fn characteristic(&mut self, typ: Characteristic) -> UiBuilder<Entity> {
+    fn characteristic(
+        &mut self,
+        typ: Characteristic,
+        localization: &Localization,
+    ) -> UiBuilder<'_, Entity> {
         let mut item = self.row(|parent| {
             parent
                 .configure_text_button(
-                    typ.name(),
+                    typ.name(localization),
                     typ,
                     TextConfig::from_color(Color::from(ANTIQUE_WHITE)),
                     ButtonConfig {
@@ -167,7 +176,7 @@ impl<'a> CharacteristicItemExt<'a> for UiBuilder<'a, Entity> {
 pub fn select_item_handle<T: HasDescription + Bundle>(
     mut commands: Commands,
     mut query: Query<(&TextButton<T>, &Interaction, &mut BackgroundColor), Changed<Interaction>>,
-    mut description_query: Query<(&Children), With<Description>>,
+    description_query: Query<&Children, With<Description>>,
 ) {
     for (item, interaction, mut background_color) in &mut query {
         match *interaction {
@@ -175,15 +184,14 @@ pub fn select_item_handle<T: HasDescription + Bundle>(
                 *background_color = item.config.idle;
             }
             Interaction::Hovered => {
-                for mut children in description_query.iter() {
+                for children in description_query.iter() {
                     for &child in children.iter() {
                         match commands.get_entity(child) {
                             None => {
                                 warn!("Description is not found")
                             }
                             Some(mut entity_commands) => {
-                                entity_commands
-                                    .update_text(format!("{}", item.payload.description()));
+                                entity_commands.update_text(item.payload.description().to_string());
                             }
                         }
                     }
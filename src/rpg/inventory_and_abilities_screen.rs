@@ -6,8 +6,8 @@ use crate::gui::TextButton;
 use crate::gui::TextButtonExt;
 use crate::gui::TextConfig;
 use crate::gui::TextExt;
+use crate::localization::Localization;
 use crate::party::PartyStateStorage;
-use crate::rpg::{ConsumableItem, DirectionalAttack};
 use bevy::app::{App, Plugin, Update};
 use bevy::color::palettes::css::ANTIQUE_WHITE;
 use bevy::color::{Color, Srgba};
@@ -36,7 +36,6 @@ use bevy::prelude::Val;
 use bevy::prelude::With;
 use bevy::prelude::{AppExtStates, OnExit};
 use bevy::ui::FocusPolicy;
-use bevy_rapier2d::na::DimRange;
 use sickle_ui::prelude::ScrollAxis;
 use sickle_ui::prelude::SetAlignItemsExt;
 use sickle_ui::prelude::SetBackgroundColorExt;
@@ -85,18 +84,6 @@ impl SelectedItemPosHolder {
     fn store(&mut self, value: usize) {
         self.value = Some(value);
     }
-
-    fn take_away_unsafe(&mut self) -> usize {
-        let value = self.value.expect("Value was not be stored");
-        self.value = None;
-        value
-    }
-
-    fn take_away(&mut self) -> Option<usize> {
-        let value = self.value;
-        self.value = None;
-        value
-    }
 }
 
 #[derive(Component)]
@@ -127,6 +114,7 @@ fn spawn_main(mut commands: Commands) {
 fn main_respawns(
     mut commands: Commands,
     party_storage: Res<PartyStateStorage>,
+    localization: Res<Localization>,
     tab_state: Res<State<Tab>>,
     selected_member_state: Res<State<SelectedMember>>,
     game_state: ResMut<NextState<GameState>>,
@@ -145,16 +133,24 @@ fn main_respawns(
     let member = &members[current_member_id];
     let items = match tab_state.get() {
         Tab::Inventory => {
-            let items = party_storage.get_consumables();
-            to_selector_items(&items)
+            let mut items = to_selector_items(&party_storage.get_consumables(), &localization);
+            items.extend(to_selector_items(
+                &party_storage.get_equipment(),
+                &localization,
+            ));
+            items.extend(to_selector_items(
+                &party_storage.get_quest_items(),
+                &localization,
+            ));
+            items
         }
         Tab::Abilities => {
             let items = &member.abilities;
-            to_selector_items(&items)
+            to_selector_items(items, &localization)
         }
         Tab::Attacks => {
             let items = &member.attacks;
-            to_selector_items(&items)
+            to_selector_items(items, &localization)
         }
     };
     commands
@@ -174,7 +170,7 @@ fn main_respawns(
                             };
                             parent
                                 .configure_text_button(
-                                    "Лут".to_string(),
+                                    localization.get("inventory.loot"),
                                     Tab::Inventory,
                                     TextConfig::from_color(Color::from(ANTIQUE_WHITE)),
                                     config.clone(),
@@ -183,7 +179,7 @@ fn main_respawns(
                                 .focus_policy(FocusPolicy::Pass);
                             parent
                                 .configure_text_button(
-                                    "Способности".to_string(),
+                                    localization.get("inventory.abilities"),
                                     Tab::Abilities,
                                     TextConfig::from_color(Color::from(ANTIQUE_WHITE)),
                                     config.clone(),
@@ -192,7 +188,7 @@ fn main_respawns(
                                 .focus_policy(FocusPolicy::Pass);
                             parent
                                 .configure_text_button(
-                                    "Атаки".to_string(),
+                                    localization.get("inventory.attacks"),
                                     Tab::Attacks,
                                     TextConfig::from_color(Color::from(ANTIQUE_WHITE)),
                                     config,
@@ -217,7 +213,7 @@ fn main_respawns(
                                 };
                                 parent
                                     .configure_text_button(
-                                        member.name.clone(),
+                                        localization.get(&member.name),
                                         SelectedMember(idx),
                                         TextConfig::from_color(Color::from(ANTIQUE_WHITE)),
                                         config,
@@ -306,10 +302,13 @@ fn main_respawns(
         .background_color(Color::from(SCREEN_BG));
 }
 
-fn to_selector_items<T: GetSelectorItem>(items: &Vec<T>) -> Vec<SelectorItem> {
+fn to_selector_items<T: GetSelectorItem>(
+    items: &Vec<T>,
+    localization: &Localization,
+) -> Vec<SelectorItem> {
     let mut result = vec![];
     for item in items {
-        result.push(item.selector_item());
+        result.push(item.selector_item(localization));
     }
     result
 }
@@ -320,9 +319,9 @@ fn pick_item_handle(
         (&TextButton<PosAndDescr>, &Interaction, &mut BackgroundColor),
         Changed<Interaction>,
     >,
-    mut description_query: Query<(&Children), With<Description>>,
-    mut holder_query: Query<(&mut SelectedItemPosHolder)>,
-    mut tab_state: Res<State<Tab>>,
+    description_query: Query<&Children, With<Description>>,
+    mut holder_query: Query<&mut SelectedItemPosHolder>,
+    tab_state: Res<State<Tab>>,
 ) {
     for (item, interaction, mut background_color) in &mut query {
         match *interaction {
@@ -330,7 +329,7 @@ fn pick_item_handle(
                 *background_color = item.config.idle;
             }
             Interaction::Hovered => {
-                for mut children in description_query.iter() {
+                for children in description_query.iter() {
                     for &child in children.iter() {
                         match commands.get_entity(child) {
                             None => {
@@ -361,7 +360,7 @@ fn pick_item_handle(
 fn pick_tab_handle(
     mut query: Query<(&TextButton<Tab>, &Interaction, &mut BackgroundColor), Changed<Interaction>>,
     mut next_tab_state: ResMut<NextState<Tab>>,
-    mut tab_state: Res<State<Tab>>,
+    tab_state: Res<State<Tab>>,
 ) {
     for (item, interaction, mut background_color) in &mut query {
         match *interaction {
@@ -391,7 +390,7 @@ fn pick_member_handle(
         Changed<Interaction>,
     >,
     mut next_member_state: ResMut<NextState<SelectedMember>>,
-    mut member_state: Res<State<SelectedMember>>,
+    member_state: Res<State<SelectedMember>>,
 ) {
     for (item, interaction, mut background_color) in &mut query {
         match *interaction {
@@ -21,9 +21,25 @@ impl ConsumableItem {
             energy: 5,
         })
     }
+
+    /// Name used for items in maps and shops data.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "dumplings" => Some(ConsumableItem::default_dumplings()),
+            "venison" => Some(ConsumableItem::default_venison()),
+            _ => None,
+        }
+    }
 }
 
 impl ConsumableItem {
+    pub fn base_price(&self) -> i32 {
+        match self {
+            ConsumableItem::Dumplings(_) => 10,
+            ConsumableItem::Venison(_) => 25,
+        }
+    }
+
     pub fn apply(&self, target: &mut TargetProps) {
         match self {
             ConsumableItem::Dumplings(value) => {
@@ -49,3 +65,24 @@ pub struct Venison {
     pub health: i32,
     pub energy: i32,
 }
+
+#[derive(Component, Clone, Debug, PartialEq)]
+pub enum EquipmentItem {
+    BrassKnuckles { attack: i32 },
+    LeatherJacket { armor: i32 },
+}
+
+#[derive(Component, Clone, Debug, PartialEq)]
+pub enum QuestItem {
+    ChestKey,
+    Feather,
+}
+
+// Anything that can be found, e.g. in containers
+#[derive(Clone, Debug)]
+pub enum Loot {
+    Consumable(ConsumableItem),
+    Equipment(EquipmentItem),
+    Money(i32),
+    Quest(QuestItem),
+}
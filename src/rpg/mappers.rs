@@ -1,56 +1,56 @@
 use crate::gui::GetSelectorItem;
-use crate::rpg::{Ability, ConsumableItem, DirectionalAttack, Dumplings, Venison};
+use crate::localization::Localization;
+use crate::rpg::{
+    Ability, ConsumableItem, DirectionalAttack, Dumplings, EquipmentItem, QuestItem, Venison,
+};
 
 impl GetSelectorItem for DirectionalAttack {
-    fn localised_name(&self) -> String {
-        match self {
-            DirectionalAttack::InevitableDamage { .. } => "Абсолютный урон".to_string(),
-            DirectionalAttack::Punch { .. } => "Удар кулаком".to_string(),
-            DirectionalAttack::Kick { .. } => "Удар ногой по харе".to_string(),
-            DirectionalAttack::Bite { .. } => "Укус за пятку".to_string(),
-            DirectionalAttack::PawStrike { .. } => "Удар задней лапой".to_string(),
-        }
+    fn localised_name(&self, localization: &Localization) -> String {
+        let key = match self {
+            DirectionalAttack::InevitableDamage { .. } => "attack.inevitable_damage.name",
+            DirectionalAttack::Punch { .. } => "attack.punch.name",
+            DirectionalAttack::Kick { .. } => "attack.kick.name",
+            DirectionalAttack::Bite { .. } => "attack.bite.name",
+            DirectionalAttack::PawStrike { .. } => "attack.paw_strike.name",
+        };
+        localization.get(key)
     }
 
-    fn localised_description(&self) -> String {
-        match self {
+    fn localised_description(&self, localization: &Localization) -> String {
+        let (key, damage) = match self {
             DirectionalAttack::InevitableDamage { damage } => {
-                format!("Ignores armor.\n\nDamage = {damage}")
-            }
-            DirectionalAttack::Punch { damage } => {
-                format!("Ignores 5% armor.\n\nDamage = {damage}")
-            }
-            DirectionalAttack::Kick { damage } => {
-                format!("Ignores 5% armor.\n\nDamage = {damage}")
-            }
-            DirectionalAttack::Bite { damage } => {
-                format!("Ignores 5% armor.\n\nDamage = {damage}")
-            }
-            DirectionalAttack::PawStrike { damage } => {
-                format!("Ignores 5% armor.\n\nDamage = {damage}")
+                ("attack.inevitable_damage.description", damage)
             }
-        }
+            DirectionalAttack::Punch { damage } => ("attack.punch.description", damage),
+            DirectionalAttack::Kick { damage } => ("attack.kick.description", damage),
+            DirectionalAttack::Bite { damage } => ("attack.bite.description", damage),
+            DirectionalAttack::PawStrike { damage } => ("attack.paw_strike.description", damage),
+        };
+        localization.format(key, &[("damage", damage.to_string())])
     }
 }
 
 impl GetSelectorItem for Ability {
-    fn localised_name(&self) -> String {
-        match self {
-            Ability::NeckTwist { .. } => "Щею свернуть!".to_string(),
-            Ability::WoundsLicking { .. } => "Зализать раны".to_string(),
-            Ability::SuperPunch { .. } => "Могучий удар кулаком".to_string(),
-            Ability::NeckGnawing { .. } => "Разгрызть шею!".to_string(),
-        }
+    fn localised_name(&self, localization: &Localization) -> String {
+        let key = match self {
+            Ability::NeckTwist { .. } => "ability.neck_twist.name",
+            Ability::WoundsLicking { .. } => "ability.wounds_licking.name",
+            Ability::SuperPunch { .. } => "ability.super_punch.name",
+            Ability::NeckGnawing { .. } => "ability.neck_gnawing.name",
+        };
+        localization.get(key)
     }
 
-    fn localised_description(&self) -> String {
+    fn localised_description(&self, localization: &Localization) -> String {
         match self {
-            Ability::NeckTwist { damage, cost } => {
-                format!("It has 3% to defeat enemy, else makes damage = {damage}.\n\nEnergy cost = {cost}.\n\nIgnores armor.")
-            }
-            Ability::WoundsLicking { health, cost } => {
-                format!("No treatment? Lick your wound... Literally.\n\nRegain health = {health}.\n\nEnergy cost = {cost}.")
-            }
+            Ability::NeckTwist { damage, cost } => localization.format(
+                "ability.neck_twist.description",
+                &[("damage", damage.to_string()), ("cost", cost.to_string())],
+            ),
+            Ability::WoundsLicking { health, cost } => localization.format(
+                "ability.wounds_licking.description",
+                &[("health", health.to_string()), ("cost", cost.to_string())],
+            ),
             Ability::SuperPunch { .. } => "TODO".to_string(),
             Ability::NeckGnawing { .. } => "TODO".to_string(),
         }
@@ -58,24 +58,70 @@ impl GetSelectorItem for Ability {
 }
 
 impl GetSelectorItem for ConsumableItem {
-    fn localised_name(&self) -> String {
-        match self {
-            ConsumableItem::Dumplings(_) => "Dumplings".to_string(),
-            ConsumableItem::Venison(_) => "Venison".to_string(),
-        }
+    fn localised_name(&self, localization: &Localization) -> String {
+        let key = match self {
+            ConsumableItem::Dumplings(_) => "consumable.dumplings.name",
+            ConsumableItem::Venison(_) => "consumable.venison.name",
+        };
+        localization.get(key)
     }
 
-    fn localised_description(&self) -> String {
-        match self {
+    fn localised_description(&self, localization: &Localization) -> String {
+        let (key, health, energy) = match self {
             ConsumableItem::Dumplings(Dumplings { health, energy }) => {
-                format!("It's very tasty.\n\nRegain {health} of health and {energy} of energy.")
+                ("consumable.dumplings.description", health, energy)
             }
             ConsumableItem::Venison(Venison { health, energy }) => {
-                format!(
-                    "It's quite tasty, but you will be lazy if eat it.\n\n\
-                Regain {health} of health, but take energy = {energy}."
-                )
+                ("consumable.venison.description", health, energy)
             }
+        };
+        localization.format(
+            key,
+            &[
+                ("health", health.to_string()),
+                ("energy", energy.to_string()),
+            ],
+        )
+    }
+}
+
+impl GetSelectorItem for EquipmentItem {
+    fn localised_name(&self, localization: &Localization) -> String {
+        let key = match self {
+            EquipmentItem::BrassKnuckles { .. } => "equipment.brass_knuckles.name",
+            EquipmentItem::LeatherJacket { .. } => "equipment.leather_jacket.name",
+        };
+        localization.get(key)
+    }
+
+    fn localised_description(&self, localization: &Localization) -> String {
+        match self {
+            EquipmentItem::BrassKnuckles { attack } => localization.format(
+                "equipment.brass_knuckles.description",
+                &[("attack", attack.to_string())],
+            ),
+            EquipmentItem::LeatherJacket { armor } => localization.format(
+                "equipment.leather_jacket.description",
+                &[("armor", armor.to_string())],
+            ),
         }
     }
 }
+
+impl GetSelectorItem for QuestItem {
+    fn localised_name(&self, localization: &Localization) -> String {
+        let key = match self {
+            QuestItem::ChestKey => "quest_item.chest_key.name",
+            QuestItem::Feather => "quest_item.feather.name",
+        };
+        localization.get(key)
+    }
+
+    fn localised_description(&self, localization: &Localization) -> String {
+        let key = match self {
+            QuestItem::ChestKey => "quest_item.chest_key.description",
+            QuestItem::Feather => "quest_item.feather.description",
+        };
+        localization.get(key)
+    }
+}
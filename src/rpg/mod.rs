@@ -1,6 +1,7 @@
 pub use abilities::*;
 pub use attacks::*;
 pub use character_screen::*;
+pub use characteristic_item_ui::Characteristic;
 pub use interactions::*;
 pub use inventory_and_abilities_screen::*;
 pub use items::*;
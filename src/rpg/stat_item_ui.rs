@@ -20,6 +20,7 @@ use crate::gui::ButtonConfig;
 use crate::gui::TextButtonExt;
 use crate::gui::TextConfig;
 use crate::gui::TextExt;
+use crate::localization::Localization;
 use crate::rpg::characteristic_item_ui::HasDescription;
 
 #[derive(Component, Hash, Copy, Clone, Eq, PartialEq, Debug)]
@@ -32,16 +33,16 @@ pub enum Stat {
 }
 
 impl Stat {
-    fn name(&self) -> String {
-        let str = match self {
-            Stat::Health => "Здоровье",
-            Stat::Energy => "Энергия",
-            Stat::Armor => "Броня",
-            Stat::Evasion => "Уклонение",
-            Stat::BaseAttack => "Базовый урон",
+    fn name(&self, localization: &Localization) -> String {
+        let key = match self {
+            Stat::Health => "stat.health",
+            Stat::Energy => "stat.energy",
+            Stat::Armor => "stat.armor",
+            Stat::Evasion => "stat.evasion",
+            Stat::BaseAttack => "stat.base_attack",
         };
 
-        str.to_string()
+        localization.get(key)
     }
 }
 
@@ -58,15 +59,15 @@ pub struct StatsValues(pub HashMap<Stat, i32>);
 pub struct StatValue;
 
 pub trait StatItemExt<'a> {
-    fn stat(&mut self, typ: Stat) -> UiBuilder<Entity>;
+    fn stat(&mut self, typ: Stat, localization: &Localization) -> UiBuilder<'_, Entity>;
 }
 
 impl<'a> StatItemExt<'a> for UiBuilder<'a, Entity> {
-    fn stat(&mut self, typ: Stat) -> UiBuilder<Entity> {
+    fn stat(&mut self, typ: Stat, localization: &Localization) -> UiBuilder<'_, Entity> {
         let mut item = self.row(|parent| {
             parent
                 .configure_text_button(
-                    typ.name(),
+                    typ.name(localization),
                     typ,
                     TextConfig::from_color(Color::from(ANTIQUE_WHITE)),
                     ButtonConfig {
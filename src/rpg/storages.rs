@@ -27,10 +27,6 @@ impl CharacterStorage {
         &self.characters[id].level
     }
 
-    pub fn update_by_id(&mut self, id: usize, new_value: Character) {
-        self.characters[id] = new_value;
-    }
-
     pub fn update_class_by_id(&mut self, id: usize, class: Class) {
         self.characters[id].class = class;
     }
@@ -39,10 +35,6 @@ impl CharacterStorage {
         self.characters[id].level = level;
     }
 
-    pub fn update_exp_by_id(&mut self, id: usize, exp: i32) {
-        self.characters[id].level.current_experience += exp;
-    }
-
     pub fn add(&mut self, value: Character) {
         self.characters.push(value);
     }
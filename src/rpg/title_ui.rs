@@ -29,11 +29,11 @@ pub enum TitleAction {
 }
 
 pub trait TitleExt<'a> {
-    fn title(&mut self) -> UiBuilder<Entity>;
+    fn title(&mut self) -> UiBuilder<'_, Entity>;
 }
 
 impl<'a> TitleExt<'a> for UiBuilder<'a, Entity> {
-    fn title(&mut self) -> UiBuilder<Entity> {
+    fn title(&mut self) -> UiBuilder<'_, Entity> {
         let mut item = self.row(|parent| {
             parent
                 .configure_text_button(
@@ -0,0 +1,326 @@
+use bevy::prelude::Resource;
+use bevy::render::settings::Backends;
+use bevy::window::{PresentMode, WindowMode};
+use serde::{Deserialize, Serialize};
+
+use crate::core::config::{load_config, save_config};
+use crate::localization::Localization;
+
+const SETTINGS_CONFIG_NAME: &str = "settings";
+const VOLUME_STEP: f32 = 0.1;
+
+pub const RESOLUTIONS: [Resolution; 5] = [
+    Resolution::new(1280, 720),
+    Resolution::new(1600, 900),
+    Resolution::new(1920, 1080),
+    Resolution::new(2560, 1440),
+    Resolution::new(3840, 2160),
+];
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum DisplayMode {
+    Windowed,
+    Borderless,
+    Fullscreen,
+}
+
+impl DisplayMode {
+    const ALL: [DisplayMode; 3] = [
+        DisplayMode::Windowed,
+        DisplayMode::Borderless,
+        DisplayMode::Fullscreen,
+    ];
+
+    pub fn window_mode(&self) -> WindowMode {
+        match self {
+            DisplayMode::Windowed => WindowMode::Windowed,
+            DisplayMode::Borderless => WindowMode::BorderlessFullscreen,
+            DisplayMode::Fullscreen => WindowMode::Fullscreen,
+        }
+    }
+
+    fn title_key(&self) -> &'static str {
+        match self {
+            DisplayMode::Windowed => "settings.display.windowed",
+            DisplayMode::Borderless => "settings.display.borderless",
+            DisplayMode::Fullscreen => "settings.display.fullscreen",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Resolution {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Resolution {
+    pub const fn new(width: u32, height: u32) -> Self {
+        Resolution { width, height }
+    }
+}
+
+/// Graphics api, it's applied after restart only.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Renderer {
+    Auto,
+    Vulkan,
+}
+
+impl Renderer {
+    const ALL: [Renderer; 2] = [Renderer::Auto, Renderer::Vulkan];
+
+    /// None if wgpu picks backend by itself.
+    pub fn backends(&self) -> Option<Backends> {
+        match self {
+            Renderer::Auto => None,
+            Renderer::Vulkan => Some(Backends::VULKAN),
+        }
+    }
+
+    fn title(&self) -> &'static str {
+        match self {
+            Renderer::Auto => "Auto",
+            Renderer::Vulkan => "Vulkan",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum TextSpeed {
+    Slow,
+    Normal,
+    Fast,
+    Instant,
+}
+
+impl TextSpeed {
+    const ALL: [TextSpeed; 4] = [
+        TextSpeed::Slow,
+        TextSpeed::Normal,
+        TextSpeed::Fast,
+        TextSpeed::Instant,
+    ];
+
+    fn title_key(&self) -> &'static str {
+        match self {
+            TextSpeed::Slow => "settings.text_speed.slow",
+            TextSpeed::Normal => "settings.text_speed.normal",
+            TextSpeed::Fast => "settings.text_speed.fast",
+            TextSpeed::Instant => "settings.text_speed.instant",
+        }
+    }
+
+    /// None means text is shown at once.
+    pub fn chars_per_second(&self) -> Option<f32> {
+        match self {
+            TextSpeed::Slow => Some(20.0),
+            TextSpeed::Normal => Some(40.0),
+            TextSpeed::Fast => Some(80.0),
+            TextSpeed::Instant => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Language {
+    Russian,
+    English,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::Russian, Language::English];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::Russian => "ru",
+            Language::English => "en",
+        }
+    }
+
+    // Names of languages are not translated, so everyone can find own one
+    fn title(&self) -> &'static str {
+        match self {
+            Language::Russian => "Русский",
+            Language::English => "English",
+        }
+    }
+}
+
+/// Item of the settings screen.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SettingKind {
+    DisplayMode,
+    Resolution,
+    Vsync,
+    Renderer,
+    MasterVolume,
+    MusicVolume,
+    SfxVolume,
+    UiVolume,
+    TextSpeed,
+    Language,
+}
+
+impl SettingKind {
+    pub const ALL: [SettingKind; 10] = [
+        SettingKind::DisplayMode,
+        SettingKind::Resolution,
+        SettingKind::Vsync,
+        SettingKind::Renderer,
+        SettingKind::MasterVolume,
+        SettingKind::MusicVolume,
+        SettingKind::SfxVolume,
+        SettingKind::UiVolume,
+        SettingKind::TextSpeed,
+        SettingKind::Language,
+    ];
+}
+
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub display_mode: DisplayMode,
+    pub resolution: Resolution,
+    pub vsync: bool,
+    pub renderer: Renderer,
+    pub master_volume: f32,
+    pub music_volume: f32,
+    pub sfx_volume: f32,
+    pub ui_volume: f32,
+    pub text_speed: TextSpeed,
+    pub language: Language,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            display_mode: DisplayMode::Borderless,
+            resolution: Resolution::new(1920, 1080),
+            vsync: true,
+            renderer: Renderer::Vulkan,
+            master_volume: 1.0,
+            music_volume: 1.0,
+            sfx_volume: 1.0,
+            ui_volume: 1.0,
+            text_speed: TextSpeed::Normal,
+            language: Language::Russian,
+        }
+    }
+}
+
+impl Settings {
+    /// Settings from the config, defaults are used for missing values.
+    pub fn load() -> Self {
+        load_config(SETTINGS_CONFIG_NAME).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        save_config(SETTINGS_CONFIG_NAME, self);
+    }
+
+    pub fn present_mode(&self) -> PresentMode {
+        if self.vsync {
+            PresentMode::AutoVsync
+        } else {
+            PresentMode::AutoNoVsync
+        }
+    }
+
+    /// Switches the setting to the next (step > 0) or the previous (step < 0) value.
+    pub fn change(&mut self, kind: SettingKind, step: i32) {
+        match kind {
+            SettingKind::DisplayMode => {
+                self.display_mode = cycle(&DisplayMode::ALL, self.display_mode, step)
+            }
+            SettingKind::Resolution => self.resolution = cycle(&RESOLUTIONS, self.resolution, step),
+            SettingKind::Vsync => self.vsync = !self.vsync,
+            SettingKind::Renderer => self.renderer = cycle(&Renderer::ALL, self.renderer, step),
+            SettingKind::MasterVolume => change_volume(&mut self.master_volume, step),
+            SettingKind::MusicVolume => change_volume(&mut self.music_volume, step),
+            SettingKind::SfxVolume => change_volume(&mut self.sfx_volume, step),
+            SettingKind::UiVolume => change_volume(&mut self.ui_volume, step),
+            SettingKind::TextSpeed => {
+                self.text_speed = cycle(&TextSpeed::ALL, self.text_speed, step)
+            }
+            SettingKind::Language => self.language = cycle(&Language::ALL, self.language, step),
+        }
+    }
+
+    pub fn title(&self, kind: SettingKind, localization: &Localization) -> String {
+        let option = |key: &str, value: String| localization.format(key, &[("value", value)]);
+        let volume =
+            |key: &str, volume: f32| option(key, format!("{}%", (volume * 100.0).round() as i32));
+        match kind {
+            SettingKind::DisplayMode => option(
+                "settings.display",
+                localization.get(self.display_mode.title_key()),
+            ),
+            SettingKind::Resolution => option(
+                "settings.resolution",
+                format!("{}x{}", self.resolution.width, self.resolution.height),
+            ),
+            SettingKind::Vsync => {
+                let value = if self.vsync {
+                    "settings.on"
+                } else {
+                    "settings.off"
+                };
+                option("settings.vsync", localization.get(value))
+            }
+            SettingKind::Renderer => option("settings.renderer", self.renderer.title().to_string()),
+            SettingKind::MasterVolume => volume("settings.master_volume", self.master_volume),
+            SettingKind::MusicVolume => volume("settings.music_volume", self.music_volume),
+            SettingKind::SfxVolume => volume("settings.sfx_volume", self.sfx_volume),
+            SettingKind::UiVolume => volume("settings.ui_volume", self.ui_volume),
+            SettingKind::TextSpeed => option(
+                "settings.text_speed",
+                localization.get(self.text_speed.title_key()),
+            ),
+            SettingKind::Language => option("settings.language", self.language.title().to_string()),
+        }
+    }
+}
+
+fn cycle<T: Copy + PartialEq>(values: &[T], current: T, step: i32) -> T {
+    let len = values.len() as i32;
+    let pos = values.iter().position(|it| *it == current).unwrap_or(0) as i32;
+    values[(pos + step).rem_euclid(len) as usize]
+}
+
+fn change_volume(volume: &mut f32, step: i32) {
+    // Volume is stored with float step, so it's rounded to avoid 0.30000004
+    let steps = (*volume / VOLUME_STEP).round() as i32 + step.signum();
+    *volume = (steps as f32 * VOLUME_STEP).clamp(0.0, 1.0);
+}
+
+#[test]
+fn settings_change_test() {
+    let mut settings = Settings::default();
+
+    settings.change(SettingKind::DisplayMode, 1);
+    settings.change(SettingKind::Resolution, -1);
+    settings.change(SettingKind::MusicVolume, -1);
+    settings.change(SettingKind::SfxVolume, 1);
+    settings.change(SettingKind::Language, 1);
+
+    assert_eq!(settings.display_mode, DisplayMode::Fullscreen);
+    assert_eq!(settings.resolution, Resolution::new(1600, 900));
+    let localization = Localization::load(Language::English);
+    assert_eq!(
+        settings.title(SettingKind::MusicVolume, &localization),
+        "Music volume: 90%"
+    );
+    assert_eq!(settings.sfx_volume, 1.0);
+    assert_eq!(settings.language, Language::English);
+}
+
+#[test]
+fn settings_from_partial_json_test() {
+    let settings: Settings =
+        serde_json::from_str(r#"{"vsync": false, "display_mode": "Windowed"}"#).unwrap();
+
+    assert!(!settings.vsync);
+    assert_eq!(settings.display_mode, DisplayMode::Windowed);
+    assert_eq!(settings.text_speed, Settings::default().text_speed);
+}
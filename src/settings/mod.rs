@@ -0,0 +1,35 @@
+use bevy::app::{App, Plugin, Update};
+use bevy::prelude::{DetectChanges, Query, Res, With};
+use bevy::window::{PrimaryWindow, Window};
+
+mod entities;
+
+pub use entities::*;
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.world().contains_resource::<Settings>() {
+            app.insert_resource(Settings::load());
+        }
+        app.add_systems(Update, window_settings_apply);
+    }
+}
+
+fn window_settings_apply(
+    settings: Res<Settings>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for mut window in window_query.iter_mut() {
+        window.mode = settings.display_mode.window_mode();
+        window.present_mode = settings.present_mode();
+        window.resolution.set(
+            settings.resolution.width as f32,
+            settings.resolution.height as f32,
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use bevy::audio::{AudioBundle, AudioSink, AudioSinkPlayback, AudioSource, PlaybackSettings};
+use bevy::prelude::{
+    Commands, Component, DetectChanges, Entity, Handle, Query, Ref, Res, ResMut, Resource, Time,
+    Timer, TimerMode,
+};
+
+use crate::settings::Settings;
+
+const MUSIC_CROSSFADE_SECONDS: f32 = 1.5;
+const DUCKED_MUSIC_VOLUME: f32 = 0.35;
+const DUCKING_SECONDS: f32 = 0.5;
+// There are no short jingles, so the beginning of a usual track is played instead
+const EXCERPT_SECONDS: f32 = 5.0;
+
+/// Mixer channel of the sound, each one has own volume in settings.
+#[derive(Component, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum AudioBus {
+    Music,
+    Sfx,
+    Ui,
+}
+
+impl AudioBus {
+    fn volume(&self, settings: &Settings) -> f32 {
+        let bus_volume = match self {
+            AudioBus::Music => settings.music_volume,
+            AudioBus::Sfx => settings.sfx_volume,
+            AudioBus::Ui => settings.ui_volume,
+        };
+        settings.master_volume * bus_volume
+    }
+}
+
+/// Volume multiplier which goes to the target smoothly.
+#[derive(Component)]
+pub struct Fade {
+    volume: f32,
+    target: f32,
+    despawn_when_silent: bool,
+}
+
+/// Plays one soundtrack at a time, tracks are changed with crossfade.
+#[derive(Resource)]
+pub struct MusicPlayer {
+    requested: Option<Handle<AudioSource>>,
+    current: Option<(Handle<AudioSource>, Entity)>,
+    requested_excerpt: Option<Handle<AudioSource>>,
+    excerpt: Option<(Entity, Timer)>,
+    is_ducked: bool,
+    ducking_volume: f32,
+}
+
+impl Default for MusicPlayer {
+    fn default() -> Self {
+        MusicPlayer {
+            requested: None,
+            current: None,
+            requested_excerpt: None,
+            excerpt: None,
+            is_ducked: false,
+            ducking_volume: 1.0,
+        }
+    }
+}
+
+impl MusicPlayer {
+    /// The same track keeps playing without restart.
+    pub fn play(&mut self, track: Handle<AudioSource>) {
+        self.requested = Some(track);
+    }
+
+    /// Beginning of the track instead of the music, e.g. on victory, it's faded out
+    /// after a few seconds. Music requested after it starts when the excerpt ends.
+    pub fn play_excerpt(&mut self, track: Handle<AudioSource>) {
+        self.requested_excerpt = Some(track);
+    }
+
+    /// Ducked music is played quieter, e.g. to hear dialogs.
+    pub fn set_ducked(&mut self, is_ducked: bool) {
+        self.is_ducked = is_ducked;
+    }
+}
+
+pub trait AudioCommandsExt {
+    fn play_sound(&mut self, bus: AudioBus, source: Handle<AudioSource>);
+}
+
+impl AudioCommandsExt for Commands<'_, '_> {
+    fn play_sound(&mut self, bus: AudioBus, source: Handle<AudioSource>) {
+        // Sound is started by the mixer when its volume is set
+        self.spawn((
+            AudioBundle {
+                source,
+                settings: PlaybackSettings::DESPAWN.paused(),
+            },
+            bus,
+        ));
+    }
+}
+
+pub fn music_duck(mut music_player: ResMut<MusicPlayer>) {
+    music_player.set_ducked(true);
+}
+
+pub fn music_unduck(mut music_player: ResMut<MusicPlayer>) {
+    music_player.set_ducked(false);
+}
+
+pub(super) fn music_plays(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut music_player: ResMut<MusicPlayer>,
+    mut fade_query: Query<&mut Fade>,
+) {
+    if let Some(excerpt) = music_player.requested_excerpt.take() {
+        if let Some((_, entity)) = music_player.current.take() {
+            fade_out(&mut fade_query, entity);
+        }
+        let entity = commands
+            .spawn((
+                AudioBundle {
+                    source: excerpt,
+                    settings: PlaybackSettings::DESPAWN.paused(),
+                },
+                AudioBus::Music,
+                Fade {
+                    volume: 1.0,
+                    target: 1.0,
+                    despawn_when_silent: false,
+                },
+            ))
+            .id();
+        let timer = Timer::from_seconds(EXCERPT_SECONDS, TimerMode::Once);
+        music_player.excerpt = Some((entity, timer));
+    }
+
+    if let Some((entity, timer)) = music_player.excerpt.as_mut() {
+        timer.tick(time.delta());
+        if !timer.finished() {
+            return;
+        }
+        fade_out(&mut fade_query, *entity);
+        music_player.excerpt = None;
+    }
+
+    let Some(requested) = music_player.requested.take() else {
+        return;
+    };
+    if let Some((track, entity)) = music_player.current.take() {
+        if track == requested {
+            music_player.current = Some((track, entity));
+            return;
+        }
+        fade_out(&mut fade_query, entity);
+    }
+    let entity = commands
+        .spawn((
+            AudioBundle {
+                source: requested.clone(),
+                settings: PlaybackSettings::LOOP.paused(),
+            },
+            AudioBus::Music,
+            Fade {
+                volume: 0.0,
+                target: 1.0,
+                despawn_when_silent: false,
+            },
+        ))
+        .id();
+    music_player.current = Some((requested, entity));
+}
+
+pub(super) fn audio_fades(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut music_player: ResMut<MusicPlayer>,
+    mut fade_query: Query<(Entity, &mut Fade)>,
+) {
+    let delta = time.delta_seconds();
+    for (entity, mut fade) in fade_query.iter_mut() {
+        fade.volume = move_towards(fade.volume, fade.target, delta / MUSIC_CROSSFADE_SECONDS);
+        if fade.despawn_when_silent && fade.volume == 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    let target = if music_player.is_ducked {
+        DUCKED_MUSIC_VOLUME
+    } else {
+        1.0
+    };
+    let ducking_volume = move_towards(
+        music_player.ducking_volume,
+        target,
+        delta * (1.0 - DUCKED_MUSIC_VOLUME) / DUCKING_SECONDS,
+    );
+    // Avoid resource change on every frame
+    if ducking_volume != music_player.ducking_volume {
+        music_player.ducking_volume = ducking_volume;
+    }
+}
+
+pub(super) fn volumes_apply(
+    settings: Res<Settings>,
+    music_player: Res<MusicPlayer>,
+    sinks_query: Query<(Ref<AudioSink>, &PlaybackSettings, &AudioBus, Option<&Fade>)>,
+) {
+    for (sink, playback, bus, fade) in sinks_query.iter() {
+        let mut volume = playback.volume.get() * bus.volume(&settings);
+        if let Some(fade) = fade {
+            volume *= fade.volume;
+        }
+        if *bus == AudioBus::Music {
+            volume *= music_player.ducking_volume;
+        }
+        sink.set_volume(volume);
+        if sink.is_added() {
+            sink.play();
+        }
+    }
+}
+
+fn fade_out(fade_query: &mut Query<&mut Fade>, entity: Entity) {
+    if let Ok(mut fade) = fade_query.get_mut(entity) {
+        fade.target = 0.0;
+        fade.despawn_when_silent = true;
+    }
+}
+
+fn move_towards(value: f32, target: f32, step: f32) -> f32 {
+    if value < target {
+        (value + step).min(target)
+    } else {
+        (value - step).max(target)
+    }
+}
+
+#[test]
+fn move_towards_test() {
+    assert_eq!(move_towards(0.0, 1.0, 0.25), 0.25);
+    assert_eq!(move_towards(0.9, 1.0, 0.25), 1.0);
+    assert_eq!(move_towards(0.5, 0.35, 0.25), 0.35);
+}
@@ -1,9 +1,10 @@
-use bevy::app::{App, Update};
-use bevy::audio::{AudioBundle, PlaybackSettings};
-use bevy::prelude::{Changed, Commands, Interaction, Plugin, Query, Res};
+use bevy::app::{App, PostUpdate, Update};
+use bevy::prelude::{Changed, Commands, Interaction, IntoSystemConfigs, Plugin, Query, Res};
 
+mod mixer;
 mod resources;
 
+pub use mixer::*;
 pub use resources::*;
 pub struct SoundPlugin;
 
@@ -12,7 +13,15 @@ impl Plugin for SoundPlugin {
         app.init_resource::<ButtonSounds>()
             .init_resource::<Soundtrack>()
             .init_resource::<ChestSounds>()
-            .add_systems(Update, menu_button_hover_sounds_handle);
+            .init_resource::<MusicPlayer>()
+            .add_systems(
+                Update,
+                (
+                    menu_button_hover_sounds_handle,
+                    (music_plays, audio_fades).chain(),
+                ),
+            )
+            .add_systems(PostUpdate, volumes_apply);
     }
 }
 
@@ -23,10 +32,7 @@ fn menu_button_hover_sounds_handle(
 ) {
     for interaction in &mut interaction_query {
         if *interaction == Interaction::Hovered {
-            commands.spawn(AudioBundle {
-                source: audio_res.hover.clone(),
-                settings: PlaybackSettings::ONCE,
-            });
+            commands.play_sound(AudioBus::Ui, audio_res.hover.clone());
         }
     }
 }
@@ -26,13 +26,33 @@ impl FromWorld for ButtonSounds {
 #[derive(Resource)]
 pub struct Soundtrack {
     pub menu: Handle<AudioSource>,
-    pub house: Handle<AudioSource>,
-    pub courthouse_front: Handle<AudioSource>,
-    pub courthouse: Handle<AudioSource>,
     pub into_battle: Handle<AudioSource>,
-    pub super_speed: Handle<AudioSource>,
     pub warrior_routine: Handle<AudioSource>,
     pub cold_anger: Handle<AudioSource>,
+    pub the_last_spurt: Handle<AudioSource>,
+}
+
+/// Track of the soundtrack, e.g. to choose it in fight description.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Track {
+    Menu,
+    IntoBattle,
+    WarriorRoutine,
+    ColdAnger,
+    TheLastSpurt,
+}
+
+impl Soundtrack {
+    pub fn get(&self, track: Track) -> Handle<AudioSource> {
+        let handle = match track {
+            Track::Menu => &self.menu,
+            Track::IntoBattle => &self.into_battle,
+            Track::WarriorRoutine => &self.warrior_routine,
+            Track::ColdAnger => &self.cold_anger,
+            Track::TheLastSpurt => &self.the_last_spurt,
+        };
+        handle.clone()
+    }
 }
 
 impl FromWorld for Soundtrack {
@@ -40,13 +60,10 @@ impl FromWorld for Soundtrack {
         let asset_server = world.resource::<AssetServer>();
         Soundtrack {
             menu: asset_server.load("sounds/track/menu.ogg"),
-            house: asset_server.load("sounds/track/house.ogg"),
-            courthouse_front: asset_server.load("sounds/track/courthouse_front.ogg"),
-            courthouse: asset_server.load("sounds/track/courthouse.ogg"),
             into_battle: asset_server.load("sounds/track/into_battle.ogg"),
-            super_speed: asset_server.load("sounds/track/super_speed.ogg"),
             warrior_routine: asset_server.load("sounds/track/warrior_routine.ogg"),
             cold_anger: asset_server.load("sounds/track/cold_anger.ogg"),
+            the_last_spurt: asset_server.load("sounds/track/the_last_spurt.ogg"),
         }
     }
 }
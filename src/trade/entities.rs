@@ -0,0 +1,27 @@
+use bevy::prelude::Component;
+
+use crate::rpg::ConsumableItem;
+
+#[derive(Clone, Debug)]
+pub struct Shop {
+    pub id: usize,
+    /// Key of the localized shop name.
+    pub name: String,
+    pub goods: Vec<Goods>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Goods {
+    pub item: ConsumableItem,
+    pub price: i32,
+}
+
+impl Goods {
+    pub fn from_item(item: ConsumableItem) -> Self {
+        let price = item.base_price();
+        Goods { item, price }
+    }
+}
+
+#[derive(Component)]
+pub struct ShopId(pub usize);
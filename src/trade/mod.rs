@@ -0,0 +1,11 @@
+pub use entities::*;
+pub use plugin::TradePlugin;
+pub use prices::*;
+pub use scene::*;
+pub use storages::*;
+
+mod entities;
+mod plugin;
+mod prices;
+mod scene;
+mod storages;
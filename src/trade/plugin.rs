@@ -0,0 +1,16 @@
+use bevy::app::{App, Plugin, Startup};
+use bevy::prelude::Commands;
+
+use crate::trade::ShopsStorage;
+
+pub struct TradePlugin;
+
+impl Plugin for TradePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, init_storages);
+    }
+}
+
+fn init_storages(mut commands: Commands) {
+    commands.insert_resource(ShopsStorage::default());
+}
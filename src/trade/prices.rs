@@ -0,0 +1,36 @@
+// Each charisma point gives 5% discount on buying and 5% bonus on selling.
+const CHARISMA_PERCENT: i32 = 5;
+const MAX_DISCOUNT_PERCENT: i32 = 50;
+
+pub fn buy_price(price: i32, charisma: i32) -> i32 {
+    let discount = (charisma * CHARISMA_PERCENT).clamp(0, MAX_DISCOUNT_PERCENT);
+    let result = price * (100 - discount) / 100;
+    result.max(1)
+}
+
+pub fn sell_price(price: i32, charisma: i32) -> i32 {
+    let bonus = (charisma * CHARISMA_PERCENT).clamp(0, MAX_DISCOUNT_PERCENT);
+    // half of the price is rounded up, so even the cheapest item can be sold for a coin
+    let result = ((price + 1) / 2) * (100 + bonus) / 100;
+    // selling must never be more profitable than buying back
+    result.min(buy_price(price, charisma)).max(1)
+}
+
+#[test]
+fn buy_price_test() {
+    assert_eq!(buy_price(100, 0), 100);
+    assert_eq!(buy_price(100, 1), 95);
+    assert_eq!(buy_price(100, 4), 80);
+    assert_eq!(buy_price(100, 20), 50);
+    assert_eq!(buy_price(1, 5), 1);
+}
+
+#[test]
+fn sell_price_test() {
+    assert_eq!(sell_price(100, 0), 50);
+    assert_eq!(sell_price(100, 2), 55);
+    assert_eq!(sell_price(100, 20), 50);
+    assert_eq!(sell_price(1, 5), 1);
+    assert_eq!(sell_price(1, 0), 1);
+    assert_eq!(sell_price(25, 0), 13);
+}
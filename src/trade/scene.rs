@@ -0,0 +1,309 @@
+use bevy::app::{App, Plugin, Update};
+use bevy::color::palettes::css::ANTIQUE_WHITE;
+use bevy::color::{Color, Srgba};
+use bevy::hierarchy::DespawnRecursiveExt;
+use bevy::log::warn;
+use bevy::prelude::in_state;
+use bevy::prelude::AlignItems;
+use bevy::prelude::AppExtStates;
+use bevy::prelude::BackgroundColor;
+use bevy::prelude::Changed;
+use bevy::prelude::Commands;
+use bevy::prelude::Component;
+use bevy::prelude::DetectChanges;
+use bevy::prelude::Entity;
+use bevy::prelude::Interaction;
+use bevy::prelude::IntoSystemConfigs;
+use bevy::prelude::JustifyContent;
+use bevy::prelude::NextState;
+use bevy::prelude::OnExit;
+use bevy::prelude::PositionType;
+use bevy::prelude::Query;
+use bevy::prelude::Res;
+use bevy::prelude::ResMut;
+use bevy::prelude::State;
+use bevy::prelude::States;
+use bevy::prelude::UiRect;
+use bevy::prelude::Val;
+use bevy::prelude::With;
+use bevy::ui::FocusPolicy;
+use sickle_ui::prelude::SetAlignItemsExt;
+use sickle_ui::prelude::SetBackgroundColorExt;
+use sickle_ui::prelude::SetFocusPolicyExt;
+use sickle_ui::prelude::SetHeightExt;
+use sickle_ui::prelude::SetJustifyContentExt;
+use sickle_ui::prelude::SetMarginExt;
+use sickle_ui::prelude::SetPositionTypeExt;
+use sickle_ui::prelude::SetTopExt;
+use sickle_ui::prelude::SetWidthExt;
+use sickle_ui::prelude::UiColumnExt;
+use sickle_ui::prelude::UiRoot;
+use sickle_ui::prelude::UiRowExt;
+use sickle_ui::ui_builder::UiBuilderExt;
+
+use crate::core::states::GameState;
+use crate::fight::{pick_item_handle, SelectedItemPosHolder, SelectorExt};
+use crate::gui::{
+    ButtonConfig, GetSelectorItem, SelectorItem, TextButton, TextButtonExt, TextConfig, TextExt,
+};
+use crate::localization::Localization;
+use crate::party::PartyStateStorage;
+use crate::rpg::CharacterStorage;
+use crate::sound::{AudioBus, AudioCommandsExt, ButtonSounds};
+use crate::trade::{buy_price, sell_price, Goods, ShopId, ShopsStorage};
+
+pub struct TradeScene;
+
+#[derive(Component)]
+struct TradeScreen;
+
+#[derive(Component, Debug, Clone, Copy, Default, Eq, PartialEq, Hash, States)]
+enum TradeTab {
+    #[default]
+    Buy,
+    Sell,
+}
+
+impl Plugin for TradeScene {
+    fn build(&self, app: &mut App) {
+        app.init_state::<TradeTab>()
+            .add_systems(OnExit(GameState::Trading), despawn_main)
+            .add_systems(
+                Update,
+                (
+                    main_respawns,
+                    pick_tab_handle,
+                    pick_item_handle,
+                    selected_goods_handle,
+                )
+                    .run_if(in_state(GameState::Trading)),
+            );
+    }
+}
+
+fn main_respawns(
+    mut commands: Commands,
+    party_storage: Res<PartyStateStorage>,
+    shops_storage: Res<ShopsStorage>,
+    character_storage: Res<CharacterStorage>,
+    localization: Res<Localization>,
+    tab_state: Res<State<TradeTab>>,
+    game_state: Res<State<GameState>>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+    shop_id_query: Query<&ShopId>,
+    screen_query: Query<Entity, With<TradeScreen>>,
+) {
+    if !tab_state.is_changed()
+        && !game_state.is_changed()
+        && !party_storage.is_changed()
+        && !shops_storage.is_changed()
+    {
+        return;
+    }
+
+    for entity in screen_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let shop_id = shop_id_query.single();
+    let Some(shop) = shops_storage.get_by_id(&shop_id.0) else {
+        warn!("No shop with id {}", shop_id.0);
+        next_game_state.set(GameState::Exploration);
+        return;
+    };
+    let charisma = character_storage.get_class_by_id(0).charisma();
+
+    let items: Vec<SelectorItem> = match tab_state.get() {
+        TradeTab::Buy => shop
+            .goods
+            .iter()
+            .map(|goods| {
+                let item = goods.item.selector_item(&localization);
+                priced_item(item, buy_price(goods.price, charisma))
+            })
+            .collect(),
+        TradeTab::Sell => party_storage
+            .get_consumables()
+            .iter()
+            .map(|item| {
+                let price = sell_price(item.base_price(), charisma);
+                priced_item(item.selector_item(&localization), price)
+            })
+            .collect(),
+    };
+
+    commands
+        .ui_builder(UiRoot)
+        .column(|parent| {
+            parent
+                .row(|parent| {
+                    parent
+                        .configure_text(
+                            localization.get(&shop.name),
+                            TextConfig::from_color(Color::from(ANTIQUE_WHITE)),
+                        )
+                        .style()
+                        .margin(UiRect::all(Val::Px(20.0)));
+                    parent
+                        .configure_text(
+                            localization.format(
+                                "trade.money",
+                                &[("money", party_storage.get_money().to_string())],
+                            ),
+                            TextConfig::from_color(Color::from(ANTIQUE_WHITE)),
+                        )
+                        .style()
+                        .margin(UiRect::all(Val::Px(20.0)));
+                })
+                .style()
+                .justify_content(JustifyContent::SpaceBetween)
+                .width(Val::Percent(100.0))
+                .height(Val::Percent(50.0));
+
+            parent
+                .row(|parent| {
+                    let config = ButtonConfig {
+                        width: Val::Percent(50.0),
+                        height: Val::Percent(100.0),
+                        idle: BackgroundColor::from(Color::NONE),
+                        hover: BackgroundColor::from(PRESSED_HOVER_BUTTON_COLOR),
+                        pressed: BackgroundColor::from(PRESSED_HOVER_BUTTON_COLOR),
+                        justify_content: JustifyContent::Center,
+                    };
+                    parent
+                        .configure_text_button(
+                            localization.get("trade.buy"),
+                            TradeTab::Buy,
+                            TextConfig::from_color(Color::from(ANTIQUE_WHITE)),
+                            config.clone(),
+                        )
+                        .style()
+                        .focus_policy(FocusPolicy::Pass);
+                    parent
+                        .configure_text_button(
+                            localization.get("trade.sell"),
+                            TradeTab::Sell,
+                            TextConfig::from_color(Color::from(ANTIQUE_WHITE)),
+                            config,
+                        )
+                        .style()
+                        .focus_policy(FocusPolicy::Pass);
+                })
+                .style()
+                .width(Val::Percent(100.0))
+                .height(Val::Percent(50.0));
+        })
+        .insert(TradeScreen)
+        .style()
+        .width(Val::Percent(100.0))
+        .height(Val::Percent(HEADER_HEIGHT_PERCENT))
+        .background_color(Color::from(SCREEN_BG));
+
+    commands
+        .ui_builder(UiRoot)
+        .selector(items)
+        .insert((TradeScreen, SelectedItemPosHolder::new()))
+        .style()
+        .position_type(PositionType::Absolute)
+        .top(Val::Percent(HEADER_HEIGHT_PERCENT))
+        .width(Val::Percent(100.0))
+        .height(Val::Percent(100.0 - HEADER_HEIGHT_PERCENT))
+        .align_items(AlignItems::Center);
+}
+
+fn priced_item(item: SelectorItem, price: i32) -> SelectorItem {
+    SelectorItem {
+        name: format!("{} - {}", item.name, price),
+        description: item.description,
+    }
+}
+
+fn selected_goods_handle(
+    mut commands: Commands,
+    mut party_storage: ResMut<PartyStateStorage>,
+    mut shops_storage: ResMut<ShopsStorage>,
+    character_storage: Res<CharacterStorage>,
+    tab_state: Res<State<TradeTab>>,
+    shop_id_query: Query<&ShopId>,
+    mut holder_query: Query<&mut SelectedItemPosHolder, Changed<SelectedItemPosHolder>>,
+    audio_res: Res<ButtonSounds>,
+) {
+    for mut holder in holder_query.iter_mut() {
+        let Some(pos) = holder.take_away() else {
+            continue;
+        };
+        let shop_id = shop_id_query.single().0;
+        let charisma = character_storage.get_class_by_id(0).charisma();
+        match tab_state.get() {
+            TradeTab::Buy => {
+                let Some(shop) = shops_storage.get_by_id(&shop_id) else {
+                    return;
+                };
+                // position can be stale, e.g. when stock ran out in the same frame
+                let Some(goods) = shop.goods.get(pos) else {
+                    return;
+                };
+                let price = buy_price(goods.price, charisma);
+                if party_storage.take_money(price) {
+                    let goods = shops_storage
+                        .take_goods(&shop_id, pos)
+                        .expect("Goods must exist");
+                    party_storage.add_consumable(goods.item);
+                } else {
+                    commands.play_sound(AudioBus::Ui, audio_res.negative_click.clone());
+                }
+            }
+            TradeTab::Sell => {
+                let Some(item) = party_storage.get_consumables().get(pos).cloned() else {
+                    return;
+                };
+                party_storage.remove_consumable_by_id(pos);
+                party_storage.add_money(sell_price(item.base_price(), charisma));
+                shops_storage.add_goods(&shop_id, Goods::from_item(item));
+            }
+        }
+    }
+}
+
+fn pick_tab_handle(
+    mut query: Query<
+        (&TextButton<TradeTab>, &Interaction, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    mut next_tab_state: ResMut<NextState<TradeTab>>,
+    tab_state: Res<State<TradeTab>>,
+) {
+    for (item, interaction, mut background_color) in &mut query {
+        match *interaction {
+            Interaction::None => {
+                if &item.payload == tab_state.get() {
+                    *background_color = item.config.pressed
+                } else {
+                    *background_color = item.config.idle;
+                }
+            }
+            Interaction::Hovered => *background_color = item.config.hover,
+            Interaction::Pressed => {
+                next_tab_state.set(item.payload);
+                *background_color = item.config.pressed;
+            }
+        }
+    }
+}
+
+fn despawn_main(
+    mut commands: Commands,
+    mut next_tab_state: ResMut<NextState<TradeTab>>,
+    query: Query<Entity, With<TradeScreen>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    next_tab_state.set(TradeTab::Buy);
+}
+
+const HEADER_HEIGHT_PERCENT: f32 = 20.0;
+/// <div style="background-color:rgb(60.0%, 44.4%, 25.0%); width: 10px; padding: 10px; border: 1px solid;"></div>
+const PRESSED_HOVER_BUTTON_COLOR: Color = Color::srgba(0.6, 0.444, 0.25, 1.0);
+/// <div style="background-color:rgb(50.0%, 39.4%, 21.0%); width: 10px; padding: 10px; border: 1px solid;"></div>
+const SCREEN_BG: Srgba = Srgba::new(0.5, 0.394, 0.21, 1.0);
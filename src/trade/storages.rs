@@ -0,0 +1,43 @@
+use bevy::prelude::Resource;
+use bevy::utils::HashMap;
+
+use crate::level::shops;
+use crate::trade::{Goods, Shop};
+
+// unlike other storages, stock changes while playing, so shops are kept here
+#[derive(Resource)]
+pub struct ShopsStorage {
+    shops: HashMap<usize, Shop>,
+}
+
+impl Default for ShopsStorage {
+    fn default() -> Self {
+        ShopsStorage {
+            shops: test_shops(),
+        }
+    }
+}
+
+impl ShopsStorage {
+    pub fn get_by_id(&self, id: &usize) -> Option<&Shop> {
+        self.shops.get(id)
+    }
+
+    pub fn take_goods(&mut self, shop_id: &usize, pos: usize) -> Option<Goods> {
+        let shop = self.shops.get_mut(shop_id)?;
+        if pos >= shop.goods.len() {
+            return None;
+        }
+        Some(shop.goods.remove(pos))
+    }
+
+    pub fn add_goods(&mut self, shop_id: &usize, goods: Goods) {
+        if let Some(shop) = self.shops.get_mut(shop_id) {
+            shop.goods.push(goods);
+        }
+    }
+}
+
+fn test_shops() -> HashMap<usize, Shop> {
+    shops().into_iter().map(|shop| (shop.id, shop)).collect()
+}
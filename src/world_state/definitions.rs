@@ -0,0 +1,310 @@
+use crate::fight::{FightResult, GOPNIKS_FIGHT_ID};
+use crate::level::states::Level;
+use crate::level::*;
+use crate::rpg::QuestItem;
+use crate::world_state::engine::QuestDefinition;
+use crate::world_state::engine::QuestTrigger;
+use crate::world_state::engine::QuestTrigger::{ItemPicked, LevelEntered, WentToBed};
+use crate::world_state::{
+    BlondAndGopniks, Court, EscapeFromHouse, GoIntoCourt, House, StrangeOldWoman, Trial,
+};
+
+pub fn escape_from_house_quest() -> QuestDefinition<EscapeFromHouse> {
+    QuestDefinition::new("EscapeFromHouse")
+        .transition(
+            EscapeFromHouse::Courier,
+            EscapeFromHouse::GoSleep,
+            QuestTrigger::dialog_end(COURIER_DIALOG, END_DIALOG_NECK_TWISTED),
+        )
+        .transition(
+            EscapeFromHouse::Courier,
+            EscapeFromHouse::CallDog,
+            QuestTrigger::dialog_end(COURIER_DIALOG, END_DIALOG_AGENDA_TAKEN),
+        )
+        .transition(
+            EscapeFromHouse::CallDog,
+            EscapeFromHouse::Escape,
+            QuestTrigger::any_dialog_end(SLEEPING_FORMIDABLE_DOG_DIALOG),
+        )
+}
+
+pub fn house_quest() -> QuestDefinition<House> {
+    QuestDefinition::new("House").transition(House::GoSleep, House::TalkWithPolice, WentToBed)
+}
+
+pub fn strange_old_woman_quest() -> QuestDefinition<StrangeOldWoman> {
+    QuestDefinition::new("StrangeOldWoman")
+        .transition(
+            StrangeOldWoman::None,
+            StrangeOldWoman::GiveMeFeather,
+            LevelEntered(Level::CourtHouseFront),
+        )
+        .transition(
+            StrangeOldWoman::GiveMeFeather,
+            StrangeOldWoman::FeatherFound,
+            ItemPicked(QuestItem::Feather),
+        )
+        .transition(
+            StrangeOldWoman::GiveMeFeather,
+            StrangeOldWoman::Beaten,
+            QuestTrigger::dialog_end(DREVNIRA_DIALOG, END_DIALOG_DREVNIRA_BEATEN),
+        )
+        .transition(
+            StrangeOldWoman::FeatherFound,
+            StrangeOldWoman::FeatherGiven,
+            QuestTrigger::dialog_end(DREVNIRA_DIALOG, END_DIALOG_DREVNIRA_FEATHER_GIVEN),
+        )
+        .transition(
+            StrangeOldWoman::FeatherFound,
+            StrangeOldWoman::Beaten,
+            QuestTrigger::dialog_end(DREVNIRA_DIALOG, END_DIALOG_DREVNIRA_BEATEN),
+        )
+}
+
+pub fn blond_and_gopniks_quest() -> QuestDefinition<BlondAndGopniks> {
+    QuestDefinition::new("BlondAndGopniks")
+        .transition(
+            BlondAndGopniks::None,
+            BlondAndGopniks::TalkWithBlond,
+            LevelEntered(Level::CourtHouseFront),
+        )
+        .transition(
+            BlondAndGopniks::TalkWithBlond,
+            BlondAndGopniks::TalkWithGopniks,
+            QuestTrigger::dialog_end(BLOND_FIRST_DIALOG, END_DIALOG_BLOND_FIRST_ACCEPTED),
+        )
+        .transition(
+            BlondAndGopniks::TalkWithBlond,
+            BlondAndGopniks::Completed,
+            QuestTrigger::any_dialog_end(BLOND_FIRST_DIALOG),
+        )
+        .transition(
+            BlondAndGopniks::TalkWithGopniks,
+            BlondAndGopniks::TakeDumplingsFromBlond,
+            QuestTrigger::dialog_end(GOPNIKS_DIALOG, END_DIALOG_GOPNIKS_DIALOG_ASK_BLOND),
+        )
+        .transition(
+            BlondAndGopniks::TalkWithGopniks,
+            BlondAndGopniks::GiveDumplingsToBlond,
+            QuestTrigger::skill_check(GOPNIKS_INTIMIDATE_CHECK, true),
+        )
+        .transition(
+            BlondAndGopniks::TalkWithGopniks,
+            BlondAndGopniks::FightGopniks,
+            QuestTrigger::dialog_end(GOPNIKS_DIALOG, END_DIALOG_GOPNIKS_DIALOG_FIGHT),
+        )
+        .transition(
+            BlondAndGopniks::FightGopniks,
+            BlondAndGopniks::GiveDumplingsToBlond,
+            QuestTrigger::FightFinished {
+                fight_id: GOPNIKS_FIGHT_ID.0,
+                result: FightResult::Won,
+            },
+        )
+        .transition(
+            BlondAndGopniks::GiveDumplingsToBlond,
+            BlondAndGopniks::Completed,
+            QuestTrigger::dialog_end(
+                BLOND_GIVE_DUMPLINGS_DIALOG,
+                END_DIALOG_BLOND_GIVE_DUMPLINGS_COMPLETED,
+            ),
+        )
+        .transition(
+            BlondAndGopniks::TakeDumplingsFromBlond,
+            BlondAndGopniks::Completed,
+            QuestTrigger::dialog_end(
+                BLOND_TAKE_DUMPLINGS_DIALOG,
+                END_DIALOG_BLOND_TAKE_DUMPLINGS_JUST_COMPLETED,
+            ),
+        )
+        .transition(
+            BlondAndGopniks::TakeDumplingsFromBlond,
+            BlondAndGopniks::Completed,
+            QuestTrigger::dialog_end(
+                BLOND_TAKE_DUMPLINGS_DIALOG,
+                END_DIALOG_BLOND_TAKE_DUMPLINGS_NECK_TWISTED,
+            ),
+        )
+}
+
+pub fn court_quest() -> QuestDefinition<Court> {
+    QuestDefinition::new("Court")
+        .transition(
+            Court::None,
+            Court::TalkWithGuardian,
+            LevelEntered(Level::CourtHouseFront),
+        )
+        .transition(
+            Court::TalkWithGuardian,
+            Court::Completed,
+            QuestTrigger::dialog_end(
+                GUARDIAN_FIRST_DIALOG,
+                END_DIALOG_GUARDIAN_FIRST_JUST_COMPLETED,
+            ),
+        )
+        .transition(
+            Court::TalkWithGuardian,
+            Court::Completed,
+            QuestTrigger::dialog_end(GUARDIAN_FIRST_DIALOG, END_DIALOG_GUARDIAN_FIRST_BEATEN),
+        )
+        .transition(
+            Court::TalkWithGuardian,
+            Court::StopDrevnira,
+            QuestTrigger::dialog_end(
+                GUARDIAN_FIRST_DIALOG,
+                END_DIALOG_GUARDIAN_FIRST_DREVNIRA_STOP_ACCEPTED,
+            ),
+        )
+        .transition(
+            Court::TalkWithGuardian,
+            Court::DrevniraStopped,
+            QuestTrigger::dialog_end(DREVNIRA_DIALOG, END_DIALOG_DREVNIRA_BEATEN),
+        )
+        .transition(
+            Court::TalkWithGuardian,
+            Court::DrevniraStopped,
+            QuestTrigger::dialog_end(DREVNIRA_DIALOG, END_DIALOG_DREVNIRA_FEATHER_GIVEN),
+        )
+        .transition(
+            Court::StopDrevnira,
+            Court::DrevniraStopped,
+            QuestTrigger::dialog_end(DREVNIRA_DIALOG, END_DIALOG_DREVNIRA_BEATEN),
+        )
+        .transition(
+            Court::StopDrevnira,
+            Court::DrevniraStopped,
+            QuestTrigger::dialog_end(DREVNIRA_DIALOG, END_DIALOG_DREVNIRA_FEATHER_GIVEN),
+        )
+        .transition(
+            Court::StopDrevnira,
+            Court::Completed,
+            QuestTrigger::dialog_end(GUARDIAN_SECOND_DIALOG, END_DIALOG_GUARDIAN_SECOND_COMPLETED),
+        )
+        .transition(
+            Court::StopDrevnira,
+            Court::Completed,
+            QuestTrigger::dialog_end(GUARDIAN_SECOND_DIALOG, END_DIALOG_GUARDIAN_SECOND_BEATEN),
+        )
+        .transition(
+            Court::DrevniraStopped,
+            Court::Completed,
+            QuestTrigger::dialog_end(GUARDIAN_THIRD_DIALOG, END_DIALOG_GUARDIAN_THIRD_COMPLETED),
+        )
+        .transition(
+            Court::DrevniraStopped,
+            Court::Completed,
+            QuestTrigger::dialog_end(GUARDIAN_THIRD_DIALOG, END_DIALOG_GUARDIAN_THIRD_BEATEN),
+        )
+}
+
+pub fn go_into_court_quest() -> QuestDefinition<GoIntoCourt> {
+    QuestDefinition::new("GoIntoCourt")
+        .transition(
+            GoIntoCourt::None,
+            GoIntoCourt::Wait,
+            LevelEntered(Level::CourtHouseHall),
+        )
+        .transition(
+            GoIntoCourt::Wait,
+            GoIntoCourt::Go,
+            QuestTrigger::dialog_end(
+                HALL_GUARDIAN_FIRST_DIALOG,
+                HALL_GUARDIAN_FIRST_DIALOG_BEATEN,
+            ),
+        )
+        .transition(
+            GoIntoCourt::Wait,
+            GoIntoCourt::CanGo,
+            QuestTrigger::dialog_end(CRAZY_MAN_DIALOG, CRAZY_MAN_DIALOG_COMPLETED),
+        )
+        .transition(
+            GoIntoCourt::Wait,
+            GoIntoCourt::CanGo,
+            QuestTrigger::dialog_end(CRAZY_MAN_DIALOG, CRAZY_MAN_DIALOG_BEATEN),
+        )
+        .transition(
+            GoIntoCourt::CanGo,
+            GoIntoCourt::Go,
+            QuestTrigger::dialog_end(HALL_GUARDIAN_SECOND_DIALOG, HALL_GUARDIAN_SECOND_COMPLETED),
+        )
+}
+
+pub fn trial_quest() -> QuestDefinition<Trial> {
+    QuestDefinition::new("Trial")
+        .transition(
+            Trial::None,
+            Trial::SpeakWithJudges,
+            LevelEntered(Level::Court),
+        )
+        .transition(
+            Trial::SpeakWithJudges,
+            Trial::Wait,
+            QuestTrigger::dialog_end(JUDGES_FIRST_DIALOG, JUDGES_FIRST_DIALOG_COMPLETED),
+        )
+        .transition(
+            Trial::SpeakWithJudges,
+            Trial::GoAtHome,
+            QuestTrigger::dialog_end(JUDGES_FIRST_DIALOG, JUDGES_FIRST_DIALOG_BEATEN),
+        )
+        .transition(
+            Trial::Wait,
+            Trial::FormidableFaceWon,
+            QuestTrigger::cutscene_end(TRIAL_VERDICT_CUTSCENE, TRIAL_VERDICT_WON),
+        )
+        .transition(
+            Trial::Wait,
+            Trial::FormidableFaceFailed,
+            QuestTrigger::cutscene_end(TRIAL_VERDICT_CUTSCENE, TRIAL_VERDICT_FAILED),
+        )
+        .transition(
+            Trial::FormidableFaceWon,
+            Trial::GoAtHome,
+            QuestTrigger::dialog_end(JUDGES_SECOND_DIALOG, JUDGES_SECOND_DIALOG_COMPLETED),
+        )
+        .transition(
+            Trial::FormidableFaceFailed,
+            Trial::GoAtHome,
+            QuestTrigger::any_dialog_end(JUDGES_THIRD_DIALOG),
+        )
+}
+
+#[test]
+fn fight_and_item_triggers_test() {
+    use crate::world_state::engine::{QuestEngine, QuestEvent};
+
+    let mut gopniks = QuestEngine::new(blond_and_gopniks_quest());
+    let fight_started = QuestEvent::DialogEnded {
+        dialog_id: GOPNIKS_DIALOG,
+        end_id: END_DIALOG_GOPNIKS_DIALOG_FIGHT,
+    };
+    let won = QuestEvent::FightFinished {
+        fight_id: GOPNIKS_FIGHT_ID.0,
+        result: FightResult::Won,
+    };
+    assert_eq!(
+        gopniks.handle(&BlondAndGopniks::TalkWithGopniks, &fight_started),
+        Some(BlondAndGopniks::FightGopniks)
+    );
+    assert_eq!(
+        gopniks.handle(&BlondAndGopniks::FightGopniks, &won),
+        Some(BlondAndGopniks::GiveDumplingsToBlond)
+    );
+
+    let mut old_woman = QuestEngine::new(strange_old_woman_quest());
+    let picked = QuestEvent::ItemPicked(QuestItem::Feather);
+    assert_eq!(old_woman.handle(&StrangeOldWoman::None, &picked), None);
+    assert_eq!(
+        old_woman.handle(&StrangeOldWoman::GiveMeFeather, &picked),
+        Some(StrangeOldWoman::FeatherFound)
+    );
+    assert_eq!(
+        old_woman.handle(
+            &StrangeOldWoman::FeatherFound,
+            &QuestEvent::DialogEnded {
+                dialog_id: DREVNIRA_DIALOG,
+                end_id: END_DIALOG_DREVNIRA_FEATHER_GIVEN,
+            }
+        ),
+        Some(StrangeOldWoman::FeatherGiven)
+    );
+}
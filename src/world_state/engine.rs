@@ -0,0 +1,364 @@
+use std::fmt::Debug;
+
+use bevy::log::info;
+use bevy::prelude::DetectChanges;
+use bevy::prelude::Event;
+use bevy::prelude::EventReader;
+use bevy::prelude::EventWriter;
+use bevy::prelude::NextState;
+use bevy::prelude::Res;
+use bevy::prelude::ResMut;
+use bevy::prelude::Resource;
+use bevy::prelude::State;
+use bevy::state::state::FreelyMutableState;
+
+use crate::fight::FightResult;
+use crate::level::states::Level;
+use crate::rpg::QuestItem;
+
+// Something that happened in the world and can move quests forward
+#[derive(Event, Clone, Debug, PartialEq)]
+pub enum QuestEvent {
+    DialogEnded {
+        dialog_id: usize,
+        end_id: usize,
+    },
+    FightFinished {
+        fight_id: usize,
+        result: FightResult,
+    },
+    ItemPicked(QuestItem),
+    LevelEntered(Level),
+    WentToBed,
+    CutsceneEnded {
+        cutscene_id: usize,
+        end_id: usize,
+    },
+    SkillChecked {
+        name: String,
+        passed: bool,
+    },
+}
+
+// Condition of quest transition. Dialog end id None means any end of the dialog
+#[derive(Clone, Debug, PartialEq)]
+pub enum QuestTrigger {
+    DialogEnded {
+        dialog_id: usize,
+        end_id: Option<usize>,
+    },
+    FightFinished {
+        fight_id: usize,
+        result: FightResult,
+    },
+    ItemPicked(QuestItem),
+    LevelEntered(Level),
+    WentToBed,
+    CutsceneEnded {
+        cutscene_id: usize,
+        end_id: usize,
+    },
+    SkillChecked {
+        name: String,
+        passed: bool,
+    },
+}
+
+impl QuestTrigger {
+    pub fn dialog_end(dialog_id: usize, end_id: usize) -> Self {
+        QuestTrigger::DialogEnded {
+            dialog_id,
+            end_id: Some(end_id),
+        }
+    }
+
+    pub fn any_dialog_end(dialog_id: usize) -> Self {
+        QuestTrigger::DialogEnded {
+            dialog_id,
+            end_id: None,
+        }
+    }
+
+    pub fn cutscene_end(cutscene_id: usize, end_id: usize) -> Self {
+        QuestTrigger::CutsceneEnded {
+            cutscene_id,
+            end_id,
+        }
+    }
+
+    pub fn skill_check(name: &str, passed: bool) -> Self {
+        QuestTrigger::SkillChecked {
+            name: name.to_string(),
+            passed,
+        }
+    }
+
+    pub fn matches(&self, event: &QuestEvent) -> bool {
+        match (self, event) {
+            (
+                QuestTrigger::DialogEnded { dialog_id, end_id },
+                QuestEvent::DialogEnded {
+                    dialog_id: event_dialog_id,
+                    end_id: event_end_id,
+                },
+            ) => {
+                dialog_id == event_dialog_id && end_id.is_none_or(|end_id| end_id == *event_end_id)
+            }
+            (
+                QuestTrigger::FightFinished { fight_id, result },
+                QuestEvent::FightFinished {
+                    fight_id: event_fight_id,
+                    result: event_result,
+                },
+            ) => fight_id == event_fight_id && result == event_result,
+            (QuestTrigger::ItemPicked(item), QuestEvent::ItemPicked(event_item)) => {
+                item == event_item
+            }
+            (QuestTrigger::LevelEntered(level), QuestEvent::LevelEntered(event_level)) => {
+                level == event_level
+            }
+            (QuestTrigger::WentToBed, QuestEvent::WentToBed) => true,
+            (
+                QuestTrigger::CutsceneEnded {
+                    cutscene_id,
+                    end_id,
+                },
+                QuestEvent::CutsceneEnded {
+                    cutscene_id: event_cutscene_id,
+                    end_id: event_end_id,
+                },
+            ) => cutscene_id == event_cutscene_id && end_id == event_end_id,
+            (
+                QuestTrigger::SkillChecked { name, passed },
+                QuestEvent::SkillChecked {
+                    name: event_name,
+                    passed: event_passed,
+                },
+            ) => name == event_name && passed == event_passed,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct QuestTransition<S> {
+    pub from: S,
+    pub to: S,
+    pub trigger: QuestTrigger,
+}
+
+// Transitions are checked in order of declaration,
+// so specific triggers must be declared before wildcard ones.
+// Initial state is the default one of the quest state.
+#[derive(Clone, Debug)]
+pub struct QuestDefinition<S> {
+    pub name: &'static str,
+    pub transitions: Vec<QuestTransition<S>>,
+}
+
+impl<S: Clone + PartialEq + Debug> QuestDefinition<S> {
+    pub fn new(name: &'static str) -> Self {
+        QuestDefinition {
+            name,
+            transitions: vec![],
+        }
+    }
+
+    pub fn transition(mut self, from: S, to: S, trigger: QuestTrigger) -> Self {
+        self.transitions.push(QuestTransition { from, to, trigger });
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuestLogRecord<S> {
+    pub from: S,
+    pub to: S,
+    pub event: QuestEvent,
+}
+
+// Current state of the quest is the bevy state, engine only knows how to move it
+#[derive(Resource)]
+pub struct QuestEngine<S> {
+    definition: QuestDefinition<S>,
+    log: Vec<QuestLogRecord<S>>,
+}
+
+impl<S: Clone + PartialEq + Debug> QuestEngine<S> {
+    pub fn new(definition: QuestDefinition<S>) -> Self {
+        QuestEngine {
+            definition,
+            log: vec![],
+        }
+    }
+
+    pub fn log(&self) -> &Vec<QuestLogRecord<S>> {
+        &self.log
+    }
+
+    // Returns new state if event caused a transition from the current one
+    pub fn handle(&mut self, current: &S, event: &QuestEvent) -> Option<S> {
+        let transition =
+            self.definition.transitions.iter().find(|transition| {
+                transition.from == *current && transition.trigger.matches(event)
+            })?;
+        let to = transition.to.clone();
+        self.log.push(QuestLogRecord {
+            from: current.clone(),
+            to: to.clone(),
+            event: event.clone(),
+        });
+        Some(to)
+    }
+}
+
+pub fn quest_engine_handles<S: FreelyMutableState + Debug>(
+    mut engine: ResMut<QuestEngine<S>>,
+    mut events: EventReader<QuestEvent>,
+    state: Res<State<S>>,
+    mut next_state: ResMut<NextState<S>>,
+) {
+    // state is applied on the next frame, so events of this frame continue from the last transition
+    let mut current = state.get().clone();
+    for event in events.read() {
+        let Some(to) = engine.handle(&current, event) else {
+            continue;
+        };
+        current = to.clone();
+        if let Some(record) = engine.log().last() {
+            info!(
+                "Quest {}: {:?} -> {:?} by {:?}",
+                engine.definition.name, record.from, record.to, record.event
+            );
+        }
+        next_state.set(to);
+    }
+}
+
+pub fn level_entered_triggers(level_state: Res<State<Level>>, mut events: EventWriter<QuestEvent>) {
+    if level_state.is_changed() {
+        events.send(QuestEvent::LevelEntered(*level_state.get()));
+    }
+}
+
+#[test]
+fn quest_trigger_matches_test() {
+    let event = QuestEvent::DialogEnded {
+        dialog_id: 1,
+        end_id: 2,
+    };
+
+    assert!(QuestTrigger::dialog_end(1, 2).matches(&event));
+    assert!(QuestTrigger::any_dialog_end(1).matches(&event));
+    assert!(!QuestTrigger::dialog_end(1, 3).matches(&event));
+    assert!(!QuestTrigger::any_dialog_end(2).matches(&event));
+    assert!(!QuestTrigger::ItemPicked(QuestItem::Feather).matches(&event));
+    assert!(QuestTrigger::ItemPicked(QuestItem::Feather)
+        .matches(&QuestEvent::ItemPicked(QuestItem::Feather)));
+    assert!(
+        QuestTrigger::cutscene_end(1, 2).matches(&QuestEvent::CutsceneEnded {
+            cutscene_id: 1,
+            end_id: 2,
+        })
+    );
+    assert!(!QuestTrigger::cutscene_end(1, 2).matches(&event));
+    let passed = QuestEvent::SkillChecked {
+        name: "persuade".to_string(),
+        passed: true,
+    };
+    assert!(QuestTrigger::skill_check("persuade", true).matches(&passed));
+    assert!(!QuestTrigger::skill_check("persuade", false).matches(&passed));
+    assert!(!QuestTrigger::skill_check("intimidate", true).matches(&passed));
+}
+
+#[test]
+fn quest_engine_handle_test() {
+    #[derive(Clone, Debug, PartialEq)]
+    enum TestQuest {
+        Start,
+        Middle,
+        Failed,
+        Done,
+    }
+
+    let definition = QuestDefinition::new("test")
+        .transition(
+            TestQuest::Start,
+            TestQuest::Middle,
+            QuestTrigger::dialog_end(1, 10),
+        )
+        .transition(
+            TestQuest::Start,
+            TestQuest::Failed,
+            QuestTrigger::any_dialog_end(1),
+        )
+        .transition(
+            TestQuest::Middle,
+            TestQuest::Done,
+            QuestTrigger::FightFinished {
+                fight_id: 5,
+                result: FightResult::Won,
+            },
+        );
+    let mut engine = QuestEngine::new(definition);
+    let won = QuestEvent::FightFinished {
+        fight_id: 5,
+        result: FightResult::Won,
+    };
+
+    // Illegal from current state, ignored
+    assert_eq!(engine.handle(&TestQuest::Start, &won), None);
+    assert!(engine.log().is_empty());
+
+    let dialog_end = QuestEvent::DialogEnded {
+        dialog_id: 1,
+        end_id: 10,
+    };
+    assert_eq!(
+        engine.handle(&TestQuest::Start, &dialog_end),
+        Some(TestQuest::Middle)
+    );
+    assert_eq!(engine.handle(&TestQuest::Middle, &dialog_end), None);
+    assert_eq!(
+        engine.handle(&TestQuest::Middle, &won),
+        Some(TestQuest::Done)
+    );
+
+    assert_eq!(
+        engine.log(),
+        &vec![
+            QuestLogRecord {
+                from: TestQuest::Start,
+                to: TestQuest::Middle,
+                event: dialog_end,
+            },
+            QuestLogRecord {
+                from: TestQuest::Middle,
+                to: TestQuest::Done,
+                event: won,
+            },
+        ]
+    );
+
+    let mut engine = QuestEngine::new(
+        QuestDefinition::new("test")
+            .transition(
+                TestQuest::Start,
+                TestQuest::Middle,
+                QuestTrigger::dialog_end(1, 10),
+            )
+            .transition(
+                TestQuest::Start,
+                TestQuest::Failed,
+                QuestTrigger::any_dialog_end(1),
+            ),
+    );
+    let other_end = QuestEvent::DialogEnded {
+        dialog_id: 1,
+        end_id: 11,
+    };
+    assert_eq!(
+        engine.handle(&TestQuest::Start, &other_end),
+        Some(TestQuest::Failed)
+    );
+}
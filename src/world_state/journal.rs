@@ -0,0 +1,337 @@
+use bevy::color::palettes::css::ANTIQUE_WHITE;
+use bevy::color::{Color, Srgba};
+use bevy::hierarchy::DespawnRecursiveExt;
+use bevy::prelude::AlignItems;
+use bevy::prelude::Commands;
+use bevy::prelude::Component;
+use bevy::prelude::DetectChanges;
+use bevy::prelude::Entity;
+use bevy::prelude::PositionType;
+use bevy::prelude::Query;
+use bevy::prelude::Res;
+use bevy::prelude::ResMut;
+use bevy::prelude::Resource;
+use bevy::prelude::State;
+use bevy::prelude::States;
+use bevy::prelude::Time;
+use bevy::prelude::Timer;
+use bevy::prelude::UiRect;
+use bevy::prelude::Val;
+use bevy::time::TimerMode;
+use sickle_ui::prelude::SetAlignItemsExt;
+use sickle_ui::prelude::SetBackgroundColorExt;
+use sickle_ui::prelude::SetPaddingExt;
+use sickle_ui::prelude::SetPositionTypeExt;
+use sickle_ui::prelude::SetTopExt;
+use sickle_ui::prelude::SetWidthExt;
+use sickle_ui::prelude::UiColumnExt;
+use sickle_ui::prelude::UiRoot;
+use sickle_ui::ui_builder::UiBuilderExt;
+
+use crate::gui::{TextConfig, TextExt};
+use crate::localization::Localization;
+use crate::world_state::{
+    BlondAndGopniks, Court, EscapeFromHouse, GoIntoCourt, House, StrangeOldWoman, Trial,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuestStatus {
+    Active,
+    Completed,
+}
+
+/// Texts are keys of localized strings.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuestInfo {
+    pub title: String,
+    pub description: String,
+    pub objective: String,
+    pub status: QuestStatus,
+}
+
+impl QuestInfo {
+    fn active(title: &str, description: &str, objective: &str) -> Self {
+        QuestInfo {
+            title: title.to_string(),
+            description: description.to_string(),
+            objective: objective.to_string(),
+            status: QuestStatus::Active,
+        }
+    }
+
+    fn completed(title: &str, description: &str, objective: &str) -> Self {
+        QuestInfo {
+            status: QuestStatus::Completed,
+            ..QuestInfo::active(title, description, objective)
+        }
+    }
+}
+
+// Maps quest state to text shown in journal. None means quest is not started yet
+pub trait HasQuestInfo {
+    fn quest_info(&self) -> Option<QuestInfo>;
+}
+
+#[derive(Resource, Default)]
+pub struct QuestJournal {
+    entries: Vec<(&'static str, QuestInfo)>,
+}
+
+impl QuestJournal {
+    pub fn get_entries(&self) -> Vec<QuestInfo> {
+        self.entries.iter().map(|(_, info)| info.clone()).collect()
+    }
+
+    pub fn update(&mut self, key: &'static str, info: QuestInfo) {
+        match self
+            .entries
+            .iter_mut()
+            .find(|(entry_key, _)| *entry_key == key)
+        {
+            Some(entry) => entry.1 = info,
+            None => self.entries.push((key, info)),
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct QuestNotification {
+    timer: Timer,
+}
+
+pub fn quest_journal_updates<T: States + HasQuestInfo>(
+    mut commands: Commands,
+    mut journal: ResMut<QuestJournal>,
+    localization: Res<Localization>,
+    state: Res<State<T>>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+    let Some(info) = state.get().quest_info() else {
+        return;
+    };
+    if journal.get_entries().contains(&info) {
+        return;
+    }
+    // Quests states are initialized with the app, there is nothing to notify about
+    if !state.is_added() {
+        let key = match info.status {
+            QuestStatus::Active => "journal.quest_updated",
+            QuestStatus::Completed => "journal.quest_completed",
+        };
+        let text = localization.format(key, &[("title", localization.get(&info.title))]);
+        spawn_notification(&mut commands, text);
+    }
+    journal.update(std::any::type_name::<T>(), info);
+}
+
+fn spawn_notification(commands: &mut Commands, text: String) {
+    commands
+        .ui_builder(UiRoot)
+        .column(|parent| {
+            parent
+                .configure_text(text, TextConfig::small(Color::from(ANTIQUE_WHITE)))
+                .style()
+                .padding(UiRect::all(Val::Px(10.0)))
+                .background_color(Color::from(NOTIFICATION_BG));
+        })
+        .insert(QuestNotification {
+            timer: Timer::from_seconds(NOTIFICATION_SECONDS, TimerMode::Once),
+        })
+        .style()
+        .position_type(PositionType::Absolute)
+        .top(Val::Px(20.0))
+        .width(Val::Percent(100.0))
+        .align_items(AlignItems::Center);
+}
+
+pub fn quest_notifications_despawns(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut notifications: Query<(Entity, &mut QuestNotification)>,
+) {
+    for (entity, mut notification) in notifications.iter_mut() {
+        notification.timer.tick(time.delta());
+        if notification.timer.finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+const NOTIFICATION_SECONDS: f32 = 3.0;
+/// <div style="background-color:rgb(50.0%, 39.4%, 21.0%); width: 10px; padding: 10px; border: 1px solid;"></div>
+const NOTIFICATION_BG: Srgba = Srgba::new(0.5, 0.394, 0.21, 0.9);
+
+impl HasQuestInfo for EscapeFromHouse {
+    fn quest_info(&self) -> Option<QuestInfo> {
+        let title = "quest.escape_from_house.title";
+        let description = "quest.escape_from_house.description";
+        let info = match self {
+            EscapeFromHouse::Courier => {
+                QuestInfo::active(title, description, "quest.escape_from_house.courier")
+            }
+            EscapeFromHouse::GoSleep => {
+                QuestInfo::active(title, description, "quest.escape_from_house.go_sleep")
+            }
+            EscapeFromHouse::CallDog => {
+                QuestInfo::active(title, description, "quest.escape_from_house.call_dog")
+            }
+            EscapeFromHouse::Escape => {
+                QuestInfo::completed(title, description, "quest.escape_from_house.escape")
+            }
+        };
+        Some(info)
+    }
+}
+
+impl HasQuestInfo for House {
+    fn quest_info(&self) -> Option<QuestInfo> {
+        let title = "quest.house.title";
+        let description = "quest.house.description";
+        let info = match self {
+            House::GoSleep => QuestInfo::active(title, description, "quest.house.go_sleep"),
+            House::TalkWithPolice => {
+                QuestInfo::active(title, description, "quest.house.talk_with_police")
+            }
+            House::Completed => QuestInfo::completed(title, description, "quest.house.completed"),
+        };
+        Some(info)
+    }
+}
+
+impl HasQuestInfo for Court {
+    fn quest_info(&self) -> Option<QuestInfo> {
+        let title = "quest.court.title";
+        let description = "quest.court.description";
+        let info = match self {
+            Court::None => return None,
+            Court::TalkWithGuardian => {
+                QuestInfo::active(title, description, "quest.court.talk_with_guardian")
+            }
+            Court::StopDrevnira => {
+                QuestInfo::active(title, description, "quest.court.stop_drevnira")
+            }
+            Court::DrevniraStopped => {
+                QuestInfo::active(title, description, "quest.court.drevnira_stopped")
+            }
+            Court::Completed => QuestInfo::completed(title, description, "quest.court.completed"),
+        };
+        Some(info)
+    }
+}
+
+impl HasQuestInfo for StrangeOldWoman {
+    fn quest_info(&self) -> Option<QuestInfo> {
+        let title = "quest.strange_old_woman.title";
+        let description = "quest.strange_old_woman.description";
+        let info = match self {
+            StrangeOldWoman::None => return None,
+            StrangeOldWoman::GiveMeFeather => QuestInfo::active(
+                title,
+                description,
+                "quest.strange_old_woman.give_me_feather",
+            ),
+            StrangeOldWoman::FeatherFound => {
+                QuestInfo::active(title, description, "quest.strange_old_woman.feather_found")
+            }
+            StrangeOldWoman::FeatherGiven => {
+                QuestInfo::completed(title, description, "quest.strange_old_woman.feather_given")
+            }
+            StrangeOldWoman::Beaten => {
+                QuestInfo::completed(title, description, "quest.strange_old_woman.beaten")
+            }
+        };
+        Some(info)
+    }
+}
+
+impl HasQuestInfo for BlondAndGopniks {
+    fn quest_info(&self) -> Option<QuestInfo> {
+        let title = "quest.blond_and_gopniks.title";
+        let description = "quest.blond_and_gopniks.description";
+        let info = match self {
+            BlondAndGopniks::None => return None,
+            BlondAndGopniks::TalkWithBlond => QuestInfo::active(
+                title,
+                description,
+                "quest.blond_and_gopniks.talk_with_blond",
+            ),
+            BlondAndGopniks::TalkWithGopniks => QuestInfo::active(
+                title,
+                description,
+                "quest.blond_and_gopniks.talk_with_gopniks",
+            ),
+            BlondAndGopniks::FightGopniks => {
+                QuestInfo::active(title, description, "quest.blond_and_gopniks.fight_gopniks")
+            }
+            BlondAndGopniks::GiveDumplingsToBlond => QuestInfo::active(
+                title,
+                description,
+                "quest.blond_and_gopniks.give_dumplings_to_blond",
+            ),
+            BlondAndGopniks::TakeDumplingsFromBlond => QuestInfo::active(
+                title,
+                description,
+                "quest.blond_and_gopniks.take_dumplings_from_blond",
+            ),
+            BlondAndGopniks::Completed => {
+                QuestInfo::completed(title, description, "quest.blond_and_gopniks.completed")
+            }
+        };
+        Some(info)
+    }
+}
+
+impl HasQuestInfo for GoIntoCourt {
+    fn quest_info(&self) -> Option<QuestInfo> {
+        let title = "quest.go_into_court.title";
+        let description = "quest.go_into_court.description";
+        let info = match self {
+            GoIntoCourt::None => return None,
+            GoIntoCourt::Wait => QuestInfo::active(title, description, "quest.go_into_court.wait"),
+            GoIntoCourt::CanGo => {
+                QuestInfo::active(title, description, "quest.go_into_court.can_go")
+            }
+            GoIntoCourt::Go => QuestInfo::completed(title, description, "quest.go_into_court.go"),
+        };
+        Some(info)
+    }
+}
+
+impl HasQuestInfo for Trial {
+    fn quest_info(&self) -> Option<QuestInfo> {
+        let title = "quest.trial.title";
+        let description = "quest.trial.description";
+        let info = match self {
+            Trial::None => return None,
+            Trial::SpeakWithJudges => {
+                QuestInfo::active(title, description, "quest.trial.speak_with_judges")
+            }
+            Trial::Wait => QuestInfo::active(title, description, "quest.trial.wait"),
+            Trial::FormidableFaceWon => {
+                QuestInfo::active(title, description, "quest.trial.formidable_face_won")
+            }
+            Trial::FormidableFaceFailed => {
+                QuestInfo::active(title, description, "quest.trial.formidable_face_failed")
+            }
+            Trial::GoAtHome => QuestInfo::completed(title, description, "quest.trial.go_at_home"),
+        };
+        Some(info)
+    }
+}
+
+#[test]
+fn quest_journal_update_test() {
+    let mut journal = QuestJournal::default();
+    let first = EscapeFromHouse::Courier.quest_info().unwrap();
+    let second = EscapeFromHouse::GoSleep.quest_info().unwrap();
+    let other = House::GoSleep.quest_info().unwrap();
+
+    journal.update("escape", first);
+    journal.update("house", other.clone());
+    journal.update("escape", second.clone());
+
+    assert_eq!(journal.get_entries(), vec![second, other]);
+    assert_eq!(Trial::None.quest_info(), None);
+}
@@ -0,0 +1,111 @@
+use bevy::app::{App, Plugin};
+use bevy::color::palettes::css::{ANTIQUE_WHITE, DIM_GREY};
+use bevy::color::{Color, Srgba};
+use bevy::hierarchy::DespawnRecursiveExt;
+use bevy::prelude::Commands;
+use bevy::prelude::Component;
+use bevy::prelude::Entity;
+use bevy::prelude::OnEnter;
+use bevy::prelude::OnExit;
+use bevy::prelude::Query;
+use bevy::prelude::Res;
+use bevy::prelude::UiRect;
+use bevy::prelude::Val;
+use bevy::prelude::With;
+use sickle_ui::prelude::SetBackgroundColorExt;
+use sickle_ui::prelude::SetMarginExt;
+use sickle_ui::prelude::SetPaddingExt;
+use sickle_ui::prelude::SetSizeExt;
+use sickle_ui::prelude::SetWidthExt;
+use sickle_ui::prelude::UiColumnExt;
+use sickle_ui::prelude::UiRoot;
+use sickle_ui::ui_builder::UiBuilderExt;
+
+use crate::core::states::GameState;
+use crate::gui::{TextConfig, TextExt};
+use crate::localization::Localization;
+use crate::world_state::{QuestJournal, QuestStatus};
+
+pub struct JournalScreenPlugin;
+
+#[derive(Component)]
+struct JournalScreen;
+
+impl Plugin for JournalScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Journal), spawn_main)
+            .add_systems(OnExit(GameState::Journal), despawn_main);
+    }
+}
+
+fn spawn_main(mut commands: Commands, journal: Res<QuestJournal>, localization: Res<Localization>) {
+    let mut entries = journal.get_entries();
+    // Active quests go first, the most recent on top
+    entries.reverse();
+    entries.sort_by_key(|info| info.status == QuestStatus::Completed);
+
+    commands
+        .ui_builder(UiRoot)
+        .column(|parent| {
+            parent
+                .configure_text(
+                    localization.get("journal.title"),
+                    TextConfig::large(Color::from(ANTIQUE_WHITE)),
+                )
+                .style()
+                .margin(UiRect::bottom(Val::Px(20.0)));
+
+            if entries.is_empty() {
+                parent.configure_text(
+                    localization.get("journal.empty"),
+                    TextConfig::from_color(Color::from(DIM_GREY)),
+                );
+            }
+
+            for info in entries {
+                let color = match info.status {
+                    QuestStatus::Active => Color::from(ANTIQUE_WHITE),
+                    QuestStatus::Completed => Color::from(DIM_GREY),
+                };
+                let title = localization.get(&info.title);
+                let title = match info.status {
+                    QuestStatus::Active => title,
+                    QuestStatus::Completed => {
+                        localization.format("journal.completed", &[("title", title)])
+                    }
+                };
+                parent
+                    .column(|parent| {
+                        parent.configure_text(title, TextConfig::from_color(color));
+                        parent.configure_text(
+                            localization.get(&info.description),
+                            TextConfig::small(color),
+                        );
+                        parent.configure_text(
+                            localization.format(
+                                "journal.objective",
+                                &[("objective", localization.get(&info.objective))],
+                            ),
+                            TextConfig::small(color),
+                        );
+                    })
+                    .style()
+                    .width(Val::Percent(100.0))
+                    .margin(UiRect::bottom(Val::Px(15.0)));
+            }
+        })
+        .insert(JournalScreen)
+        .style()
+        .size(Val::Percent(100.0))
+        .padding(UiRect::all(Val::Percent(5.0)))
+        .background_color(Color::from(SCREEN_BG));
+}
+
+fn despawn_main(mut commands: Commands, query: Query<Entity, With<JournalScreen>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// <div style="background-color:rgb(50.0%, 39.4%, 21.0%); width: 10px; padding: 10px; border: 1px solid;"></div>
+const SCREEN_BG: Srgba = Srgba::new(0.5, 0.394, 0.21, 1.0);
@@ -1,5 +1,11 @@
+mod definitions;
+mod engine;
+mod journal;
+mod journal_screen;
 mod plugin;
 mod quests;
 
+pub use engine::*;
+pub use journal::*;
 pub use plugin::WorldStatePlugin;
 pub use quests::*;
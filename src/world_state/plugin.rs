@@ -1,13 +1,21 @@
+use crate::dialog::quest_variables_updates;
 use crate::world_state::{GoIntoCourt, Trial};
-use bevy::app::{App, Plugin};
+use bevy::app::{App, Plugin, Update};
 use bevy::prelude::AppExtStates;
 
+use crate::world_state::definitions::*;
+use crate::world_state::engine::{
+    level_entered_triggers, quest_engine_handles, QuestEngine, QuestEvent,
+};
+use crate::world_state::journal::{
+    quest_journal_updates, quest_notifications_despawns, QuestJournal,
+};
+use crate::world_state::journal_screen::JournalScreenPlugin;
+
 use crate::world_state::quests::BlondAndGopniks;
 use crate::world_state::quests::Court;
-use crate::world_state::quests::EnterTheCourt;
 use crate::world_state::quests::EscapeFromHouse;
 use crate::world_state::quests::House;
-use crate::world_state::quests::InCourHall;
 use crate::world_state::quests::StrangeOldWoman;
 
 pub struct WorldStatePlugin;
@@ -15,13 +23,59 @@ pub struct WorldStatePlugin;
 impl Plugin for WorldStatePlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<EscapeFromHouse>()
-            .init_state::<EnterTheCourt>()
-            .init_state::<InCourHall>()
             .init_state::<Court>()
             .init_state::<House>()
             .init_state::<StrangeOldWoman>()
             .init_state::<BlondAndGopniks>()
             .init_state::<GoIntoCourt>()
-            .init_state::<Trial>();
+            .init_state::<Trial>()
+            .add_event::<QuestEvent>()
+            .insert_resource(QuestEngine::new(escape_from_house_quest()))
+            .insert_resource(QuestEngine::new(house_quest()))
+            .insert_resource(QuestEngine::new(strange_old_woman_quest()))
+            .insert_resource(QuestEngine::new(blond_and_gopniks_quest()))
+            .insert_resource(QuestEngine::new(court_quest()))
+            .insert_resource(QuestEngine::new(go_into_court_quest()))
+            .insert_resource(QuestEngine::new(trial_quest()))
+            .add_systems(
+                Update,
+                (
+                    level_entered_triggers,
+                    quest_engine_handles::<EscapeFromHouse>,
+                    quest_engine_handles::<House>,
+                    quest_engine_handles::<StrangeOldWoman>,
+                    quest_engine_handles::<BlondAndGopniks>,
+                    quest_engine_handles::<Court>,
+                    quest_engine_handles::<GoIntoCourt>,
+                    quest_engine_handles::<Trial>,
+                ),
+            )
+            .init_resource::<QuestJournal>()
+            .add_plugins(JournalScreenPlugin)
+            .add_systems(
+                Update,
+                (
+                    quest_journal_updates::<EscapeFromHouse>,
+                    quest_journal_updates::<House>,
+                    quest_journal_updates::<Court>,
+                    quest_journal_updates::<StrangeOldWoman>,
+                    quest_journal_updates::<BlondAndGopniks>,
+                    quest_journal_updates::<GoIntoCourt>,
+                    quest_journal_updates::<Trial>,
+                    quest_notifications_despawns,
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    quest_variables_updates::<EscapeFromHouse>,
+                    quest_variables_updates::<House>,
+                    quest_variables_updates::<Court>,
+                    quest_variables_updates::<StrangeOldWoman>,
+                    quest_variables_updates::<BlondAndGopniks>,
+                    quest_variables_updates::<GoIntoCourt>,
+                    quest_variables_updates::<Trial>,
+                ),
+            );
     }
 }
@@ -1,6 +1,8 @@
 use bevy::prelude::States;
 
-#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, States)]
+// States of each quest are declared in the order they are passed, so later ones compare greater
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash, States)]
 pub enum EscapeFromHouse {
     #[default]
     Courier,
@@ -9,25 +11,7 @@ pub enum EscapeFromHouse {
     Escape,
 }
 
-#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, States)]
-pub enum EnterTheCourt {
-    #[default]
-    None,
-    Go,
-    StopDrevnira,
-    Completed,
-}
-
-#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, States)]
-pub enum InCourHall {
-    #[default]
-    None,
-    TalkWithManager,
-    Wait,
-    Completed,
-}
-
-#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, States)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash, States)]
 pub enum Court {
     #[default]
     None,
@@ -37,34 +21,38 @@ pub enum Court {
     Completed,
 }
 
-#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, States)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash, States)]
 pub enum House {
     #[default]
     GoSleep,
     TalkWithPolice,
+    #[allow(dead_code)]
     Completed,
 }
 
-#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, States)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash, States)]
 pub enum StrangeOldWoman {
     #[default]
     None,
     GiveMeFeather,
+    FeatherFound,
+    FeatherGiven,
     Beaten,
 }
 
-#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, States)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash, States)]
 pub enum BlondAndGopniks {
     #[default]
     None,
     TalkWithBlond,
     TalkWithGopniks,
+    FightGopniks,
     GiveDumplingsToBlond,
     TakeDumplingsFromBlond,
     Completed,
 }
 
-#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, States)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash, States)]
 pub enum GoIntoCourt {
     #[default]
     None,
@@ -73,7 +61,7 @@ pub enum GoIntoCourt {
     Go,
 }
 
-#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, States)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash, States)]
 pub enum Trial {
     #[default]
     None,